@@ -0,0 +1,237 @@
+//! Ordered containers: the skip list against the balanced trees and `std`'s `BTreeMap`.
+//!
+//! ```sh
+//! cargo bench --bench ordered
+//! ```
+//!
+//! Same seeded keys for every container, times are per phase.
+
+use std::{
+    collections::BTreeMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use playground::{
+    algorithm::{
+        b_tree::{BPlusTree, BTree},
+        skip_list::SkipList,
+        tree::{AvlMap, RedBlackMap, SearchTree},
+    },
+    random::SeededRandom,
+};
+
+const COUNT: usize = 200_000;
+const RANGES: usize = 2_000;
+const RANGE_WIDTH: u32 = 1_000;
+const B_TREE_ORDER: usize = 32;
+
+/// Set-like view of every benched container.
+trait Ordered {
+    const NAME: &'static str;
+
+    fn new() -> Self;
+    fn insert(&mut self, key: u32);
+    fn contains(&self, key: &u32) -> bool;
+    fn remove(&mut self, key: &u32);
+    fn range_len(&self, start: u32, end: u32) -> usize;
+}
+
+impl Ordered for SkipList<u32> {
+    const NAME: &'static str = "SkipList";
+
+    fn new() -> Self {
+        SkipList::with_seed(43)
+    }
+
+    fn insert(&mut self, key: u32) {
+        SkipList::insert(self, key);
+    }
+
+    fn contains(&self, key: &u32) -> bool {
+        SkipList::contains(self, key)
+    }
+
+    fn remove(&mut self, key: &u32) {
+        SkipList::remove(self, key);
+    }
+
+    fn range_len(&self, start: u32, end: u32) -> usize {
+        self.range(start..end).count()
+    }
+}
+
+impl Ordered for AvlMap<u32, ()> {
+    const NAME: &'static str = "AvlMap";
+
+    fn new() -> Self {
+        AvlMap::new()
+    }
+
+    fn insert(&mut self, key: u32) {
+        AvlMap::insert(self, key, ());
+    }
+
+    fn contains(&self, key: &u32) -> bool {
+        self.contains_key(key)
+    }
+
+    fn remove(&mut self, key: &u32) {
+        AvlMap::remove(self, key);
+    }
+
+    fn range_len(&self, start: u32, end: u32) -> usize {
+        self.range(start..end).count()
+    }
+}
+
+impl Ordered for RedBlackMap<u32, ()> {
+    const NAME: &'static str = "RedBlackMap";
+
+    fn new() -> Self {
+        RedBlackMap::new()
+    }
+
+    fn insert(&mut self, key: u32) {
+        RedBlackMap::insert(self, key, ());
+    }
+
+    fn contains(&self, key: &u32) -> bool {
+        self.contains_key(key)
+    }
+
+    fn remove(&mut self, key: &u32) {
+        RedBlackMap::remove(self, key);
+    }
+
+    fn range_len(&self, start: u32, end: u32) -> usize {
+        self.range(start..end).count()
+    }
+}
+
+impl Ordered for BTree<u32, ()> {
+    const NAME: &'static str = "BTree";
+
+    fn new() -> Self {
+        BTree::new(B_TREE_ORDER).unwrap()
+    }
+
+    fn insert(&mut self, key: u32) {
+        BTree::insert(self, key, ());
+    }
+
+    fn contains(&self, key: &u32) -> bool {
+        self.contains_key(key)
+    }
+
+    fn remove(&mut self, key: &u32) {
+        BTree::remove(self, key);
+    }
+
+    fn range_len(&self, start: u32, end: u32) -> usize {
+        self.range(start..end).count()
+    }
+}
+
+impl Ordered for BPlusTree<u32, ()> {
+    const NAME: &'static str = "BPlusTree";
+
+    fn new() -> Self {
+        BPlusTree::new(B_TREE_ORDER).unwrap()
+    }
+
+    fn insert(&mut self, key: u32) {
+        BPlusTree::insert(self, key, ());
+    }
+
+    fn contains(&self, key: &u32) -> bool {
+        self.contains_key(key)
+    }
+
+    fn remove(&mut self, key: &u32) {
+        BPlusTree::remove(self, key);
+    }
+
+    fn range_len(&self, start: u32, end: u32) -> usize {
+        self.range(start..end).count()
+    }
+}
+
+impl Ordered for BTreeMap<u32, ()> {
+    const NAME: &'static str = "std BTreeMap";
+
+    fn new() -> Self {
+        BTreeMap::new()
+    }
+
+    fn insert(&mut self, key: u32) {
+        BTreeMap::insert(self, key, ());
+    }
+
+    fn contains(&self, key: &u32) -> bool {
+        self.contains_key(key)
+    }
+
+    fn remove(&mut self, key: &u32) {
+        BTreeMap::remove(self, key);
+    }
+
+    fn range_len(&self, start: u32, end: u32) -> usize {
+        self.range(start..end).count()
+    }
+}
+
+fn time(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn bench<C: Ordered>(keys: &[u32], starts: &[u32]) {
+    let mut container = C::new();
+
+    let insert = time(|| keys.iter().for_each(|&key| container.insert(key)));
+    let contains = time(|| {
+        keys.iter()
+            .for_each(|key| assert!(black_box(container.contains(key))))
+    });
+    let range = time(|| {
+        starts.iter().for_each(|&start| {
+            black_box(container.range_len(start, start + RANGE_WIDTH));
+        })
+    });
+    let remove = time(|| keys.iter().for_each(|key| container.remove(key)));
+
+    let ms = |d: Duration| d.as_secs_f64() * 1e3;
+    println!(
+        "{:<14} {:>10.1} {:>10.1} {:>10.1} {:>10.1}",
+        C::NAME,
+        ms(insert),
+        ms(contains),
+        ms(range),
+        ms(remove)
+    );
+}
+
+fn main() {
+    let mut random = SeededRandom::new(43);
+    let keys = (0..COUNT)
+        .map(|_| random.get_in_range(0, u32::MAX - RANGE_WIDTH))
+        .collect::<Vec<_>>();
+    let starts = (0..RANGES)
+        .map(|_| keys[random.get_in_range(0, COUNT)])
+        .collect::<Vec<_>>();
+
+    println!("{COUNT} random keys, {RANGES} range scans, times in ms\n");
+    println!(
+        "{:<14} {:>10} {:>10} {:>10} {:>10}",
+        "", "insert", "contains", "range", "remove"
+    );
+
+    bench::<SkipList<u32>>(&keys, &starts);
+    bench::<AvlMap<u32, ()>>(&keys, &starts);
+    bench::<RedBlackMap<u32, ()>>(&keys, &starts);
+    bench::<BTree<u32, ()>>(&keys, &starts);
+    bench::<BPlusTree<u32, ()>>(&keys, &starts);
+    bench::<BTreeMap<u32, ()>>(&keys, &starts);
+}
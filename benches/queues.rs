@@ -0,0 +1,115 @@
+//! Throughput of the lock-free queues against `queue::Queue` behind a `Mutex`.
+//!
+//! ```sh
+//! cargo bench --bench queues
+//! ```
+//!
+//! Waiting sides yield instead of spinning, so the numbers stay meaningful
+//! even when there are fewer cores than threads.
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use playground::algorithm::{
+    lock_free::{mpsc, spsc},
+    queue::Queue,
+};
+
+const COUNT: u32 = 2_000_000;
+const PRODUCERS: u32 = 4;
+
+fn report(name: &str, elapsed: Duration) {
+    let mops = COUNT as f64 / elapsed.as_secs_f64() / 1e6;
+    println!(
+        "{name:<32} {:>8.1} ms {mops:>8.2} Mops/s",
+        elapsed.as_secs_f64() * 1e3
+    );
+}
+
+fn spsc_lock_free() -> Duration {
+    let (mut producer, mut consumer) = spsc::channel(1024);
+    let start = Instant::now();
+
+    let producer = thread::spawn(move || {
+        for v in 0..COUNT {
+            let mut value = v;
+            while let Err(back) = producer.push(value) {
+                value = back;
+                thread::yield_now();
+            }
+        }
+    });
+
+    let mut received = 0;
+    while received < COUNT {
+        match consumer.pop() {
+            Some(_) => received += 1,
+            None => thread::yield_now(),
+        }
+    }
+
+    producer.join().unwrap();
+    start.elapsed()
+}
+
+fn mpsc_lock_free(producers: u32) -> Duration {
+    let (sender, mut receiver) = mpsc::channel();
+    let start = Instant::now();
+
+    let handles = (0..producers)
+        .map(|_| {
+            let sender = sender.clone();
+            thread::spawn(move || (0..COUNT / producers).for_each(|v| sender.send(v)))
+        })
+        .collect::<Vec<_>>();
+
+    let mut received = 0;
+    while received < COUNT / producers * producers {
+        match receiver.try_recv() {
+            Some(_) => received += 1,
+            None => thread::yield_now(),
+        }
+    }
+
+    handles.into_iter().for_each(|h| h.join().unwrap());
+    start.elapsed()
+}
+
+fn mutex_queue(producers: u32) -> Duration {
+    let queue = Arc::new(Mutex::new(Queue::<u32>::new()));
+    let start = Instant::now();
+
+    let handles = (0..producers)
+        .map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                (0..COUNT / producers).for_each(|v| queue.lock().unwrap().enqueue(v))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut received = 0;
+    while received < COUNT / producers * producers {
+        match queue.lock().unwrap().dequeue() {
+            Some(_) => received += 1,
+            None => thread::yield_now(),
+        }
+    }
+
+    handles.into_iter().for_each(|h| h.join().unwrap());
+    start.elapsed()
+}
+
+fn main() {
+    println!("{COUNT} values, {PRODUCERS} producers for MPSC\n");
+
+    report("spsc::channel", spsc_lock_free());
+    report("Mutex<Queue>, 1 producer", mutex_queue(1));
+    report("mpsc::channel, 1 producer", mpsc_lock_free(1));
+    println!();
+    report("mpsc::channel", mpsc_lock_free(PRODUCERS));
+    report("Mutex<Queue>", mutex_queue(PRODUCERS));
+}
@@ -0,0 +1,518 @@
+use std::mem;
+
+use super::*;
+use crate::core::term::print::{Align, PrintConfig};
+
+struct Node<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    // Empty for leaves, `keys.len() + 1` otherwise.
+    children: Vec<Node<K, V>>,
+}
+
+impl<K, V> Node<K, V> {
+    fn new() -> Self {
+        Self {
+            keys: vec![],
+            values: vec![],
+            children: vec![],
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Moves the upper half into a new right sibling.
+    /// @Returns median entry, which goes up into the parent, and the sibling.
+    fn split(&mut self) -> (K, V, Self) {
+        let mid = self.keys.len() / 2;
+        let right = Self {
+            keys: self.keys.split_off(mid + 1),
+            values: self.values.split_off(mid + 1),
+            children: match self.is_leaf() {
+                true => vec![],
+                false => self.children.split_off(mid + 1),
+            },
+        };
+
+        let key = self.keys.pop().expect("Node overflowed, so it isn't empty");
+        let value = self.values.pop().expect("Same length as `keys`");
+        (key, value, right)
+    }
+}
+
+enum Insert<K, V> {
+    Inserted,
+    Replaced(V),
+    Split(K, V, Node<K, V>),
+}
+
+/// B-tree map: every node holds up to `order - 1` entries and `order` children,
+/// all leaves are on the same level.
+pub struct BTree<K, V> {
+    root: Node<K, V>,
+    order: usize,
+    size: usize,
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    /// @If `order` < [`MIN_ORDER`] -> `Err`
+    pub fn new(order: usize) -> Result<Self, BTreeError> {
+        check_order(order)?;
+
+        Ok(Self {
+            root: Node::new(),
+            order,
+            size: 0,
+        })
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Number of levels, a lone root is 1.
+    pub fn height(&self) -> usize {
+        let (mut node, mut height) = (&self.root, 1);
+        while let Some(child) = node.children.first() {
+            node = child;
+            height += 1;
+        }
+
+        height
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut node = &self.root;
+        loop {
+            match node.keys.binary_search(key) {
+                Ok(i) => return Some(&node.values[i]),
+                Err(i) => node = node.children.get(i)?,
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// @Returns previous value of `key`.
+    /// @Mutate splits every overflowing node on the way back up, growing a new root last.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match Self::insert_into(&mut self.root, key, value, self.order) {
+            Insert::Replaced(old) => return Some(old),
+            Insert::Inserted => {}
+            Insert::Split(key, value, right) => {
+                let left = mem::replace(&mut self.root, Node::new());
+                self.root = Node {
+                    keys: vec![key],
+                    values: vec![value],
+                    children: vec![left, right],
+                };
+            }
+        }
+
+        self.size += 1;
+        None
+    }
+
+    fn insert_into(node: &mut Node<K, V>, key: K, value: V, order: usize) -> Insert<K, V> {
+        match node.keys.binary_search(&key) {
+            Ok(i) => return Insert::Replaced(mem::replace(&mut node.values[i], value)),
+            Err(i) if node.is_leaf() => {
+                node.keys.insert(i, key);
+                node.values.insert(i, value);
+            }
+            Err(i) => match Self::insert_into(&mut node.children[i], key, value, order) {
+                Insert::Split(key, value, right) => {
+                    node.keys.insert(i, key);
+                    node.values.insert(i, value);
+                    node.children.insert(i + 1, right);
+                }
+                result => return result,
+            },
+        }
+
+        match node.keys.len() == order {
+            true => {
+                let (key, value, right) = node.split();
+                Insert::Split(key, value, right)
+            }
+            false => Insert::Inserted,
+        }
+    }
+
+    /// @Mutate refills every underflowing node on the way back up,
+    /// borrowing from a sibling or merging with it.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = Self::remove_from(&mut self.root, key, min_keys(self.order))?;
+        self.size -= 1;
+
+        // Root lost its last separator to a merge.
+        if self.root.keys.is_empty() {
+            if let Some(child) = self.root.children.pop() {
+                self.root = child;
+            }
+        }
+
+        Some(removed)
+    }
+
+    fn remove_from(node: &mut Node<K, V>, key: &K, min: usize) -> Option<V> {
+        match node.keys.binary_search(key) {
+            Ok(i) if node.is_leaf() => {
+                node.keys.remove(i);
+                Some(node.values.remove(i))
+            }
+            // Predecessor (max of the left subtree) takes the entry's place.
+            Ok(i) => {
+                let (key, value) = Self::remove_max(&mut node.children[i], min);
+                node.keys[i] = key;
+                let removed = mem::replace(&mut node.values[i], value);
+                Self::refill(node, i, min);
+                Some(removed)
+            }
+            Err(i) => {
+                let removed = Self::remove_from(node.children.get_mut(i)?, key, min)?;
+                Self::refill(node, i, min);
+                Some(removed)
+            }
+        }
+    }
+
+    fn remove_max(node: &mut Node<K, V>, min: usize) -> (K, V) {
+        match node.children.len().checked_sub(1) {
+            None => {
+                let key = node
+                    .keys
+                    .pop()
+                    .expect("Leaves on a removal path aren't empty");
+                (key, node.values.pop().expect("Same length as `keys`"))
+            }
+            Some(last) => {
+                let max = Self::remove_max(&mut node.children[last], min);
+                Self::refill(node, last, min);
+                max
+            }
+        }
+    }
+
+    /// Restores at least `min` keys in `node.children[i]`.
+    fn refill(node: &mut Node<K, V>, i: usize, min: usize) {
+        if node.children[i].keys.len() >= min {
+            return;
+        }
+
+        let can_lend = |sibling: Option<&Node<K, V>>| sibling.is_some_and(|s| s.keys.len() > min);
+
+        // Rotate the left sibling's last entry through the parent.
+        if i > 0 && can_lend(node.children.get(i - 1)) {
+            let left = &mut node.children[i - 1];
+            let (key, value, child) = (left.keys.pop(), left.values.pop(), left.children.pop());
+
+            let key = mem::replace(&mut node.keys[i - 1], key.expect("Lender has keys"));
+            let value = mem::replace(&mut node.values[i - 1], value.expect("Same as `keys`"));
+            let target = &mut node.children[i];
+            target.keys.insert(0, key);
+            target.values.insert(0, value);
+            target.children.splice(0..0, child);
+        } else if can_lend(node.children.get(i + 1)) {
+            let right = &mut node.children[i + 1];
+            let (key, value) = (right.keys.remove(0), right.values.remove(0));
+            let child = (!right.is_leaf()).then(|| right.children.remove(0));
+
+            let key = mem::replace(&mut node.keys[i], key);
+            let value = mem::replace(&mut node.values[i], value);
+            let target = &mut node.children[i];
+            target.keys.push(key);
+            target.values.push(value);
+            target.children.extend(child);
+        } else {
+            // Merge with a sibling, pulling their separator down.
+            let j = i.saturating_sub(1);
+            let right = node.children.remove(j + 1);
+            let (key, value) = (node.keys.remove(j), node.values.remove(j));
+
+            let left = &mut node.children[j];
+            left.keys.push(key);
+            left.keys.extend(right.keys);
+            left.values.push(value);
+            left.values.extend(right.values);
+            left.children.extend(right.children);
+        }
+    }
+
+    /// Ascending by key.
+    pub fn iter(&self) -> Range<'_, K, V, std::ops::RangeFull> {
+        self.range(..)
+    }
+
+    /// Ascending entries with keys in `range`. O(height + entries).
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V, R> {
+        let mut stack = vec![];
+        let mut node = Some(&self.root);
+        while let Some(current) = node {
+            let i = current
+                .keys
+                .partition_point(|key| !is_above_start(&range, key));
+            stack.push((current, i));
+            node = current.children.get(i);
+        }
+
+        Range { stack, range }
+    }
+
+    /// One line per level, nodes as `[k1 k2 ...]`.
+    pub fn levels(&self) -> Levels
+    where
+        K: Display,
+    {
+        let mut lines = vec![];
+        let mut level = vec![&self.root];
+        while !level.is_empty() {
+            let nodes = level.iter().map(|node| node_label(&node.keys));
+            lines.push(nodes.collect::<Vec<_>>().join("  "));
+            level = level.iter().flat_map(|node| &node.children).collect();
+        }
+
+        Levels { lines }
+    }
+
+    /// Key order, node fill, child counts, leaf depth and `len`.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        struct Checked {
+            depth: usize,
+            count: usize,
+        }
+
+        fn check<K: Ord, V>(
+            node: &Node<K, V>,
+            order: usize,
+            is_root: bool,
+            low: Option<&K>,
+            high: Option<&K>,
+        ) -> Result<Checked, String> {
+            let len = node.keys.len();
+            if len >= order || (!is_root && len < min_keys(order)) {
+                return Err(format!("Node has {len} keys, order is {order}"));
+            }
+            if node.values.len() != len {
+                return Err("Keys and values differ in length".to_string());
+            }
+
+            let in_order = node.keys.windows(2).all(|pair| pair[0] < pair[1]);
+            let in_bounds = node
+                .keys
+                .first()
+                .zip(low)
+                .is_none_or(|(first, low)| first > low)
+                && node
+                    .keys
+                    .last()
+                    .zip(high)
+                    .is_none_or(|(last, high)| last < high);
+            if !in_order || !in_bounds {
+                return Err("Keys are out of order".to_string());
+            }
+
+            if node.is_leaf() {
+                return Ok(Checked {
+                    depth: 1,
+                    count: len,
+                });
+            }
+            if node.children.len() != len + 1 {
+                return Err(format!("{} children for {len} keys", node.children.len()));
+            }
+
+            let mut depth = None;
+            let mut count = len;
+            for (i, child) in node.children.iter().enumerate() {
+                let low = match i {
+                    0 => low,
+                    _ => Some(&node.keys[i - 1]),
+                };
+                let checked = check(child, order, false, low, node.keys.get(i).or(high))?;
+
+                if depth.is_some_and(|depth| depth != checked.depth) {
+                    return Err("Leaves are on different levels".to_string());
+                }
+                depth = Some(checked.depth);
+                count += checked.count;
+            }
+
+            Ok(Checked {
+                depth: depth.unwrap_or(0) + 1,
+                count,
+            })
+        }
+
+        let checked = check(&self.root, self.order, true, None, None)?;
+        match checked.count == self.size {
+            true => Ok(()),
+            false => Err(format!(
+                "Holds {} entries, len is {}",
+                checked.count, self.size
+            )),
+        }
+    }
+}
+
+pub struct Range<'a, K, V, R> {
+    // Node and the index of its next entry, deepest node on top.
+    stack: Vec<(&'a Node<K, V>, usize)>,
+    range: R,
+}
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for Range<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, i) = self.stack.last_mut()?;
+            let (node, i) = (*node, mem::replace(i, *i + 1));
+            if i == node.keys.len() {
+                self.stack.pop();
+                continue;
+            }
+
+            let key = &node.keys[i];
+            if !is_below_end(&self.range, key) {
+                self.stack.clear();
+                return None;
+            }
+
+            // Everything in the right subtree comes before the next entry.
+            let mut child = node.children.get(i + 1);
+            while let Some(current) = child {
+                self.stack.push((current, 0));
+                child = current.children.first();
+            }
+
+            return Some((key, &node.values[i]));
+        }
+    }
+}
+
+impl super::super::Algorithm for BTree<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let cfg = PrintConfig::new().align(Align::Center);
+        let mut tree = BTree::new(3)?;
+
+        for key in 1..=12 {
+            tree.insert(key, key * 10);
+        }
+        println!("Order 3 after inserting 1..=12:");
+        tree.levels().print(&cfg);
+
+        tree.remove(&1);
+        println!("\nAfter removing 1 (merges cascade up to the root):");
+        tree.levels().print(&cfg);
+
+        tree.remove(&9);
+        println!("\nAfter removing 9 (borrows from a sibling through the parent):");
+        tree.levels().print(&cfg);
+
+        let scan = tree.range(4..9).map(|(k, _)| *k).collect::<Vec<_>>();
+        println!("\nrange(4..9) = {scan:?}");
+        println!("get(7) = {:?}", tree.get(&7));
+
+        match BTree::<u32, u32>::new(2) {
+            Ok(_) => println!("Order 2 accepted?"),
+            Err(e) => println!("BTree::new(2) -> {e}"),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        BTree::showcase().unwrap();
+    }
+
+    #[test]
+    fn order_too_small() {
+        assert_eq!(
+            BTree::<u32, ()>::new(2).err(),
+            Some(BTreeError::OrderTooSmall)
+        );
+        assert!(BTree::<u32, ()>::new(MIN_ORDER).is_ok());
+    }
+
+    #[test]
+    fn against_btree_map() {
+        for order in [3, 4, 5, 8] {
+            let mut random = SeededRandom::new(order as u64);
+            let mut tree = BTree::new(order).unwrap();
+            let mut reference = BTreeMap::new();
+
+            for i in 0..2_000 {
+                let key = random.get_in_range(0, 200u32);
+                match random.get_bool(0.55) {
+                    true => assert_eq!(tree.insert(key, i), reference.insert(key, i)),
+                    false => assert_eq!(tree.remove(&key), reference.remove(&key)),
+                }
+
+                tree.check_invariants().unwrap();
+                assert_eq!(tree.get(&key), reference.get(&key));
+            }
+
+            assert!(tree.iter().eq(reference.iter()));
+            for key in reference.keys() {
+                assert!(tree.remove(key).is_some());
+                tree.check_invariants().unwrap();
+            }
+            assert!(tree.is_empty());
+            assert_eq!(tree.height(), 1);
+        }
+    }
+
+    #[test]
+    fn range() {
+        let mut tree = BTree::new(4).unwrap();
+        let mut reference = BTreeMap::new();
+        for key in (0..60).map(|k| k * 3) {
+            tree.insert(key, ());
+            reference.insert(key, ());
+        }
+
+        for (start, end) in [(0, 200), (10, 20), (9, 21), (150, 300), (50, 50)] {
+            assert!(tree.range(start..end).eq(reference.range(start..end)));
+            assert!(tree.range(start..=end).eq(reference.range(start..=end)));
+        }
+        assert!(tree.range(..40).eq(reference.range(..40)));
+        assert!(tree.range(100..).eq(reference.range(100..)));
+    }
+
+    #[test]
+    fn levels() {
+        let mut tree = BTree::new(3).unwrap();
+        for key in 1..=7 {
+            tree.insert(key, ());
+        }
+
+        assert_eq!(tree.height(), 3);
+        assert_eq!(
+            tree.levels().to_string(),
+            "       [4]\n     [2]  [6]\n[1]  [3]  [5]  [7]\n"
+        );
+    }
+}
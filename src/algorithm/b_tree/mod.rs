@@ -0,0 +1,86 @@
+use std::{
+    fmt::Display,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::core::term::print::Print;
+
+mod classic;
+mod plus;
+pub use classic::*;
+pub use plus::*;
+
+/// Smallest order (max children per node) that can still split and merge.
+pub const MIN_ORDER: usize = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BTreeError {
+    /// Order is below [`MIN_ORDER`].
+    OrderTooSmall,
+}
+
+impl super::Error for BTreeError {}
+impl Display for BTreeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+fn check_order(order: usize) -> Result<(), BTreeError> {
+    match order >= MIN_ORDER {
+        true => Ok(()),
+        false => Err(BTreeError::OrderTooSmall),
+    }
+}
+
+/// Fewest keys a non-root node may hold: every node but the root is at least half full.
+fn min_keys(order: usize) -> usize {
+    order.div_ceil(2) - 1
+}
+
+/// `[k1 k2 ...]`
+fn node_label<K: Display>(keys: &[K]) -> String {
+    let keys = keys.iter().map(ToString::to_string).collect::<Vec<_>>();
+    format!("[{}]", keys.join(" "))
+}
+
+fn is_above_start<K: Ord, R: RangeBounds<K>>(range: &R, key: &K) -> bool {
+    match range.start_bound() {
+        Bound::Included(start) => key >= start,
+        Bound::Excluded(start) => key > start,
+        Bound::Unbounded => true,
+    }
+}
+
+fn is_below_end<K: Ord, R: RangeBounds<K>>(range: &R, key: &K) -> bool {
+    match range.end_bound() {
+        Bound::Included(end) => key <= end,
+        Bound::Excluded(end) => key < end,
+        Bound::Unbounded => true,
+    }
+}
+
+/// Tree drawn level by level, root first, every line centered.
+pub struct Levels {
+    lines: Vec<String>,
+}
+
+impl Display for Levels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for line in &self.lines {
+            let pad = (width - line.chars().count()) / 2;
+            writeln!(f, "{}{line}", " ".repeat(pad))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Print for Levels {}
@@ -0,0 +1,691 @@
+use std::mem;
+
+use super::*;
+use crate::core::term::print::{Align, PrintConfig};
+
+/// Index into [`BPlusTree::nodes`].
+type NodeId = usize;
+
+struct Leaf<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    next: Option<NodeId>,
+}
+
+struct Internal<K> {
+    // `keys[i]` <= every key under `children[i + 1]`, > every key under `children[i]`.
+    keys: Vec<K>,
+    children: Vec<NodeId>,
+}
+
+enum Node<K, V> {
+    Leaf(Leaf<K, V>),
+    Internal(Internal<K>),
+}
+
+impl<K, V> Node<K, V> {
+    fn empty_leaf() -> Self {
+        Self::Leaf(Leaf {
+            keys: vec![],
+            values: vec![],
+            next: None,
+        })
+    }
+
+    fn keys(&self) -> &[K] {
+        match self {
+            Self::Leaf(leaf) => &leaf.keys,
+            Self::Internal(internal) => &internal.keys,
+        }
+    }
+}
+
+enum Insert<K, V> {
+    Inserted,
+    Replaced(V),
+    /// Separator and the new right sibling.
+    Split(K, NodeId),
+}
+
+/// B+ tree map: entries live only in the leaves, which are linked left to right,
+/// internal nodes hold copies of keys for routing.
+/// Nodes sit in an arena and refer to each other by index.
+pub struct BPlusTree<K, V> {
+    nodes: Vec<Node<K, V>>,
+    // Slots of merged-away nodes, reused before the arena grows.
+    free: Vec<NodeId>,
+    root: NodeId,
+    order: usize,
+    size: usize,
+}
+
+impl<K: Ord + Clone, V> BPlusTree<K, V> {
+    /// @If `order` < [`MIN_ORDER`] -> `Err`
+    pub fn new(order: usize) -> Result<Self, BTreeError> {
+        check_order(order)?;
+
+        Ok(Self {
+            nodes: vec![Node::empty_leaf()],
+            free: vec![],
+            root: 0,
+            order,
+            size: 0,
+        })
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Number of levels, a lone root leaf is 1.
+    pub fn height(&self) -> usize {
+        let (mut id, mut height) = (self.root, 1);
+        while let Node::Internal(internal) = &self.nodes[id] {
+            id = internal.children[0];
+            height += 1;
+        }
+
+        height
+    }
+
+    fn alloc(&mut self, node: Node<K, V>) -> NodeId {
+        match self.free.pop() {
+            Some(id) => {
+                self.nodes[id] = node;
+                id
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn release(&mut self, id: NodeId) -> Node<K, V> {
+        self.free.push(id);
+        mem::replace(&mut self.nodes[id], Node::empty_leaf())
+    }
+
+    fn leaf_mut(&mut self, id: NodeId) -> &mut Leaf<K, V> {
+        match &mut self.nodes[id] {
+            Node::Leaf(leaf) => leaf,
+            Node::Internal(_) => unreachable!("Expected a leaf"),
+        }
+    }
+
+    fn internal_mut(&mut self, id: NodeId) -> &mut Internal<K> {
+        match &mut self.nodes[id] {
+            Node::Internal(internal) => internal,
+            Node::Leaf(_) => unreachable!("Expected an internal node"),
+        }
+    }
+
+    /// Index of the child whose subtree may hold `key`.
+    fn child_index(keys: &[K], key: &K) -> usize {
+        keys.partition_point(|separator| separator <= key)
+    }
+
+    /// Leaf whose key range covers `key`.
+    fn find_leaf(&self, key: &K) -> &Leaf<K, V> {
+        let mut id = self.root;
+        loop {
+            match &self.nodes[id] {
+                Node::Leaf(leaf) => return leaf,
+                Node::Internal(internal) => {
+                    id = internal.children[Self::child_index(&internal.keys, key)];
+                }
+            }
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let leaf = self.find_leaf(key);
+        let i = leaf.keys.binary_search(key).ok()?;
+        Some(&leaf.values[i])
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// @Returns previous value of `key`.
+    /// @Mutate splits every overflowing node on the way back up, growing a new root last.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.insert_into(self.root, key, value) {
+            Insert::Replaced(old) => return Some(old),
+            Insert::Inserted => {}
+            Insert::Split(separator, right) => {
+                self.root = self.alloc(Node::Internal(Internal {
+                    keys: vec![separator],
+                    children: vec![self.root, right],
+                }));
+            }
+        }
+
+        self.size += 1;
+        None
+    }
+
+    fn insert_into(&mut self, id: NodeId, key: K, value: V) -> Insert<K, V> {
+        let order = self.order;
+
+        let child = match &mut self.nodes[id] {
+            Node::Leaf(leaf) => {
+                match leaf.keys.binary_search(&key) {
+                    Ok(i) => return Insert::Replaced(mem::replace(&mut leaf.values[i], value)),
+                    Err(i) => {
+                        leaf.keys.insert(i, key);
+                        leaf.values.insert(i, value);
+                    }
+                }
+
+                return match leaf.keys.len() == order {
+                    true => self.split_leaf(id),
+                    false => Insert::Inserted,
+                };
+            }
+            Node::Internal(internal) => Self::child_index(&internal.keys, &key),
+        };
+
+        let child_id = self.internal_mut(id).children[child];
+        match self.insert_into(child_id, key, value) {
+            Insert::Split(separator, right) => {
+                let internal = self.internal_mut(id);
+                internal.keys.insert(child, separator);
+                internal.children.insert(child + 1, right);
+
+                match internal.keys.len() == order {
+                    true => self.split_internal(id),
+                    false => Insert::Inserted,
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// The right half keeps its first key, a copy goes up as the separator.
+    fn split_leaf(&mut self, id: NodeId) -> Insert<K, V> {
+        let leaf = self.leaf_mut(id);
+        let mid = leaf.keys.len() / 2;
+        let right = Leaf {
+            keys: leaf.keys.split_off(mid),
+            values: leaf.values.split_off(mid),
+            next: leaf.next,
+        };
+        let separator = right.keys[0].clone();
+
+        let right = self.alloc(Node::Leaf(right));
+        self.leaf_mut(id).next = Some(right);
+        Insert::Split(separator, right)
+    }
+
+    /// The middle key moves up as the separator.
+    fn split_internal(&mut self, id: NodeId) -> Insert<K, V> {
+        let internal = self.internal_mut(id);
+        let mid = internal.keys.len() / 2;
+        let right = Internal {
+            keys: internal.keys.split_off(mid + 1),
+            children: internal.children.split_off(mid + 1),
+        };
+        let separator = internal
+            .keys
+            .pop()
+            .expect("Node overflowed, so it isn't empty");
+
+        Insert::Split(separator, self.alloc(Node::Internal(right)))
+    }
+
+    /// Separators of removed keys stay in internal nodes, they still route correctly.
+    /// @Mutate refills every underflowing node on the way back up,
+    /// borrowing from a sibling or merging with it.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = self.remove_from(self.root, key)?;
+        self.size -= 1;
+
+        // Root lost its last separator to a merge.
+        if let Node::Internal(root) = &self.nodes[self.root] {
+            if root.keys.is_empty() {
+                let child = root.children[0];
+                self.release(self.root);
+                self.root = child;
+            }
+        }
+
+        Some(removed)
+    }
+
+    fn remove_from(&mut self, id: NodeId, key: &K) -> Option<V> {
+        let child = match &mut self.nodes[id] {
+            Node::Leaf(leaf) => {
+                let i = leaf.keys.binary_search(key).ok()?;
+                leaf.keys.remove(i);
+                return Some(leaf.values.remove(i));
+            }
+            Node::Internal(internal) => Self::child_index(&internal.keys, key),
+        };
+
+        let child_id = self.internal_mut(id).children[child];
+        let removed = self.remove_from(child_id, key)?;
+        self.refill(id, child);
+        Some(removed)
+    }
+
+    /// Restores at least `min_keys` keys in the `i`th child of `parent`.
+    fn refill(&mut self, parent: NodeId, i: usize) {
+        let min = min_keys(self.order);
+        let children = &self.internal_mut(parent).children;
+        let (target, left, right) = (children[i], i.checked_sub(1), children.get(i + 1).copied());
+        let left = left.map(|i| children[i]);
+
+        if self.nodes[target].keys().len() >= min {
+            return;
+        }
+
+        let can_lend = |id: Option<NodeId>| id.is_some_and(|id| self.nodes[id].keys().len() > min);
+        match (can_lend(left), can_lend(right)) {
+            (true, _) => self.borrow_left(parent, i),
+            (false, true) => self.borrow_right(parent, i),
+            // Merge with a sibling.
+            (false, false) => self.merge(parent, i.saturating_sub(1)),
+        }
+    }
+
+    fn borrow_left(&mut self, parent: NodeId, i: usize) {
+        let children = &self.internal_mut(parent).children;
+        let (left, target) = (children[i - 1], children[i]);
+
+        match &mut self.nodes[left] {
+            Node::Leaf(leaf) => {
+                let key = leaf.keys.pop().expect("Lender has keys");
+                let value = leaf.values.pop().expect("Same length as `keys`");
+
+                self.internal_mut(parent).keys[i - 1] = key.clone();
+                let target = self.leaf_mut(target);
+                target.keys.insert(0, key);
+                target.values.insert(0, value);
+            }
+            // Rotate through the parent.
+            Node::Internal(internal) => {
+                let key = internal.keys.pop().expect("Lender has keys");
+                let child = internal.children.pop().expect("One more than `keys`");
+
+                let key = mem::replace(&mut self.internal_mut(parent).keys[i - 1], key);
+                let target = self.internal_mut(target);
+                target.keys.insert(0, key);
+                target.children.insert(0, child);
+            }
+        }
+    }
+
+    fn borrow_right(&mut self, parent: NodeId, i: usize) {
+        let children = &self.internal_mut(parent).children;
+        let (target, right) = (children[i], children[i + 1]);
+
+        match &mut self.nodes[right] {
+            Node::Leaf(leaf) => {
+                let (key, value) = (leaf.keys.remove(0), leaf.values.remove(0));
+                let separator = leaf.keys[0].clone();
+
+                self.internal_mut(parent).keys[i] = separator;
+                let target = self.leaf_mut(target);
+                target.keys.push(key);
+                target.values.push(value);
+            }
+            Node::Internal(internal) => {
+                let (key, child) = (internal.keys.remove(0), internal.children.remove(0));
+
+                let key = mem::replace(&mut self.internal_mut(parent).keys[i], key);
+                let target = self.internal_mut(target);
+                target.keys.push(key);
+                target.children.push(child);
+            }
+        }
+    }
+
+    /// Moves the `j + 1`th child of `parent` into the `j`th.
+    fn merge(&mut self, parent: NodeId, j: usize) {
+        let internal = self.internal_mut(parent);
+        let separator = internal.keys.remove(j);
+        let (left, right) = (internal.children[j], internal.children.remove(j + 1));
+
+        match (self.release(right), &mut self.nodes[left]) {
+            (Node::Leaf(right), Node::Leaf(left)) => {
+                left.keys.extend(right.keys);
+                left.values.extend(right.values);
+                left.next = right.next;
+            }
+            (Node::Internal(right), Node::Internal(left)) => {
+                left.keys.push(separator);
+                left.keys.extend(right.keys);
+                left.children.extend(right.children);
+            }
+            _ => unreachable!("Siblings are on the same level"),
+        }
+    }
+
+    /// Ascending by key.
+    pub fn iter(&self) -> LeafScan<'_, K, V, std::ops::RangeFull> {
+        self.range(..)
+    }
+
+    /// Ascending entries with keys in `range`: one descent to the first leaf,
+    /// then along the leaf links. O(height + entries).
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> LeafScan<'_, K, V, R> {
+        let leaf = match range.start_bound() {
+            Bound::Included(start) | Bound::Excluded(start) => self.find_leaf(start),
+            Bound::Unbounded => self.find_leaf_by(|_| 0),
+        };
+
+        LeafScan {
+            nodes: &self.nodes,
+            index: leaf
+                .keys
+                .partition_point(|key| !is_above_start(&range, key)),
+            leaf: Some(leaf),
+            range,
+        }
+    }
+
+    fn find_leaf_by(&self, child: impl Fn(&Internal<K>) -> usize) -> &Leaf<K, V> {
+        let mut id = self.root;
+        loop {
+            match &self.nodes[id] {
+                Node::Leaf(leaf) => return leaf,
+                Node::Internal(internal) => id = internal.children[child(internal)],
+            }
+        }
+    }
+
+    /// One line per level, nodes as `[k1 k2 ...]`, leaves joined by their links.
+    pub fn levels(&self) -> Levels
+    where
+        K: Display,
+    {
+        let mut lines = vec![];
+        let mut level = vec![self.root];
+        loop {
+            let nodes = level.iter().map(|&id| node_label(self.nodes[id].keys()));
+            let nodes = nodes.collect::<Vec<_>>();
+
+            let mut next = vec![];
+            for &id in &level {
+                if let Node::Internal(internal) = &self.nodes[id] {
+                    next.extend(&internal.children);
+                }
+            }
+
+            if next.is_empty() {
+                lines.push(nodes.join(" → "));
+                return Levels { lines };
+            }
+            lines.push(nodes.join("  "));
+            level = next;
+        }
+    }
+
+    /// Separator bounds, node fill, child counts, leaf depth, leaf links and `len`.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let min = min_keys(self.order);
+        let mut leaves = vec![];
+        self.check_node(self.root, None, None, &mut leaves)?;
+
+        let mut count = 0;
+        for (i, &id) in leaves.iter().enumerate() {
+            let Node::Leaf(leaf) = &self.nodes[id] else {
+                unreachable!("Collected as a leaf");
+            };
+            if id != self.root && leaf.keys.len() < min {
+                return Err(format!(
+                    "Leaf has {} keys, order is {}",
+                    leaf.keys.len(),
+                    self.order
+                ));
+            }
+            if leaf.next != leaves.get(i + 1).copied() {
+                return Err("Leaf links skip or reorder leaves".to_string());
+            }
+            count += leaf.keys.len();
+        }
+
+        match count == self.size {
+            true => Ok(()),
+            false => Err(format!("Holds {count} entries, len is {}", self.size)),
+        }
+    }
+
+    /// Keys under `id` are in `low..high`.
+    /// @Mutate pushes leaves left to right into `leaves`.
+    /// @Returns depth of the subtree.
+    fn check_node(
+        &self,
+        id: NodeId,
+        low: Option<&K>,
+        high: Option<&K>,
+        leaves: &mut Vec<NodeId>,
+    ) -> Result<usize, String> {
+        let keys = self.nodes[id].keys();
+        let in_order = keys.windows(2).all(|pair| pair[0] < pair[1]);
+        let in_bounds = keys
+            .first()
+            .zip(low)
+            .is_none_or(|(first, low)| first >= low)
+            && keys.last().zip(high).is_none_or(|(last, high)| last < high);
+        if !in_order || !in_bounds {
+            return Err("Keys are out of order".to_string());
+        }
+        if keys.len() >= self.order {
+            return Err(format!(
+                "Node has {} keys, order is {}",
+                keys.len(),
+                self.order
+            ));
+        }
+
+        let internal = match &self.nodes[id] {
+            Node::Leaf(leaf) => {
+                if leaf.values.len() != leaf.keys.len() {
+                    return Err("Keys and values differ in length".to_string());
+                }
+                leaves.push(id);
+                return Ok(1);
+            }
+            Node::Internal(internal) => internal,
+        };
+
+        let len = internal.keys.len();
+        if len == 0 || (id != self.root && len < min_keys(self.order)) {
+            return Err(format!(
+                "Internal node has {len} keys, order is {}",
+                self.order
+            ));
+        }
+        if internal.children.len() != len + 1 {
+            return Err(format!(
+                "{} children for {len} keys",
+                internal.children.len()
+            ));
+        }
+
+        let mut depth = None;
+        for (i, &child) in internal.children.iter().enumerate() {
+            let low = match i {
+                0 => low,
+                _ => Some(&internal.keys[i - 1]),
+            };
+            let child_depth = self.check_node(child, low, internal.keys.get(i).or(high), leaves)?;
+
+            if depth.is_some_and(|depth| depth != child_depth) {
+                return Err("Leaves are on different levels".to_string());
+            }
+            depth = Some(child_depth);
+        }
+
+        Ok(depth.unwrap_or(0) + 1)
+    }
+}
+
+/// Walks the leaf links, see [`BPlusTree::range`].
+pub struct LeafScan<'a, K, V, R> {
+    nodes: &'a [Node<K, V>],
+    leaf: Option<&'a Leaf<K, V>>,
+    index: usize,
+    range: R,
+}
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for LeafScan<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let leaf = self.leaf?;
+            let Some(key) = leaf.keys.get(self.index) else {
+                self.leaf = leaf.next.map(|id| match &self.nodes[id] {
+                    Node::Leaf(next) => next,
+                    Node::Internal(_) => unreachable!("Leaves only link to leaves"),
+                });
+                self.index = 0;
+                continue;
+            };
+
+            if !is_below_end(&self.range, key) {
+                self.leaf = None;
+                return None;
+            }
+
+            self.index += 1;
+            return Some((key, &leaf.values[self.index - 1]));
+        }
+    }
+}
+
+impl super::super::Algorithm for BPlusTree<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let cfg = PrintConfig::new().align(Align::Center);
+        let mut tree = BPlusTree::new(4)?;
+
+        for key in 1..=16 {
+            tree.insert(key, key * 10);
+        }
+        println!("Order 4 after inserting 1..=16:");
+        tree.levels().print(&cfg);
+
+        tree.remove(&7);
+        println!("\nAfter removing 7 (separator 7 stays, it still routes):");
+        tree.levels().print(&cfg);
+
+        for key in 1..=6 {
+            tree.remove(&key);
+        }
+        println!("\nAfter removing 1..=6 (leaves merge, links skip the freed ones):");
+        tree.levels().print(&cfg);
+
+        let scan = tree
+            .range(11..=14)
+            .map(|(k, v)| (*k, *v))
+            .collect::<Vec<_>>();
+        println!("\nrange(11..=14) along the leaf links = {scan:?}");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        BPlusTree::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_btree_map() {
+        for order in [3, 4, 5, 8] {
+            let mut random = SeededRandom::new(100 + order as u64);
+            let mut tree = BPlusTree::new(order).unwrap();
+            let mut reference = BTreeMap::new();
+
+            for i in 0..2_000 {
+                let key = random.get_in_range(0, 200u32);
+                match random.get_bool(0.55) {
+                    true => assert_eq!(tree.insert(key, i), reference.insert(key, i)),
+                    false => assert_eq!(tree.remove(&key), reference.remove(&key)),
+                }
+
+                tree.check_invariants().unwrap();
+                assert_eq!(tree.get(&key), reference.get(&key));
+            }
+
+            assert!(tree.iter().eq(reference.iter()));
+            for key in reference.keys() {
+                assert!(tree.remove(key).is_some());
+                tree.check_invariants().unwrap();
+            }
+            assert!(tree.is_empty());
+            assert_eq!(tree.height(), 1);
+        }
+    }
+
+    #[test]
+    fn reuses_freed_nodes() {
+        let mut tree = BPlusTree::new(3).unwrap();
+        for round in 0..5 {
+            for key in 0..100u32 {
+                tree.insert(key, round);
+            }
+            for key in 0..100 {
+                tree.remove(&key);
+            }
+        }
+
+        assert!(tree.nodes.len() < 200);
+        assert_eq!(tree.nodes.len() - tree.free.len(), 1);
+    }
+
+    #[test]
+    fn range() {
+        let mut tree = BPlusTree::new(4).unwrap();
+        let mut reference = BTreeMap::new();
+        for key in (0..60).map(|k| k * 3) {
+            tree.insert(key, ());
+            reference.insert(key, ());
+        }
+        // Leave stale separators behind.
+        for key in (0..60).map(|k| k * 6) {
+            tree.remove(&key);
+            reference.remove(&key);
+        }
+
+        for (start, end) in [(0, 200), (10, 20), (9, 21), (150, 300), (50, 50)] {
+            assert!(tree.range(start..end).eq(reference.range(start..end)));
+            assert!(tree.range(start..=end).eq(reference.range(start..=end)));
+        }
+        assert!(tree.range(..40).eq(reference.range(..40)));
+        assert!(tree.range(100..).eq(reference.range(100..)));
+    }
+
+    #[test]
+    fn levels() {
+        let mut tree = BPlusTree::new(3).unwrap();
+        for key in 1..=4 {
+            tree.insert(key, ());
+        }
+
+        assert_eq!(
+            tree.levels().to_string(),
+            "      [2 3]\n[1] → [2] → [3 4]\n"
+        );
+    }
+}
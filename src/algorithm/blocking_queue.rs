@@ -0,0 +1,444 @@
+use std::{
+    fmt::{Debug, Display},
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    thread,
+    time::Duration,
+};
+
+use super::queue2::RingQueue;
+
+/// Value that could not be pushed is handed back.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PushError<T> {
+    Full(T),
+    Closed(T),
+    Timeout(T),
+}
+
+impl<T> PushError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(value) | Self::Closed(value) | Self::Timeout(value) => value,
+        }
+    }
+}
+
+impl<T: Debug> super::Error for PushError<T> {}
+impl<T: Debug> Display for PushError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PopError {
+    Empty,
+    /// Queue is closed and already drained.
+    Closed,
+    Timeout,
+}
+
+impl super::Error for PopError {}
+impl Display for PopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CapacityError {
+    /// A queue has to hold at least one value.
+    ZeroCapacity,
+}
+
+impl super::Error for CapacityError {}
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+struct State<T> {
+    queue: RingQueue<T>,
+    closed: bool,
+}
+
+/// Bounded multi-producer / multi-consumer FIFO queue.
+///
+/// `push` blocks while the queue is full, `pop` blocks while it's empty.
+/// After `close` every `push` fails, while `pop` still drains what is left.
+/// Share it between threads by reference (`thread::scope`) or through `Arc`.
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+impl<T> BlockingQueue<T> {
+    /// @If `capacity` is 0 -> `Err(ZeroCapacity)`.
+    pub fn new(capacity: usize) -> Result<Self, CapacityError> {
+        if capacity == 0 {
+            return Err(CapacityError::ZeroCapacity);
+        }
+
+        Ok(Self {
+            state: Mutex::new(State {
+                queue: RingQueue::with_capacity(capacity),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        })
+    }
+
+    // `State` is never left half-updated, so a poisoned lock is still usable.
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn is_full(&self, state: &State<T>) -> bool {
+        state.queue.size() >= self.capacity
+    }
+
+    fn push_locked(&self, mut state: MutexGuard<'_, State<T>>, value: T) {
+        state.queue.enqueue(value);
+        drop(state);
+        self.not_empty.notify_one();
+    }
+
+    fn pop_locked(&self, mut state: MutexGuard<'_, State<T>>) -> Result<T, PopError> {
+        let value = state.queue.dequeue().ok_or(PopError::Closed)?;
+        drop(state);
+        self.not_full.notify_one();
+
+        Ok(value)
+    }
+
+    pub fn push(&self, value: T) -> Result<(), PushError<T>> {
+        let state = self
+            .not_full
+            .wait_while(self.lock(), |state| !state.closed && self.is_full(state))
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if state.closed {
+            return Err(PushError::Closed(value));
+        }
+
+        self.push_locked(state, value);
+        Ok(())
+    }
+
+    pub fn try_push(&self, value: T) -> Result<(), PushError<T>> {
+        let state = self.lock();
+
+        if state.closed {
+            return Err(PushError::Closed(value));
+        }
+        if self.is_full(&state) {
+            return Err(PushError::Full(value));
+        }
+
+        self.push_locked(state, value);
+        Ok(())
+    }
+
+    pub fn push_timeout(&self, value: T, timeout: Duration) -> Result<(), PushError<T>> {
+        let (state, _) = self
+            .not_full
+            .wait_timeout_while(self.lock(), timeout, |state| {
+                !state.closed && self.is_full(state)
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if state.closed {
+            return Err(PushError::Closed(value));
+        }
+        if self.is_full(&state) {
+            return Err(PushError::Timeout(value));
+        }
+
+        self.push_locked(state, value);
+        Ok(())
+    }
+
+    pub fn pop(&self) -> Result<T, PopError> {
+        let state = self
+            .not_empty
+            .wait_while(self.lock(), |state| !state.closed && state.queue.is_empty())
+            .unwrap_or_else(PoisonError::into_inner);
+
+        self.pop_locked(state)
+    }
+
+    pub fn try_pop(&self) -> Result<T, PopError> {
+        let state = self.lock();
+
+        if state.queue.is_empty() && !state.closed {
+            return Err(PopError::Empty);
+        }
+
+        self.pop_locked(state)
+    }
+
+    pub fn pop_timeout(&self, timeout: Duration) -> Result<T, PopError> {
+        let (state, _) = self
+            .not_empty
+            .wait_timeout_while(self.lock(), timeout, |state| {
+                !state.closed && state.queue.is_empty()
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if state.queue.is_empty() && !state.closed {
+            return Err(PopError::Timeout);
+        }
+
+        self.pop_locked(state)
+    }
+
+    /// Disconnects producers and wakes up every blocked thread.
+    pub fn close(&self) {
+        self.lock().closed = true;
+
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    pub fn size(&self) -> usize {
+        self.lock().queue.size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl super::Algorithm for BlockingQueue<u32> {
+    fn showcase() -> Result<(), Box<dyn super::Error>> {
+        let queue = BlockingQueue::<u32>::new(4)?;
+
+        let consumed = thread::scope(|s| {
+            let producers = (0..2)
+                .map(|id| {
+                    let queue = &queue;
+                    s.spawn(move || {
+                        for v in (0..10).map(|v| id * 100 + v) {
+                            queue.push(v)?;
+                        }
+                        println!("Producer #{id} is done");
+
+                        Ok::<_, PushError<u32>>(())
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let consumers = (0..3)
+                .map(|id| {
+                    let queue = &queue;
+                    s.spawn(move || {
+                        let mut consumed = vec![];
+                        // `Err(Closed)` once producers are done and the queue is drained.
+                        while let Ok(v) = queue.pop() {
+                            consumed.push(v);
+                        }
+                        println!("Consumer #{id} got: {consumed:?}");
+
+                        consumed.len()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for producer in producers {
+                producer.join().expect("Producer should not panic")?;
+            }
+            queue.close();
+
+            Ok::<_, PushError<u32>>(
+                consumers
+                    .into_iter()
+                    .map(|consumer| consumer.join().expect("Consumer should not panic"))
+                    .sum::<usize>(),
+            )
+        })?;
+
+        println!("Consumed {consumed} values in total");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{atomic::AtomicUsize, atomic::Ordering, Arc},
+        time::Instant,
+    };
+
+    use super::super::Algorithm;
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        BlockingQueue::showcase().unwrap();
+    }
+
+    #[test]
+    fn zero_capacity() {
+        assert_eq!(
+            BlockingQueue::<u32>::new(0).err(),
+            Some(CapacityError::ZeroCapacity)
+        );
+    }
+
+    #[test]
+    fn try_ops() {
+        let queue = BlockingQueue::new(2).unwrap();
+
+        assert_eq!(queue.try_pop(), Err(PopError::Empty));
+        assert_eq!(queue.try_push(1), Ok(()));
+        assert_eq!(queue.try_push(2), Ok(()));
+        assert_eq!(queue.try_push(3), Err(PushError::Full(3)));
+        assert_eq!(queue.size(), 2);
+
+        assert_eq!(queue.try_pop(), Ok(1));
+        assert_eq!(queue.try_push(3), Ok(()));
+        assert_eq!(queue.try_pop(), Ok(2));
+        assert_eq!(queue.try_pop(), Ok(3));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn timeouts() {
+        let queue = BlockingQueue::new(1).unwrap();
+        let timeout = Duration::from_millis(20);
+
+        let start = Instant::now();
+        assert_eq!(queue.pop_timeout(timeout), Err(PopError::Timeout));
+        assert!(start.elapsed() >= timeout);
+
+        queue.push(1).unwrap();
+        let start = Instant::now();
+        assert_eq!(queue.push_timeout(2, timeout), Err(PushError::Timeout(2)));
+        assert!(start.elapsed() >= timeout);
+
+        assert_eq!(queue.pop_timeout(timeout), Ok(1));
+        assert_eq!(queue.push_timeout(2, timeout), Ok(()));
+    }
+
+    #[test]
+    fn close() {
+        let queue = BlockingQueue::new(4).unwrap();
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        queue.close();
+
+        assert!(queue.is_closed());
+        assert_eq!(queue.push(3), Err(PushError::Closed(3)));
+        assert_eq!(queue.try_push(3).unwrap_err().into_inner(), 3);
+
+        // Remaining values are still drained.
+        assert_eq!(queue.pop(), Ok(1));
+        assert_eq!(queue.try_pop(), Ok(2));
+        assert_eq!(queue.pop(), Err(PopError::Closed));
+        assert_eq!(queue.try_pop(), Err(PopError::Closed));
+        assert_eq!(
+            queue.pop_timeout(Duration::from_secs(1)),
+            Err(PopError::Closed)
+        );
+    }
+
+    #[test]
+    fn close_wakes_blocked() {
+        let empty = BlockingQueue::<u32>::new(1).unwrap();
+        let full = BlockingQueue::new(1).unwrap();
+        full.push(0).unwrap();
+
+        thread::scope(|s| {
+            let popper = s.spawn(|| empty.pop());
+            let pusher = s.spawn(|| full.push(1));
+
+            thread::sleep(Duration::from_millis(20));
+            empty.close();
+            full.close();
+
+            assert_eq!(popper.join().unwrap(), Err(PopError::Closed));
+            assert_eq!(pusher.join().unwrap(), Err(PushError::Closed(1)));
+        });
+    }
+
+    #[test]
+    fn push_blocks_until_pop() {
+        let queue = Arc::new(BlockingQueue::new(1).unwrap());
+        queue.push(1).unwrap();
+
+        let pusher = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.push(2))
+        };
+
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(queue.size(), 1);
+        assert_eq!(queue.pop(), Ok(1));
+
+        pusher.join().unwrap().unwrap();
+        assert_eq!(queue.pop(), Ok(2));
+    }
+
+    #[test]
+    fn many_producers_many_consumers() {
+        const PRODUCERS: usize = 4;
+        const PER_PRODUCER: usize = 5_000;
+
+        let queue = BlockingQueue::new(8).unwrap();
+        let popped = AtomicUsize::new(0);
+
+        let sums = thread::scope(|s| {
+            for id in 0..PRODUCERS {
+                let queue = &queue;
+                s.spawn(move || {
+                    (0..PER_PRODUCER).for_each(|v| queue.push(id * PER_PRODUCER + v).unwrap())
+                });
+            }
+
+            let consumers = (0..4)
+                .map(|_| {
+                    s.spawn(|| {
+                        let (mut sum, mut last) = (0, vec![None; PRODUCERS]);
+                        while let Ok(v) = queue.pop() {
+                            // FIFO: values of a single producer come in order.
+                            let id = v / PER_PRODUCER;
+                            assert!(last[id] < Some(v));
+                            last[id] = Some(v);
+
+                            sum += v;
+                            popped.fetch_add(1, Ordering::Relaxed);
+                        }
+                        sum
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            while popped.load(Ordering::Relaxed) < PRODUCERS * PER_PRODUCER {
+                thread::yield_now();
+            }
+            queue.close();
+
+            consumers
+                .into_iter()
+                .map(|consumer| consumer.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        let total = PRODUCERS * PER_PRODUCER;
+        assert_eq!(sums.iter().sum::<usize>(), total * (total - 1) / 2);
+    }
+}
@@ -0,0 +1,359 @@
+use std::{hash::Hash, mem};
+
+use crate::algorithm::{
+    hash_map::{OpenMap, Probing},
+    linked_list::{LinkedList, NodeHandle},
+};
+
+use super::{check_capacity, CacheError, CacheStats, OnEvict};
+
+struct Entry<K, V> {
+    value: V,
+    // Uses so far: the `put` that added the entry, then every `get` and `put`.
+    frequency: usize,
+    // Node of the key in `buckets[frequency]`.
+    handle: NodeHandle<K>,
+}
+
+/// Bounded map that evicts the least frequently used entry,
+/// the least recently used one among equally frequent. Every operation is O(1).
+pub struct LfuCache<K, V> {
+    capacity: usize,
+    entries: OpenMap<K, Entry<K, V>>,
+    // Keys by use count, most recently used first. No empty lists.
+    buckets: OpenMap<usize, LinkedList<K>>,
+    // Lowest key of `buckets`, as long as the cache isn't empty.
+    min_frequency: usize,
+    stats: CacheStats,
+    on_evict: Option<OnEvict<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    /// @If `capacity` is 0 -> `Err(ZeroCapacity)`.
+    pub fn new(capacity: usize) -> Result<Self, CacheError> {
+        check_capacity(capacity)?;
+
+        Ok(Self {
+            capacity,
+            entries: OpenMap::with_capacity(Probing::RobinHood, capacity),
+            buckets: OpenMap::new(Probing::RobinHood),
+            min_frequency: 0,
+            stats: CacheStats::default(),
+            on_evict: None,
+        })
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Replaces the previous callback.
+    pub fn on_evict(&mut self, callback: impl FnMut(K, V) + 'static) {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    /// Times `key` was used, see [`LfuCache::get`] and [`LfuCache::put`].
+    pub fn frequency(&self, key: &K) -> Option<usize> {
+        self.entries.get(key).map(|entry| entry.frequency)
+    }
+
+    /// Pushes `key` into the bucket `frequency`, creating it if needed.
+    fn push_to_bucket(&mut self, frequency: usize, key: K) -> NodeHandle<K> {
+        if !self.buckets.contains_key(&frequency) {
+            self.buckets.insert(frequency, LinkedList::new());
+        }
+
+        let bucket = self.buckets.get_mut(&frequency).expect("Just ensured");
+        bucket.push_front_with_handle(key)
+    }
+
+    /// Moves an existing `key` one bucket up.
+    fn touch(&mut self, key: &K) {
+        let entry = self.entries.get_mut(key).expect("Touched keys exist");
+        let frequency = entry.frequency;
+        let bucket = self
+            .buckets
+            .get_mut(&frequency)
+            .expect("Entries are in their bucket");
+        let key = bucket
+            .remove_handle(&entry.handle)
+            .expect("Entries hold handles of their keys");
+
+        if bucket.is_empty() {
+            self.buckets.remove(&frequency);
+            if self.min_frequency == frequency {
+                self.min_frequency += 1;
+            }
+        }
+
+        let handle = self.push_to_bucket(frequency + 1, key.clone());
+        let entry = self.entries.get_mut(&key).expect("Touched keys exist");
+        entry.frequency += 1;
+        entry.handle = handle;
+    }
+
+    /// @Mutate counts a use of `key`, and a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if !self.entries.contains_key(key) {
+            self.stats.misses += 1;
+            return None;
+        }
+
+        self.touch(key);
+        self.stats.hits += 1;
+        self.peek(key)
+    }
+
+    /// Like `get`, but leaves frequency and counters alone.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// @Returns previous value of `key`.
+    /// @Mutate counts a use of `key`, evicts the least frequently used entry if full.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+            let entry = self.entries.get_mut(&key).expect("Just touched");
+            return Some(mem::replace(&mut entry.value, value));
+        }
+
+        if self.len() == self.capacity {
+            self.evict();
+        }
+        let handle = self.push_to_bucket(1, key.clone());
+        let entry = Entry {
+            value,
+            frequency: 1,
+            handle,
+        };
+        self.entries.insert(key, entry);
+        self.min_frequency = 1;
+        None
+    }
+
+    fn evict(&mut self) {
+        let bucket = self
+            .buckets
+            .get_mut(&self.min_frequency)
+            .expect("Full cache has the bucket `min_frequency`");
+        let key = bucket.pop_back().expect("Buckets aren't empty");
+        if bucket.is_empty() {
+            self.buckets.remove(&self.min_frequency);
+        }
+        let entry = self
+            .entries
+            .remove(&key)
+            .expect("Keys in buckets have entries");
+
+        self.stats.evictions += 1;
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(key, entry.value);
+        }
+    }
+
+    /// Next to be evicted first: least frequently, then least recently used.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        let mut frequencies = self.buckets.iter().map(|(&f, _)| f).collect::<Vec<_>>();
+        frequencies.sort_unstable();
+
+        frequencies.into_iter().flat_map(move |frequency| {
+            self.buckets
+                .get(&frequency)
+                .expect("Collected from `buckets`")
+                .iter()
+                .rev()
+        })
+    }
+}
+
+impl super::super::Algorithm for LfuCache<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut cache = LfuCache::new(3)?;
+        cache.on_evict(|key, value| println!("  evicted {key} = {value}"));
+
+        for (key, value) in [(1, 10), (2, 20), (3, 30)] {
+            cache.put(key, value);
+        }
+        cache.get(&1);
+        cache.get(&1);
+        cache.get(&3);
+        let frequencies = |cache: &LfuCache<u32, u32>| {
+            cache
+                .keys()
+                .map(|&key| (key, cache.frequency(&key).unwrap_or(0)))
+                .collect::<Vec<_>>()
+        };
+        println!(
+            "(key, uses), next to evict first: {:?}",
+            frequencies(&cache)
+        );
+
+        println!("put 4:");
+        cache.put(4, 40);
+        println!("put 5:");
+        cache.put(5, 50);
+        println!("get 2 = {:?}", cache.get(&2));
+        println!("{:?}", frequencies(&cache));
+
+        println!("{}", cache.stats());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    /// Naive LFU: evicts the smallest `(frequency, last use)` by a linear scan.
+    struct Model {
+        capacity: usize,
+        // Key, value, frequency, last use.
+        entries: Vec<(u32, u32, usize, usize)>,
+        evicted: Vec<(u32, u32)>,
+        clock: usize,
+    }
+
+    impl Model {
+        fn touch(&mut self, key: u32) -> Option<&mut (u32, u32, usize, usize)> {
+            self.clock += 1;
+            let entry = self.entries.iter_mut().find(|entry| entry.0 == key)?;
+            entry.2 += 1;
+            entry.3 = self.clock;
+            Some(entry)
+        }
+
+        fn get(&mut self, key: u32) -> Option<u32> {
+            self.touch(key).map(|entry| entry.1)
+        }
+
+        fn put(&mut self, key: u32, value: u32) -> Option<u32> {
+            if let Some(entry) = self.touch(key) {
+                return Some(mem::replace(&mut entry.1, value));
+            }
+
+            if self.entries.len() == self.capacity {
+                let (at, _) = self
+                    .entries
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| (entry.2, entry.3))
+                    .unwrap();
+                let (key, value, _, _) = self.entries.swap_remove(at);
+                self.evicted.push((key, value));
+            }
+            self.entries.push((key, value, 1, self.clock));
+            None
+        }
+
+        fn keys(&self) -> Vec<u32> {
+            let mut entries = self.entries.clone();
+            entries.sort_by_key(|entry| (entry.2, entry.3));
+            entries.into_iter().map(|entry| entry.0).collect()
+        }
+    }
+
+    #[test]
+    fn showcase() {
+        LfuCache::showcase().unwrap();
+    }
+
+    #[test]
+    fn zero_capacity() {
+        assert_eq!(
+            LfuCache::<u32, u32>::new(0).err(),
+            Some(CacheError::ZeroCapacity)
+        );
+    }
+
+    #[test]
+    fn against_model() {
+        let mut random = SeededRandom::new(460);
+
+        for capacity in [1, 2, 5, 16] {
+            let evicted = Rc::new(RefCell::new(vec![]));
+            let mut cache = LfuCache::new(capacity).unwrap();
+            let log = evicted.clone();
+            cache.on_evict(move |key, value| log.borrow_mut().push((key, value)));
+            let mut model = Model {
+                capacity,
+                entries: vec![],
+                evicted: vec![],
+                clock: 0,
+            };
+            let (mut hits, mut misses) = (0, 0);
+
+            for i in 0..3_000 {
+                // Skewed, so frequencies actually differ.
+                let key = random.get_in_range(0, 6u32) * random.get_in_range(0, 5u32);
+                match random.get_in_range(0, 3u8) {
+                    0 => assert_eq!(cache.put(key, i), model.put(key, i)),
+                    1 => {
+                        let expected = model.get(key);
+                        match expected {
+                            Some(_) => hits += 1,
+                            None => misses += 1,
+                        }
+                        assert_eq!(cache.get(&key).copied(), expected);
+                    }
+                    _ => assert_eq!(
+                        cache.peek(&key).copied(),
+                        model.entries.iter().find(|e| e.0 == key).map(|e| e.1)
+                    ),
+                }
+
+                assert_eq!(cache.keys().copied().collect::<Vec<_>>(), model.keys());
+                for entry in &model.entries {
+                    assert_eq!(cache.frequency(&entry.0), Some(entry.2));
+                }
+            }
+
+            assert_eq!(*evicted.borrow(), model.evicted);
+            assert_eq!(
+                cache.stats(),
+                CacheStats {
+                    hits,
+                    misses,
+                    evictions: model.evicted.len(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn frequent_entry_survives() {
+        let mut cache = LfuCache::new(2).unwrap();
+        cache.put("hot", 0);
+        for _ in 0..5 {
+            cache.get(&"hot");
+        }
+
+        for (i, key) in ["a", "b", "c", "d"].into_iter().enumerate() {
+            cache.put(key, i);
+        }
+        assert_eq!(cache.peek(&"hot"), Some(&0));
+        assert_eq!(cache.peek(&"d"), Some(&3));
+        assert!(!cache.contains_key(&"c"));
+        assert_eq!(cache.frequency(&"hot"), Some(6));
+    }
+}
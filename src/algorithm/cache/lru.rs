@@ -0,0 +1,248 @@
+use std::{hash::Hash, mem};
+
+use crate::algorithm::{
+    hash_map::{OpenMap, Probing},
+    linked_list::{LinkedList, NodeHandle},
+};
+
+use super::{check_capacity, CacheError, CacheStats, OnEvict};
+
+/// Bounded map that evicts the least recently used entry. Every operation is O(1).
+pub struct LruCache<K, V> {
+    capacity: usize,
+    // Value and the key's node in `order`.
+    entries: OpenMap<K, (V, NodeHandle<K>)>,
+    // Most recently used first.
+    order: LinkedList<K>,
+    stats: CacheStats,
+    on_evict: Option<OnEvict<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// @If `capacity` is 0 -> `Err(ZeroCapacity)`.
+    pub fn new(capacity: usize) -> Result<Self, CacheError> {
+        check_capacity(capacity)?;
+
+        Ok(Self {
+            capacity,
+            entries: OpenMap::with_capacity(Probing::RobinHood, capacity),
+            order: LinkedList::new(),
+            stats: CacheStats::default(),
+            on_evict: None,
+        })
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Replaces the previous callback.
+    pub fn on_evict(&mut self, callback: impl FnMut(K, V) + 'static) {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    /// @Mutate marks `key` as the most recently used, counts a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let Some((value, handle)) = self.entries.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+
+        self.order
+            .move_to_front(handle)
+            .expect("Entries hold handles of their keys");
+        self.stats.hits += 1;
+        Some(value)
+    }
+
+    /// Like `get`, but leaves recency and counters alone.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// @Returns previous value of `key`.
+    /// @Mutate marks `key` as the most recently used, evicts the least recently used if full.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some((old, handle)) = self.entries.get_mut(&key) {
+            self.order
+                .move_to_front(handle)
+                .expect("Entries hold handles of their keys");
+            return Some(mem::replace(old, value));
+        }
+
+        if self.len() == self.capacity {
+            self.evict();
+        }
+        let handle = self.order.push_front_with_handle(key.clone());
+        self.entries.insert(key, (value, handle));
+        None
+    }
+
+    fn evict(&mut self) {
+        let key = self.order.pop_back().expect("Full cache has entries");
+        let (value, _) = self
+            .entries
+            .remove(&key)
+            .expect("Keys in `order` have entries");
+
+        self.stats.evictions += 1;
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(key, value);
+        }
+    }
+
+    /// Most recently used first.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.order.iter()
+    }
+}
+
+impl super::super::Algorithm for LruCache<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut cache = LruCache::new(3)?;
+        cache.on_evict(|key, value| println!("  evicted {key} = {value}"));
+
+        for (key, value) in [(1, 10), (2, 20), (3, 30)] {
+            cache.put(key, value);
+        }
+        println!("put 1, 2, 3: {:?}", cache.keys().collect::<Vec<_>>());
+
+        println!("get 1 = {:?}", cache.get(&1));
+        println!("put 4:");
+        cache.put(4, 40);
+        println!("{:?}", cache.keys().collect::<Vec<_>>());
+
+        println!("peek 3 = {:?} (doesn't refresh)", cache.peek(&3));
+        println!("put 5:");
+        cache.put(5, 50);
+        println!("get 3 = {:?}", cache.get(&3));
+        println!("{:?}", cache.keys().collect::<Vec<_>>());
+
+        println!("{}", cache.stats());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    /// Naive LRU: linear scans over entries kept most recently used first.
+    struct Model {
+        capacity: usize,
+        entries: Vec<(u32, u32)>,
+        evicted: Vec<(u32, u32)>,
+    }
+
+    impl Model {
+        fn get(&mut self, key: u32) -> Option<u32> {
+            let at = self.entries.iter().position(|&(k, _)| k == key)?;
+            let entry = self.entries.remove(at);
+            self.entries.insert(0, entry);
+            Some(entry.1)
+        }
+
+        fn put(&mut self, key: u32, value: u32) -> Option<u32> {
+            let old = match self.entries.iter().position(|&(k, _)| k == key) {
+                Some(at) => Some(self.entries.remove(at).1),
+                None if self.entries.len() == self.capacity => {
+                    self.evicted.push(self.entries.pop().unwrap());
+                    None
+                }
+                None => None,
+            };
+            self.entries.insert(0, (key, value));
+            old
+        }
+    }
+
+    #[test]
+    fn showcase() {
+        LruCache::showcase().unwrap();
+    }
+
+    #[test]
+    fn zero_capacity() {
+        assert_eq!(
+            LruCache::<u32, u32>::new(0).err(),
+            Some(CacheError::ZeroCapacity)
+        );
+    }
+
+    #[test]
+    fn against_model() {
+        let mut random = SeededRandom::new(46);
+
+        for capacity in [1, 2, 5, 16] {
+            let evicted = Rc::new(RefCell::new(vec![]));
+            let mut cache = LruCache::new(capacity).unwrap();
+            let log = evicted.clone();
+            cache.on_evict(move |key, value| log.borrow_mut().push((key, value)));
+            let mut model = Model {
+                capacity,
+                entries: vec![],
+                evicted: vec![],
+            };
+            let (mut hits, mut misses) = (0, 0);
+
+            for i in 0..3_000 {
+                let key = random.get_in_range(0, 24u32);
+                match random.get_in_range(0, 3u8) {
+                    0 => assert_eq!(cache.put(key, i), model.put(key, i)),
+                    1 => {
+                        let expected = model.get(key);
+                        match expected {
+                            Some(_) => hits += 1,
+                            None => misses += 1,
+                        }
+                        assert_eq!(cache.get(&key).copied(), expected);
+                    }
+                    _ => assert_eq!(
+                        cache.peek(&key).copied(),
+                        model
+                            .entries
+                            .iter()
+                            .find(|&&(k, _)| k == key)
+                            .map(|&(_, v)| v)
+                    ),
+                }
+
+                assert!(cache
+                    .keys()
+                    .copied()
+                    .eq(model.entries.iter().map(|&(k, _)| k)));
+                assert_eq!(cache.len(), model.entries.len());
+            }
+
+            assert_eq!(*evicted.borrow(), model.evicted);
+            assert_eq!(
+                cache.stats(),
+                CacheStats {
+                    hits,
+                    misses,
+                    evictions: model.evicted.len(),
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+mod lfu;
+mod lru;
+pub use lfu::*;
+pub use lru::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CacheError {
+    /// A cache has to hold at least one entry.
+    ZeroCapacity,
+}
+
+impl super::Error for CacheError {}
+impl Display for CacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+fn check_capacity(capacity: usize) -> Result<(), CacheError> {
+    match capacity {
+        0 => Err(CacheError::ZeroCapacity),
+        _ => Ok(()),
+    }
+}
+
+/// Called with every entry pushed out to make room.
+type OnEvict<K, V> = Box<dyn FnMut(K, V)>;
+
+/// Counters of `get` calls and evictions. `peek` and `put` don't count as lookups.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+}
+
+impl CacheStats {
+    /// Share of lookups that hit, `0` before any.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "hits {}, misses {}, hit rate {:.2}, evictions {}",
+            self.hits,
+            self.misses,
+            self.hit_rate(),
+            self.evictions
+        )
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use super::RollbackDisjointSet;
+
+/// Step of a changing undirected graph, see [`offline_connectivity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// Adds an edge, possibly a parallel one.
+    Connect(usize, usize),
+    /// Removes one copy of an edge, if there is any.
+    Disconnect(usize, usize),
+    /// Asks whether two vertices are connected right now.
+    Connected(usize, usize),
+}
+
+/// Segment tree over time: the node for `lo..hi` holds the edges alive for all of it.
+struct Segments {
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl Segments {
+    fn add(
+        &mut self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        (from, to): (usize, usize),
+        edge: (usize, usize),
+    ) {
+        if to <= lo || hi <= from {
+            return;
+        }
+        if from <= lo && hi <= to {
+            self.edges[node].push(edge);
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        self.add(2 * node, (lo, mid), (from, to), edge);
+        self.add(2 * node + 1, (mid, hi), (from, to), edge);
+    }
+
+    fn solve(
+        &self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        events: &[Event],
+        set: &mut RollbackDisjointSet,
+        answers: &mut Vec<bool>,
+    ) {
+        let checkpoint = set.checkpoint();
+        for &(a, b) in &self.edges[node] {
+            set.union(a, b);
+        }
+
+        match hi - lo {
+            1 => {
+                if let Event::Connected(a, b) = events[lo] {
+                    answers.push(set.same(a, b));
+                }
+            }
+            _ => {
+                let mid = (lo + hi) / 2;
+                self.solve(2 * node, (lo, mid), events, set, answers);
+                self.solve(2 * node + 1, (mid, hi), events, set, answers);
+            }
+        }
+
+        set.rollback(checkpoint);
+    }
+}
+
+/// Answers every [`Event::Connected`] of `events` over vertices `0..len`, in order.
+///
+/// Each edge lives over a time interval, which splits into O(log t) segment tree nodes;
+/// a DFS over the tree unions edges on the way down and rolls them back on the way up.
+/// O(t log t log n) in total for `t` events.
+pub fn offline_connectivity(len: usize, events: &[Event]) -> Vec<bool> {
+    let time = events.len();
+    if time == 0 {
+        return vec![];
+    }
+
+    let mut segments = Segments {
+        edges: vec![vec![]; 4 * time],
+    };
+    // Edge -> times it was connected at, without a matching disconnect yet.
+    let mut open = HashMap::<(usize, usize), Vec<usize>>::new();
+    let key = |a: usize, b: usize| (a.min(b), a.max(b));
+
+    for (t, event) in events.iter().enumerate() {
+        match *event {
+            Event::Connect(a, b) => open.entry(key(a, b)).or_default().push(t),
+            Event::Disconnect(a, b) => {
+                let edge = key(a, b);
+                if let Some(start) = open.get_mut(&edge).and_then(Vec::pop) {
+                    segments.add(1, (0, time), (start, t), edge);
+                }
+            }
+            Event::Connected(..) => {}
+        }
+    }
+    for (edge, starts) in open {
+        for start in starts {
+            segments.add(1, (0, time), (start, time), edge);
+        }
+    }
+
+    let mut set = RollbackDisjointSet::new(len);
+    let mut answers = vec![];
+    segments.solve(1, (0, time), events, &mut set, &mut answers);
+    answers
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithm::disjoint_set::{DisjointSet, UnionBy},
+        random::SeededRandom,
+    };
+
+    use super::*;
+
+    /// Rebuilds the components from the current edges for every query.
+    fn naive(len: usize, events: &[Event]) -> Vec<bool> {
+        let mut edges = Vec::<(usize, usize)>::new();
+        let mut answers = vec![];
+
+        for event in events {
+            match *event {
+                Event::Connect(a, b) => edges.push((a, b)),
+                Event::Disconnect(a, b) => {
+                    let at = edges
+                        .iter()
+                        .position(|&edge| edge == (a, b) || edge == (b, a));
+                    if let Some(at) = at {
+                        edges.swap_remove(at);
+                    }
+                }
+                Event::Connected(a, b) => {
+                    let mut set = DisjointSet::new(len, UnionBy::Rank);
+                    edges.iter().for_each(|&(x, y)| {
+                        set.union(x, y);
+                    });
+                    answers.push(set.same(a, b));
+                }
+            }
+        }
+
+        answers
+    }
+
+    #[test]
+    fn against_naive() {
+        let mut random = SeededRandom::new(472);
+        let len = 8;
+        let events = (0..600)
+            .map(|_| {
+                let (a, b) = (random.get_in_range(0, len), random.get_in_range(0, len));
+                match random.get_in_range(0, 3) {
+                    0 => Event::Connect(a, b),
+                    1 => Event::Disconnect(a, b),
+                    _ => Event::Connected(a, b),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let answers = offline_connectivity(len, &events);
+        assert_eq!(answers, naive(len, &events));
+        assert!(answers.contains(&true) && answers.contains(&false));
+    }
+
+    #[test]
+    fn parallel_edges_and_edge_cases() {
+        let events = [
+            Event::Connected(0, 0),
+            Event::Disconnect(0, 1),
+            Event::Connect(0, 1),
+            Event::Connect(1, 0),
+            Event::Disconnect(0, 1),
+            Event::Connected(1, 0),
+            Event::Disconnect(1, 0),
+            Event::Connected(0, 1),
+        ];
+
+        assert_eq!(offline_connectivity(2, &events), [true, true, false]);
+        assert!(offline_connectivity(2, &[]).is_empty());
+    }
+}
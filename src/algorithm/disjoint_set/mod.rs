@@ -0,0 +1,24 @@
+mod connectivity;
+mod rollback;
+mod union_find;
+pub use connectivity::*;
+pub use rollback::*;
+pub use union_find::*;
+
+/// Elements grouped by root, each group ascending, groups by their smallest element.
+fn groups(len: usize, mut find: impl FnMut(usize) -> usize) -> Vec<Vec<usize>> {
+    // Root -> position of its group in the result.
+    let mut slots = vec![usize::MAX; len];
+    let mut groups = Vec::<Vec<usize>>::new();
+
+    for x in 0..len {
+        let root = find(x);
+        if slots[root] == usize::MAX {
+            slots[root] = groups.len();
+            groups.push(vec![]);
+        }
+        groups[slots[root]].push(x);
+    }
+
+    groups
+}
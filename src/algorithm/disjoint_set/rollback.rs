@@ -0,0 +1,219 @@
+use super::{groups, offline_connectivity, Event};
+
+/// Point in the history of a [`RollbackDisjointSet`] to roll back to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checkpoint(usize);
+
+/// Disjoint set that can undo unions in reverse order.
+///
+/// No path compression, so `find` is O(log n) through union by size
+/// and every union changes exactly one parent, which is cheap to put back.
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    // Child root of every successful union, latest on top.
+    history: Vec<usize>,
+}
+
+impl RollbackDisjointSet {
+    /// `len` singletons.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+            history: vec![],
+        }
+    }
+
+    /// Elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Representative of the component of `x`.
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    pub fn same(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// @Returns `false` if `a` and `b` were already together, nothing to undo then.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = match self.size[a] >= self.size[b] {
+            true => (a, b),
+            false => (b, a),
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.components -= 1;
+        self.history.push(child);
+        true
+    }
+
+    /// Elements in the component of `x`.
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// Every component, ascending, ordered by smallest element.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        groups(self.len(), |x| self.find(x))
+    }
+
+    /// Current state, for [`RollbackDisjointSet::rollback`].
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.history.len())
+    }
+
+    /// Undoes the latest successful union.
+    /// @Returns the two components it had joined, as their roots.
+    pub fn undo(&mut self) -> Option<(usize, usize)> {
+        let child = self.history.pop()?;
+        let root = self.parent[child];
+
+        self.parent[child] = child;
+        self.size[root] -= self.size[child];
+        self.components += 1;
+        Some((root, child))
+    }
+
+    /// Undoes every union since `checkpoint`.
+    /// @If `checkpoint` was already rolled back past, does nothing.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        while self.history.len() > checkpoint.0 {
+            self.undo();
+        }
+    }
+}
+
+impl super::super::Algorithm for RollbackDisjointSet {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut set = RollbackDisjointSet::new(6);
+        set.union(0, 1);
+        set.union(2, 3);
+        println!("union(0, 1), union(2, 3): {:?}", set.groups());
+
+        let checkpoint = set.checkpoint();
+        set.union(1, 2);
+        set.union(4, 5);
+        set.union(3, 5);
+        println!("union(1, 2), union(4, 5), union(3, 5): {:?}", set.groups());
+
+        println!("undo() = {:?}: {:?}", set.undo(), set.groups());
+        set.rollback(checkpoint);
+        println!("rollback to the checkpoint: {:?}", set.groups());
+
+        // Edges come and go, queries are answered all at once afterwards.
+        let events = [
+            Event::Connect(0, 1),
+            Event::Connect(1, 2),
+            Event::Connected(0, 2),
+            Event::Disconnect(0, 1),
+            Event::Connected(0, 2),
+            Event::Connect(2, 0),
+            Event::Connected(0, 1),
+        ];
+        println!("\nEvents: {events:?}");
+        println!("Answers: {:?}", offline_connectivity(3, &events));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithm::{
+            disjoint_set::{DisjointSet, UnionBy},
+            Algorithm,
+        },
+        random::SeededRandom,
+    };
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        RollbackDisjointSet::showcase().unwrap();
+    }
+
+    #[test]
+    fn rollback_restores_every_state() {
+        let mut random = SeededRandom::new(470);
+        let len = 60;
+        let mut set = RollbackDisjointSet::new(len);
+        // Groups and components at each checkpoint.
+        let mut states = vec![];
+
+        for _ in 0..20 {
+            states.push((set.checkpoint(), set.groups(), set.components()));
+            for _ in 0..random.get_in_range(0, 8) {
+                set.union(random.get_in_range(0, len), random.get_in_range(0, len));
+            }
+        }
+
+        while let Some((checkpoint, groups, components)) = states.pop() {
+            set.rollback(checkpoint);
+            assert_eq!(set.groups(), groups);
+            assert_eq!(set.components(), components);
+            for group in &groups {
+                assert!(group.iter().all(|&x| set.size_of(x) == group.len()));
+            }
+        }
+        assert_eq!(set.components(), len);
+        assert_eq!(set.undo(), None);
+    }
+
+    #[test]
+    fn matches_compressed() {
+        let mut random = SeededRandom::new(471);
+        let len = 100;
+        let mut set = RollbackDisjointSet::new(len);
+        let mut compressed = DisjointSet::new(len, UnionBy::Size);
+
+        for _ in 0..150 {
+            let (a, b) = (random.get_in_range(0, len), random.get_in_range(0, len));
+            assert_eq!(set.union(a, b), compressed.union(a, b));
+            assert_eq!(set.size_of(a), compressed.size_of(a));
+        }
+        assert_eq!(set.groups(), compressed.groups());
+        assert_eq!(set.components(), compressed.components());
+    }
+
+    #[test]
+    fn undo_and_stale_checkpoint() {
+        let mut set = RollbackDisjointSet::new(4);
+        let empty = set.checkpoint();
+        assert!(set.union(0, 1));
+        assert!(!set.union(1, 0));
+        let one = set.checkpoint();
+        assert!(set.union(2, 1));
+
+        assert_eq!(set.undo(), Some((0, 2)));
+        set.rollback(empty);
+        // Already behind `one`, nothing left to undo.
+        set.rollback(one);
+        assert_eq!(set.components(), 4);
+        assert!(!set.same(0, 1));
+    }
+}
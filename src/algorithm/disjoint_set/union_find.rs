@@ -0,0 +1,258 @@
+use std::mem;
+
+use super::groups;
+
+/// Which root stays on top when two components merge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnionBy {
+    /// The one whose tree is taller, as estimated before compression.
+    #[default]
+    Rank,
+    /// The one with more elements.
+    Size,
+}
+
+/// Partition of `0..len` into components, with path compression.
+/// Every operation is O(α(n)) amortized, practically constant.
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    // Only meaningful for roots.
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    components: usize,
+    union_by: UnionBy,
+}
+
+impl DisjointSet {
+    /// `len` singletons.
+    pub fn new(len: usize, union_by: UnionBy) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            components: len,
+            union_by,
+        }
+    }
+
+    /// Elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    pub fn union_by(&self) -> UnionBy {
+        self.union_by
+    }
+
+    /// @Returns the new singleton, numbered `len()` before the call.
+    pub fn make_set(&mut self) -> usize {
+        let x = self.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        self.size.push(1);
+        self.components += 1;
+        x
+    }
+
+    /// Representative of the component of `x`.
+    /// @Mutate points every element on the way straight at the root.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut x = x;
+        while x != root {
+            x = mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// @Returns `false` if `a` and `b` were already together.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let a_on_top = match self.union_by {
+            UnionBy::Rank => self.rank[a] >= self.rank[b],
+            UnionBy::Size => self.size[a] >= self.size[b],
+        };
+        let (root, child) = match a_on_top {
+            true => (a, b),
+            false => (b, a),
+        };
+
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    /// Elements in the component of `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Every component, ascending, ordered by smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        groups(self.len(), |x| self.find(x))
+    }
+
+    /// Members of the component of `x`, ascending. O(n).
+    pub fn group_of(&mut self, x: usize) -> Vec<usize> {
+        let root = self.find(x);
+        (0..self.len()).filter(|&y| self.find(y) == root).collect()
+    }
+
+    /// Tallest path to a root, to see what compression did. O(n log n).
+    pub fn depth(&self) -> usize {
+        (0..self.len())
+            .map(|mut x| {
+                let mut depth = 0;
+                while self.parent[x] != x {
+                    x = self.parent[x];
+                    depth += 1;
+                }
+                depth
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl super::super::Algorithm for DisjointSet {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut set = DisjointSet::new(10, UnionBy::Rank);
+        for (a, b) in [(0, 1), (2, 3), (1, 3), (5, 6), (7, 8), (8, 9), (6, 9)] {
+            set.union(a, b);
+            println!("union({a}, {b})");
+        }
+
+        println!("{} components: {:?}", set.components(), set.groups());
+        println!("same(0, 2) = {}", set.same(0, 2));
+        println!("same(4, 5) = {}", set.same(4, 5));
+        println!("size_of(9) = {}", set.size_of(9));
+
+        let x = set.make_set();
+        set.union(x, 4);
+        println!("make_set() = {x}, union({x}, 4): {:?}", set.groups());
+
+        // Linking the other way round would make a chain 999 deep.
+        let mut chain = DisjointSet::new(1_000, UnionBy::Size);
+        for x in 1..1_000 {
+            chain.union(x - 1, x);
+        }
+        println!("1000 elements joined in a row: depth {}", chain.depth());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        DisjointSet::showcase().unwrap();
+    }
+
+    /// Component label per element, relabeled by a full scan on every union.
+    fn naive_union(labels: &mut [usize], a: usize, b: usize) -> bool {
+        let (from, to) = (labels[b], labels[a]);
+        labels
+            .iter_mut()
+            .filter(|l| **l == from)
+            .for_each(|l| *l = to);
+        from != to
+    }
+
+    #[test]
+    fn against_labels() {
+        for union_by in [UnionBy::Rank, UnionBy::Size] {
+            let mut random = SeededRandom::new(47);
+            let len = 200;
+            let mut set = DisjointSet::new(len, union_by);
+            let mut labels = (0..len).collect::<Vec<_>>();
+
+            for _ in 0..400 {
+                let a = random.get_in_range(0, len);
+                let b = random.get_in_range(0, len);
+                match random.get_bool(0.5) {
+                    true => assert_eq!(set.union(a, b), naive_union(&mut labels, a, b)),
+                    false => assert_eq!(set.same(a, b), labels[a] == labels[b]),
+                }
+
+                let count = |label| labels.iter().filter(|&&l| l == label).count();
+                assert_eq!(set.size_of(a), count(labels[a]));
+            }
+
+            let mut expected = labels.clone();
+            expected.sort_unstable();
+            expected.dedup();
+            assert_eq!(set.components(), expected.len());
+
+            for group in set.groups() {
+                assert!(group.iter().all(|&x| labels[x] == labels[group[0]]));
+                assert_eq!(group, set.group_of(group[0]));
+            }
+            assert_eq!(set.groups().concat().len(), len);
+        }
+    }
+
+    #[test]
+    fn union_by_keeps_trees_shallow() {
+        for union_by in [UnionBy::Rank, UnionBy::Size] {
+            let mut set = DisjointSet::new(1 << 10, union_by);
+            // Equal halves merged level by level: the tallest trees union by rank/size allows.
+            let mut step = 1;
+            while step < set.len() {
+                for x in (0..set.len()).step_by(2 * step) {
+                    let (a, b) = (set.parent[x], set.parent[x + step]);
+                    set.union(a, b);
+                }
+                step *= 2;
+            }
+
+            assert_eq!(set.components(), 1);
+            assert!(set.depth() <= 10);
+        }
+    }
+
+    #[test]
+    fn make_set_and_groups() {
+        let mut set = DisjointSet::new(0, UnionBy::Size);
+        assert!(set.is_empty());
+        assert!(set.groups().is_empty());
+
+        let (a, b, c) = (set.make_set(), set.make_set(), set.make_set());
+        assert_eq!((a, b, c), (0, 1, 2));
+        assert!(set.union(c, a));
+        assert!(!set.union(a, c));
+
+        assert_eq!(set.groups(), [vec![0, 2], vec![1]]);
+        assert_eq!(set.components(), 2);
+        assert_eq!(set.size_of(2), 2);
+    }
+}
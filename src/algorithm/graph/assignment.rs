@@ -0,0 +1,195 @@
+use crate::{
+    core::term::print::FormatConfig,
+    matrix::{MatrixOperations, SquareMatrix},
+};
+
+use super::GraphError;
+
+/// One column per row, no column twice.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Assignment {
+    /// Column picked for every row.
+    pub columns: Vec<usize>,
+    pub cost: f32,
+}
+
+/// Cheapest assignment of rows (workers) to columns (jobs), `cost[(i, j)]` for row `i` doing
+/// column `j`. For the most profitable one, negate the matrix.
+///
+/// Hungarian algorithm with potentials: rows join one at a time, each along a cheapest
+/// augmenting path under the reduced costs, which stay non-negative. O(n³).
+/// Potentials are kept in `f64`, so the sums pick up less rounding than the matrix has.
+///
+/// @If some cost isn't finite -> `Err(NonFiniteCost)`.
+pub fn hungarian(cost: &SquareMatrix) -> Result<Assignment, GraphError> {
+    let n = cost.get_size();
+    if !cost.iter().all(|c| c.is_finite()) {
+        return Err(GraphError::NonFiniteCost);
+    }
+    let cost_of = |row: usize, column: usize| cost[(row - 1, column - 1)] as f64;
+
+    // 1-based, column 0 is a dummy holding the row being added.
+    let mut row_potential = vec![0f64; n + 1];
+    let mut column_potential = vec![0f64; n + 1];
+    // Row assigned to each column, 0 for none.
+    let mut row_of = vec![0; n + 1];
+    // Column before each one on the cheapest path found.
+    let mut previous = vec![0; n + 1];
+
+    for row in 1..=n {
+        row_of[0] = row;
+        let mut column = 0;
+        // Cheapest reduced cost into each column from the rows reached so far.
+        let mut cheapest = vec![f64::INFINITY; n + 1];
+        let mut reached = vec![false; n + 1];
+
+        // Grows the tree of reached columns until it hits a free one.
+        while row_of[column] != 0 {
+            reached[column] = true;
+            let from = row_of[column];
+            let (mut delta, mut next) = (f64::INFINITY, 0);
+            for j in (1..=n).filter(|&j| !reached[j]) {
+                let reduced = cost_of(from, j) - row_potential[from] - column_potential[j];
+                if reduced < cheapest[j] {
+                    cheapest[j] = reduced;
+                    previous[j] = column;
+                }
+                if cheapest[j] < delta {
+                    (delta, next) = (cheapest[j], j);
+                }
+            }
+
+            for j in 0..=n {
+                match reached[j] {
+                    true => {
+                        row_potential[row_of[j]] += delta;
+                        column_potential[j] -= delta;
+                    }
+                    false => cheapest[j] -= delta,
+                }
+            }
+            column = next;
+        }
+
+        // Shifts every row on the path one column over.
+        while column != 0 {
+            let before = previous[column];
+            row_of[column] = row_of[before];
+            column = before;
+        }
+    }
+
+    let mut columns = vec![0; n];
+    for column in 1..=n {
+        columns[row_of[column] - 1] = column - 1;
+    }
+    let total = (0..n)
+        .map(|row| cost[(row, columns[row])] as f64)
+        .sum::<f64>();
+
+    Ok(Assignment {
+        columns,
+        cost: total as f32,
+    })
+}
+
+/// Part of [`super::Graph::showcase`].
+pub(super) fn showcase() -> Result<(), Box<dyn crate::algorithm::Error>> {
+    // Hours each of 4 people needs for each of 4 tasks.
+    let hours = [
+        [9., 2., 7., 8.],
+        [6., 4., 3., 7.],
+        [5., 8., 1., 8.],
+        [7., 6., 9., 4.],
+    ];
+    let mut cost = SquareMatrix::new(hours.len());
+    for (i, row) in hours.iter().enumerate() {
+        for (j, &h) in row.iter().enumerate() {
+            cost[(i, j)] = h;
+        }
+    }
+
+    let config = FormatConfig::new()
+        .gaps(2, 0)
+        .precision(0)
+        .title(Some("cost"));
+    println!("{}", cost.display_with(&config));
+    let assignment = hungarian(&cost)?;
+    println!(
+        "hungarian() = {:?}, cost {}",
+        assignment.columns, assignment.cost
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::SeededRandom;
+
+    use super::*;
+
+    /// Cheapest over every permutation.
+    fn brute_force(cost: &SquareMatrix) -> f32 {
+        fn go(cost: &SquareMatrix, row: usize, used: &mut [bool]) -> f32 {
+            if row == used.len() {
+                return 0.;
+            }
+
+            let mut best = f32::INFINITY;
+            for column in 0..used.len() {
+                if !used[column] {
+                    used[column] = true;
+                    best = best.min(cost[(row, column)] + go(cost, row + 1, used));
+                    used[column] = false;
+                }
+            }
+            best
+        }
+
+        go(cost, 0, &mut vec![false; cost.get_size()])
+    }
+
+    #[test]
+    fn against_brute_force() {
+        let mut random = SeededRandom::new(492);
+        for _ in 0..200 {
+            let n = random.get_in_range(0, 7);
+            let mut cost = SquareMatrix::new(n);
+            for cell in cost.iter_mut() {
+                *cell = random.get_in_range(-10, 30) as f32;
+            }
+
+            let assignment = hungarian(&cost).unwrap();
+            let mut columns = assignment.columns.clone();
+            columns.sort_unstable();
+            assert_eq!(columns, (0..n).collect::<Vec<_>>());
+
+            let total = (0..n)
+                .map(|row| cost[(row, assignment.columns[row])])
+                .sum::<f32>();
+            assert_eq!(assignment.cost, total);
+            assert_eq!(assignment.cost, brute_force(&cost));
+        }
+    }
+
+    #[test]
+    fn identity_prefers_off_diagonal() {
+        // Ones on the diagonal, zeros elsewhere: any derangement costs 0.
+        let mut cost = SquareMatrix::new(4);
+        (0..4).for_each(|i| cost[(i, i)] = 1.);
+
+        let assignment = hungarian(&cost).unwrap();
+        assert_eq!(assignment.cost, 0.);
+        assert!((0..4).all(|i| assignment.columns[i] != i));
+    }
+
+    #[test]
+    fn non_finite_cost() {
+        let mut cost = SquareMatrix::new(2);
+        for bad in [f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+            cost[(0, 1)] = bad;
+            assert_eq!(hungarian(&cost), Err(GraphError::NonFiniteCost));
+        }
+    }
+}
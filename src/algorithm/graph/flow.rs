@@ -0,0 +1,394 @@
+use std::collections::VecDeque;
+
+use super::{Graph, GraphError, GraphKind, Weight};
+
+/// Maximum flow between two vertices, with a minimum cut that proves it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaxFlow {
+    pub value: Weight,
+    /// Through every edge, in the order of [`Graph::edges`].
+    /// Negative on an undirected edge when it goes `to -> from`.
+    pub flow: Vec<Weight>,
+    /// Vertices still reachable from the source once the flow is maxed out, ascending.
+    pub source_side: Vec<usize>,
+    /// Edges leaving `source_side` as `(from, to, capacity)`, all saturated.
+    /// Their capacities sum to `value`.
+    pub cut: Vec<(usize, usize, Weight)>,
+}
+
+/// Residual network: arcs come in pairs, `arc ^ 1` is the reverse of `arc`.
+/// Arc `2 * i` follows the `i`-th of [`Graph::edges`].
+struct Residual {
+    // Arcs leaving each vertex.
+    outgoing: Vec<Vec<usize>>,
+    to: Vec<usize>,
+    // What can still be pushed along each arc.
+    capacity: Vec<Weight>,
+    // Of every edge, to tell the flow from what's left.
+    original: Vec<(usize, usize, Weight)>,
+    kind: GraphKind,
+}
+
+impl Residual {
+    /// @If `source` or `sink` is out of `0..len` -> `Err(NoSuchVertex)`.
+    /// @If some capacity is negative -> `Err(NegativeWeight)`.
+    fn new(graph: &Graph, source: usize, sink: usize) -> Result<Self, GraphError> {
+        if source >= graph.len() || sink >= graph.len() {
+            return Err(GraphError::NoSuchVertex);
+        }
+
+        let original = graph.edges().collect::<Vec<_>>();
+        if original.iter().any(|&(_, _, capacity)| capacity < 0) {
+            return Err(GraphError::NegativeWeight);
+        }
+
+        let mut residual = Self {
+            outgoing: vec![vec![]; graph.len()],
+            to: vec![],
+            capacity: vec![],
+            original,
+            kind: graph.kind,
+        };
+        for i in 0..residual.original.len() {
+            let (from, to, capacity) = residual.original[i];
+            let back = match residual.kind {
+                GraphKind::Directed => 0,
+                GraphKind::Undirected => capacity,
+            };
+            residual.add_arc(from, to, capacity);
+            residual.add_arc(to, from, back);
+        }
+        Ok(residual)
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: Weight) {
+        self.outgoing[from].push(self.to.len());
+        self.to.push(to);
+        self.capacity.push(capacity);
+    }
+
+    fn push(&mut self, arc: usize, amount: Weight) {
+        self.capacity[arc] -= amount;
+        self.capacity[arc ^ 1] += amount;
+    }
+
+    /// Fewest arcs with capacity left from `source` to each vertex.
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let mut level = vec![None; self.outgoing.len()];
+        let mut queue = VecDeque::from([source]);
+        level[source] = Some(0);
+
+        while let Some(v) = queue.pop_front() {
+            let next = level[v].map(|l| l + 1);
+            for &arc in &self.outgoing[v] {
+                let u = self.to[arc];
+                if self.capacity[arc] > 0 && level[u].is_none() {
+                    level[u] = next;
+                    queue.push_back(u);
+                }
+            }
+        }
+
+        level
+    }
+
+    /// Arcs of a path with fewest arcs from `source` to `sink`, last one first.
+    /// `None` once `sink` is cut off.
+    fn shortest_path(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut via = vec![None; self.outgoing.len()];
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(v) = queue.pop_front() {
+            for &arc in &self.outgoing[v] {
+                let u = self.to[arc];
+                if self.capacity[arc] > 0 && u != source && via[u].is_none() {
+                    via[u] = Some(arc);
+                    queue.push_back(u);
+                }
+            }
+        }
+
+        let mut path = vec![];
+        let mut v = sink;
+        while v != source {
+            let arc = via[v]?;
+            path.push(arc);
+            v = self.to[arc ^ 1];
+        }
+        Some(path)
+    }
+
+    /// Depth-first along rising levels, skipping arcs that already led nowhere. The path
+    /// is kept as a stack of arcs, so long ones don't overflow the call stack.
+    /// @Returns how much got from `source` to `sink` along one path, 0 once there's none.
+    fn blocking_flow(
+        &mut self,
+        source: usize,
+        sink: usize,
+        level: &[Option<usize>],
+        next: &mut [usize],
+    ) -> Weight {
+        let mut path = vec![];
+        let mut v = source;
+        while v != sink {
+            match self.outgoing[v].get(next[v]) {
+                Some(&arc) => {
+                    let u = self.to[arc];
+                    match self.capacity[arc] > 0 && level[u] == level[v].map(|l| l + 1) {
+                        true => {
+                            path.push(arc);
+                            v = u;
+                        }
+                        false => next[v] += 1,
+                    }
+                }
+                // Dead end: back off, the arc that led here won't be tried again.
+                None => {
+                    let Some(arc) = path.pop() else {
+                        return 0;
+                    };
+                    v = self.to[arc ^ 1];
+                    next[v] += 1;
+                }
+            }
+        }
+
+        let pushed = path
+            .iter()
+            .map(|&arc| self.capacity[arc])
+            .min()
+            .expect("Source and sink differ, so the path has arcs");
+        path.iter().for_each(|&arc| self.push(arc, pushed));
+        pushed
+    }
+
+    fn finish(self, source: usize) -> MaxFlow {
+        let flow = (0..self.original.len())
+            .map(|i| self.original[i].2 - self.capacity[2 * i])
+            .collect::<Vec<_>>();
+        let value = self.outgoing[source]
+            .iter()
+            .map(|&arc| match arc % 2 {
+                0 => flow[arc / 2],
+                _ => -flow[arc / 2],
+            })
+            .sum();
+
+        let reachable = self.levels(source);
+        let on_source_side = |v: usize| reachable[v].is_some();
+        let cut = self
+            .original
+            .iter()
+            .filter_map(
+                |&(from, to, capacity)| match (on_source_side(from), on_source_side(to)) {
+                    (true, false) => Some((from, to, capacity)),
+                    (false, true) if self.kind == GraphKind::Undirected => {
+                        Some((to, from, capacity))
+                    }
+                    _ => None,
+                },
+            )
+            .collect();
+
+        MaxFlow {
+            value,
+            flow,
+            source_side: (0..self.outgoing.len())
+                .filter(|&v| on_source_side(v))
+                .collect(),
+            cut,
+        }
+    }
+}
+
+impl Graph {
+    /// Edge weights are capacities, an undirected edge carries up to its capacity either way.
+    /// Augments along a path with fewest edges until there's none. O(VE²).
+    ///
+    /// @If `source` or `sink` is out of `0..len` -> `Err(NoSuchVertex)`.
+    /// @If some capacity is negative -> `Err(NegativeWeight)`.
+    pub fn edmonds_karp(&self, source: usize, sink: usize) -> Result<MaxFlow, GraphError> {
+        let mut residual = Residual::new(self, source, sink)?;
+        if source == sink {
+            return Ok(residual.finish(source));
+        }
+
+        while let Some(path) = residual.shortest_path(source, sink) {
+            let bottleneck = path
+                .iter()
+                .map(|&arc| residual.capacity[arc])
+                .min()
+                .expect("Source and sink differ, so the path has arcs");
+            path.iter().for_each(|&arc| residual.push(arc, bottleneck));
+        }
+
+        Ok(residual.finish(source))
+    }
+
+    /// Same as [`Graph::edmonds_karp`], but saturates every shortest path at once
+    /// with a blocking flow before looking further. O(V²E).
+    ///
+    /// @If `source` or `sink` is out of `0..len` -> `Err(NoSuchVertex)`.
+    /// @If some capacity is negative -> `Err(NegativeWeight)`.
+    pub fn dinic(&self, source: usize, sink: usize) -> Result<MaxFlow, GraphError> {
+        let mut residual = Residual::new(self, source, sink)?;
+        if source == sink {
+            return Ok(residual.finish(source));
+        }
+
+        loop {
+            let level = residual.levels(source);
+            if level[sink].is_none() {
+                break;
+            }
+
+            let mut next = vec![0; self.len()];
+            while residual.blocking_flow(source, sink, &level, &mut next) > 0 {}
+        }
+
+        Ok(residual.finish(source))
+    }
+}
+
+/// Part of [`Graph::showcase`].
+pub(super) fn showcase() -> Result<(), Box<dyn crate::algorithm::Error>> {
+    // Pipes from a pumping station (0) to a town (5).
+    let pipes = Graph::from_edges(
+        6,
+        GraphKind::Directed,
+        &[
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 2, 10),
+            (2, 1, 4),
+            (1, 3, 12),
+            (3, 2, 9),
+            (2, 4, 14),
+            (4, 3, 7),
+            (3, 5, 20),
+            (4, 5, 4),
+        ],
+    )?;
+
+    let flow = pipes.dinic(0, 5)?;
+    println!("dinic(0, 5) = {}", flow.value);
+    println!("edmonds_karp(0, 5) = {}", pipes.edmonds_karp(0, 5)?.value);
+    println!(
+        "min cut: source side {:?}, edges {:?}",
+        flow.source_side, flow.cut
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::SeededRandom;
+
+    use super::*;
+
+    fn random_graph(random: &mut SeededRandom, kind: GraphKind) -> Graph {
+        let len = random.get_in_range(2, 8);
+        let mut graph = Graph::new(len, kind);
+        for _ in 0..random.get_in_range(0, 16) {
+            let (from, to) = (random.get_in_range(0, len), random.get_in_range(0, len));
+            graph
+                .add_edge(from, to, random.get_in_range(0, 10))
+                .unwrap();
+        }
+        graph
+    }
+
+    /// Smallest capacity leaving a vertex set with `source` but not `sink`, trying every set.
+    fn brute_force_min_cut(graph: &Graph, source: usize, sink: usize) -> Weight {
+        (0..1u32 << graph.len())
+            .filter(|set| set & (1 << source) != 0 && set & (1 << sink) == 0)
+            .map(|set| {
+                let inside = |v: usize| set & (1 << v) != 0;
+                graph
+                    .edges()
+                    .filter(|&(from, to, _)| match graph.kind() {
+                        GraphKind::Directed => inside(from) && !inside(to),
+                        GraphKind::Undirected => inside(from) != inside(to),
+                    })
+                    .map(|(_, _, capacity)| capacity)
+                    .sum()
+            })
+            .min()
+            .expect("`source` alone is such a set")
+    }
+
+    fn assert_valid(graph: &Graph, source: usize, sink: usize, flow: &MaxFlow) {
+        let mut excess = vec![0; graph.len()];
+        for ((from, to, capacity), &f) in graph.edges().zip(&flow.flow) {
+            match graph.kind() {
+                GraphKind::Directed => assert!((0..=capacity).contains(&f)),
+                GraphKind::Undirected => assert!(f.abs() <= capacity),
+            }
+            excess[from] -= f;
+            excess[to] += f;
+        }
+
+        for (v, &excess) in excess.iter().enumerate() {
+            match v {
+                v if v == source => assert_eq!(excess, -flow.value),
+                v if v == sink => assert_eq!(excess, flow.value),
+                _ => assert_eq!(excess, 0),
+            }
+        }
+
+        assert!(flow.source_side.contains(&source));
+        assert!(!flow.source_side.contains(&sink));
+        for &(from, to, _) in &flow.cut {
+            assert!(flow.source_side.contains(&from) && !flow.source_side.contains(&to));
+        }
+        let cut = flow
+            .cut
+            .iter()
+            .map(|&(_, _, capacity)| capacity)
+            .sum::<Weight>();
+        assert_eq!(cut, flow.value);
+    }
+
+    #[test]
+    fn against_brute_force() {
+        let mut random = SeededRandom::new(490);
+        for kind in [GraphKind::Directed, GraphKind::Undirected] {
+            for _ in 0..150 {
+                let graph = random_graph(&mut random, kind);
+                let source = random.get_in_range(0, graph.len());
+                let sink = (source + random.get_in_range(1, graph.len())) % graph.len();
+
+                let edmonds_karp = graph.edmonds_karp(source, sink).unwrap();
+                let dinic = graph.dinic(source, sink).unwrap();
+                assert_valid(&graph, source, sink, &edmonds_karp);
+                assert_valid(&graph, source, sink, &dinic);
+                assert_eq!(edmonds_karp.value, dinic.value);
+                assert_eq!(dinic.value, brute_force_min_cut(&graph, source, sink));
+            }
+        }
+    }
+
+    #[test]
+    fn long_path() {
+        let len = 100_000;
+        let mut graph = Graph::directed(len);
+        for v in 1..len {
+            graph.add_edge(v - 1, v, 1 + v as Weight % 7).unwrap();
+        }
+
+        assert_eq!(graph.dinic(0, len - 1).unwrap().value, 1);
+        assert_eq!(graph.edmonds_karp(0, len - 1).unwrap().value, 1);
+    }
+
+    #[test]
+    fn errors_and_edge_cases() {
+        let graph = Graph::from_edges(2, GraphKind::Directed, &[(0, 1, -1)]).unwrap();
+        assert_eq!(graph.dinic(0, 1), Err(GraphError::NegativeWeight));
+        assert_eq!(graph.edmonds_karp(0, 2), Err(GraphError::NoSuchVertex));
+
+        let graph = Graph::from_edges(2, GraphKind::Directed, &[(0, 1, 5)]).unwrap();
+        assert_eq!(graph.dinic(0, 0).unwrap().value, 0);
+        assert_eq!(graph.edmonds_karp(1, 0).unwrap().value, 0);
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::VecDeque;
+
+use super::{Graph, GraphError};
+
+const FREE: usize = usize::MAX;
+
+impl Graph {
+    /// Which side every vertex is on, edge directions ignored: `false` for the side of the
+    /// smallest vertex of its component. `None` if some edge joins two vertices of one side.
+    pub fn bipartition(&self) -> Option<Vec<bool>> {
+        let neighbors = self.undirected_neighbors();
+        let mut side = vec![None; self.len()];
+
+        for start in 0..self.len() {
+            if side[start].is_some() {
+                continue;
+            }
+
+            side[start] = Some(false);
+            let mut queue = VecDeque::from([start]);
+            while let Some(v) = queue.pop_front() {
+                let other = side[v].map(|s| !s);
+                for &u in &neighbors[v] {
+                    match side[u] {
+                        None => {
+                            side[u] = other;
+                            queue.push_back(u);
+                        }
+                        Some(s) if Some(s) != other => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        side.into_iter().collect()
+    }
+
+    /// Maximum matching of a bipartite graph as `(left, right)` pairs ascending by `left`,
+    /// sides as in [`Graph::bipartition`]. Edge directions and weights are ignored.
+    ///
+    /// Hopcroft–Karp: every phase a BFS layers the graph from the unmatched left vertices,
+    /// then a DFS flips vertex-disjoint shortest augmenting paths along the layers. O(E√V).
+    ///
+    /// @If the graph isn't bipartite -> `Err(NotBipartite)`.
+    pub fn hopcroft_karp(&self) -> Result<Vec<(usize, usize)>, GraphError> {
+        let right = self.bipartition().ok_or(GraphError::NotBipartite)?;
+        let neighbors = self.undirected_neighbors();
+        let left = (0..self.len()).filter(|&v| !right[v]).collect::<Vec<_>>();
+
+        // Partner of every vertex, on either side.
+        let mut mate = vec![FREE; self.len()];
+        // BFS layer of left vertices, `usize::MAX` once one is known to lead nowhere.
+        let mut layer = vec![usize::MAX; self.len()];
+
+        loop {
+            let mut queue = VecDeque::new();
+            for &v in &left {
+                layer[v] = match mate[v] {
+                    FREE => 0,
+                    _ => usize::MAX,
+                };
+                if mate[v] == FREE {
+                    queue.push_back(v);
+                }
+            }
+
+            // Layer of the left vertices next to a free right one: the shortest augmenting
+            // paths end there, so nothing past it is worth layering.
+            let mut shortest = usize::MAX;
+            while let Some(v) = queue.pop_front() {
+                if layer[v] > shortest {
+                    break;
+                }
+
+                for &u in &neighbors[v] {
+                    match mate[u] {
+                        FREE => shortest = shortest.min(layer[v]),
+                        w if layer[w] == usize::MAX => {
+                            layer[w] = layer[v] + 1;
+                            queue.push_back(w);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if shortest == usize::MAX {
+                break;
+            }
+
+            for &v in &left {
+                if mate[v] == FREE {
+                    augment(v, shortest, &neighbors, &mut mate, &mut layer);
+                }
+            }
+        }
+
+        Ok(left
+            .into_iter()
+            .filter(|&v| mate[v] != FREE)
+            .map(|v| (v, mate[v]))
+            .collect())
+    }
+
+    fn undirected_neighbors(&self) -> Vec<Vec<usize>> {
+        let mut neighbors = vec![vec![]; self.len()];
+        for (from, to, _) in self.edges() {
+            neighbors[from].push(to);
+            if from != to {
+                neighbors[to].push(from);
+            }
+        }
+        neighbors
+    }
+}
+
+/// Looks for an augmenting path from the left vertex `v` one layer at a time, ending at a
+/// free right vertex from layer `shortest`.
+/// @Mutate flips the path if found, otherwise takes `v` out of the layers.
+fn augment(
+    v: usize,
+    shortest: usize,
+    neighbors: &[Vec<usize>],
+    mate: &mut [usize],
+    layer: &mut [usize],
+) -> bool {
+    // Left vertices of the path so far, each with the index of the next neighbor to try.
+    // Explicit, so long paths don't overflow the call stack.
+    let mut stack = vec![(v, 0)];
+    while let Some(&(v, next)) = stack.last() {
+        let Some(&u) = neighbors[v].get(next) else {
+            layer[v] = usize::MAX;
+            stack.pop();
+            continue;
+        };
+        let top = stack.len() - 1;
+        stack[top].1 += 1;
+
+        match mate[u] {
+            FREE if layer[v] == shortest => {
+                // Each left vertex on the path takes the right one it went through.
+                for &(v, next) in &stack {
+                    let u = neighbors[v][next - 1];
+                    mate[v] = u;
+                    mate[u] = v;
+                }
+                return true;
+            }
+            FREE => {}
+            w if layer[v] < shortest && layer[w] == layer[v] + 1 => stack.push((w, 0)),
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Part of [`Graph::showcase`].
+pub(super) fn showcase() -> Result<(), Box<dyn crate::algorithm::Error>> {
+    let workers = ["ann", "bob", "cid", "dan"];
+    let shifts = ["mon", "tue", "wed", "thu"];
+    // Workers are 0..4, shifts 4..8, an edge for each shift a worker can take.
+    let availability = Graph::from_edges(
+        8,
+        super::GraphKind::Undirected,
+        &[
+            (0, 4, 1),
+            (0, 5, 1),
+            (1, 4, 1),
+            (2, 5, 1),
+            (2, 6, 1),
+            (3, 6, 1),
+            (3, 7, 1),
+        ],
+    )?;
+
+    let matching = availability.hopcroft_karp()?;
+    let named = matching
+        .iter()
+        .map(|&(worker, shift)| (workers[worker], shifts[shift - 4]))
+        .collect::<Vec<_>>();
+    println!("hopcroft_karp() = {named:?}");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithm::graph::{GraphKind, Weight},
+        random::SeededRandom,
+    };
+
+    use super::*;
+
+    /// `left` vertices `0..left`, the rest on the right.
+    fn random_bipartite(random: &mut SeededRandom, left: usize, right: usize) -> Graph {
+        let mut graph = Graph::undirected(left + right);
+        for _ in 0..random.get_in_range(0, 3 * (left + right)) {
+            let (from, to) = (random.get_in_range(0, left), random.get_in_range(0, right));
+            graph.add_edge(from, left + to, 1).unwrap();
+        }
+        graph
+    }
+
+    /// Through a network with unit capacities: source, left side, right side, sink.
+    fn max_flow_matching(graph: &Graph, right: &[bool]) -> Weight {
+        let (source, sink) = (graph.len(), graph.len() + 1);
+        let mut network = Graph::directed(graph.len() + 2);
+        for (from, to, _) in graph.edges() {
+            let (l, r) = if right[from] { (to, from) } else { (from, to) };
+            network.add_edge(l, r, 1).unwrap();
+        }
+        for (v, &right) in right.iter().enumerate() {
+            match right {
+                false => network.add_edge(source, v, 1).unwrap(),
+                true => network.add_edge(v, sink, 1).unwrap(),
+            }
+        }
+        network.dinic(source, sink).unwrap().value
+    }
+
+    #[test]
+    fn long_augmenting_path() {
+        // A path alternating left and right. The first phase matches every left vertex
+        // but the one at the end with the right vertex before it, which leaves a single
+        // augmenting path through the whole graph for the second.
+        let half = 50_000;
+        let left = |i: usize| match i {
+            0 => half - 1,
+            i => i - 1,
+        };
+        let right = |i: usize| half + i;
+
+        let mut graph = Graph::undirected(2 * half);
+        for i in 0..half {
+            graph.add_edge(left(i), right(i), 1).unwrap();
+            if i + 1 < half {
+                graph.add_edge(right(i), left(i + 1), 1).unwrap();
+            }
+        }
+
+        assert_eq!(graph.hopcroft_karp().unwrap().len(), half);
+    }
+
+    #[test]
+    fn against_max_flow() {
+        let mut random = SeededRandom::new(491);
+        for _ in 0..200 {
+            let (left, right) = (random.get_in_range(1, 10), random.get_in_range(1, 10));
+            let graph = random_bipartite(&mut random, left, right);
+            let sides = graph.bipartition().unwrap();
+            for (from, to, _) in graph.edges() {
+                assert_ne!(sides[from], sides[to]);
+            }
+
+            let matching = graph.hopcroft_karp().unwrap();
+            let mut used = vec![false; graph.len()];
+            for &(l, r) in &matching {
+                assert!(!sides[l] && sides[r]);
+                assert!(!used[l] && !used[r]);
+                (used[l], used[r]) = (true, true);
+                assert!(graph.neighbors(l).iter().any(|edge| edge.to == r));
+            }
+            assert!(matching.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert_eq!(matching.len() as Weight, max_flow_matching(&graph, &sides));
+        }
+    }
+
+    #[test]
+    fn directions_are_ignored() {
+        let graph =
+            Graph::from_edges(4, GraphKind::Directed, &[(1, 0, 1), (1, 2, 1), (3, 2, 1)]).unwrap();
+        assert_eq!(graph.bipartition(), Some(vec![false, true, false, true]));
+        assert_eq!(graph.hopcroft_karp(), Ok(vec![(0, 1), (2, 3)]));
+    }
+
+    #[test]
+    fn odd_cycle_isnt_bipartite() {
+        let triangle =
+            Graph::from_edges(3, GraphKind::Undirected, &[(0, 1, 1), (1, 2, 1), (2, 0, 1)])
+                .unwrap();
+        assert_eq!(triangle.bipartition(), None);
+        assert_eq!(triangle.hopcroft_karp(), Err(GraphError::NotBipartite));
+
+        let with_loop = Graph::from_edges(1, GraphKind::Directed, &[(0, 0, 1)]).unwrap();
+        assert_eq!(with_loop.hopcroft_karp(), Err(GraphError::NotBipartite));
+    }
+}
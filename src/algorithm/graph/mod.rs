@@ -0,0 +1,279 @@
+use std::fmt::Display;
+
+mod assignment;
+mod flow;
+mod matching;
+mod shortest_path;
+mod spanning_tree;
+mod traversal;
+pub use assignment::*;
+pub use flow::*;
+pub use shortest_path::*;
+pub use spanning_tree::*;
+
+/// Exact, so shortest paths don't pick up rounding errors.
+pub type Weight = i64;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GraphError {
+    /// Vertex index is past the end.
+    NoSuchVertex,
+    /// The algorithm only makes sense for directed graphs.
+    Undirected,
+    /// The algorithm only makes sense for undirected graphs.
+    Directed,
+    /// Topological order doesn't exist, vertices of one cycle in order.
+    Cycle(Vec<usize>),
+    /// Dijkstra can't handle negative weights.
+    NegativeWeight,
+    /// Shortest paths don't exist, vertices of one cycle of negative total weight in order.
+    NegativeCycle(Vec<usize>),
+    /// Matching needs the vertices split in two sides with no edge inside one.
+    NotBipartite,
+    /// A path is too long or too short for its total to fit in [`Weight`].
+    Overflow,
+    /// Assignment costs must be finite, a forbidden pair can cost more than all the rest combined.
+    NonFiniteCost,
+}
+
+impl super::Error for GraphError {}
+impl Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphKind {
+    #[default]
+    Directed,
+    /// Every edge can be walked both ways.
+    Undirected,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Edge {
+    pub to: usize,
+    pub weight: Weight,
+}
+
+/// Weighted graph over vertices `0..len` as adjacency lists.
+/// Parallel edges and loops are allowed.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    // Undirected edges are stored once in each direction, loops once.
+    adjacency: Vec<Vec<Edge>>,
+    edges: usize,
+    kind: GraphKind,
+}
+
+impl Graph {
+    /// `len` vertices and no edges.
+    pub fn new(len: usize, kind: GraphKind) -> Self {
+        Self {
+            adjacency: vec![vec![]; len],
+            edges: 0,
+            kind,
+        }
+    }
+
+    pub fn directed(len: usize) -> Self {
+        Self::new(len, GraphKind::Directed)
+    }
+
+    pub fn undirected(len: usize) -> Self {
+        Self::new(len, GraphKind::Undirected)
+    }
+
+    /// @If any endpoint is out of `0..len` -> `Err(NoSuchVertex)`.
+    pub fn from_edges(
+        len: usize,
+        kind: GraphKind,
+        edges: &[(usize, usize, Weight)],
+    ) -> Result<Self, GraphError> {
+        let mut graph = Self::new(len, kind);
+        for &(from, to, weight) in edges {
+            graph.add_edge(from, to, weight)?;
+        }
+        Ok(graph)
+    }
+
+    /// Vertices.
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// Edges as added, an undirected one counts once.
+    pub fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.kind == GraphKind::Directed
+    }
+
+    /// @Returns the new vertex, numbered `len()` before the call.
+    pub fn add_vertex(&mut self) -> usize {
+        self.adjacency.push(vec![]);
+        self.len() - 1
+    }
+
+    /// @If any endpoint is out of `0..len` -> `Err(NoSuchVertex)`.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: Weight) -> Result<(), GraphError> {
+        self.check_vertex(from)?;
+        self.check_vertex(to)?;
+
+        self.adjacency[from].push(Edge { to, weight });
+        if !self.is_directed() && from != to {
+            self.adjacency[to].push(Edge { to: from, weight });
+        }
+        self.edges += 1;
+        Ok(())
+    }
+
+    /// @If `vertex` is out of `0..len` -> `Err(NoSuchVertex)`.
+    fn check_vertex(&self, vertex: usize) -> Result<(), GraphError> {
+        match vertex < self.len() {
+            true => Ok(()),
+            false => Err(GraphError::NoSuchVertex),
+        }
+    }
+
+    /// Edges leaving `vertex`, in the order they were added.
+    pub fn neighbors(&self, vertex: usize) -> &[Edge] {
+        &self.adjacency[vertex]
+    }
+
+    /// Every edge as `(from, to, weight)`, an undirected one once with `from <= to`.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, Weight)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                edges
+                    .iter()
+                    .filter(move |edge| self.is_directed() || from <= edge.to)
+                    .map(move |edge| (from, edge.to, edge.weight))
+            })
+    }
+
+    /// Same vertices, every edge turned around.
+    pub fn reversed(&self) -> Self {
+        let mut reversed = Self::new(self.len(), self.kind);
+        for (from, to, weight) in self.edges() {
+            reversed.add_edge(to, from, weight).expect("Same vertices");
+        }
+        reversed
+    }
+}
+
+impl super::Algorithm for Graph {
+    fn showcase() -> Result<(), Box<dyn super::Error>> {
+        // Course prerequisites.
+        let courses = [
+            "math",
+            "programming",
+            "algorithms",
+            "databases",
+            "compilers",
+            "ml",
+        ];
+        let graph = Graph::from_edges(
+            courses.len(),
+            GraphKind::Directed,
+            &[
+                (0, 2, 1),
+                (1, 2, 1),
+                (1, 3, 1),
+                (2, 4, 1),
+                (2, 5, 1),
+                (0, 5, 1),
+            ],
+        )?;
+        let names = |vertices: &[usize]| vertices.iter().map(|&v| courses[v]).collect::<Vec<_>>();
+
+        println!("bfs(math) = {:?}", names(&graph.bfs(0)?));
+        println!("dfs(programming) = {:?}", names(&graph.dfs(1)?));
+        println!(
+            "topological_sort() = {:?}",
+            names(&graph.topological_sort()?)
+        );
+
+        let mut cyclic = graph.clone();
+        cyclic.add_edge(4, 1, 1)?;
+        println!("\nWith compilers -> programming:");
+        println!(
+            "find_cycle() = {:?}",
+            cyclic.find_cycle().map(|c| names(&c))
+        );
+        println!("topological_sort() = {:?}", cyclic.topological_sort().err());
+        for component in cyclic.strongly_connected_components() {
+            println!("  component {:?}", names(&component));
+        }
+
+        println!();
+        shortest_path::showcase()?;
+        println!();
+        spanning_tree::showcase()?;
+        println!();
+        flow::showcase()?;
+        println!();
+        matching::showcase()?;
+        println!();
+        assignment::showcase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::Algorithm;
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        Graph::showcase().unwrap();
+    }
+
+    #[test]
+    fn edges() {
+        let mut graph = Graph::undirected(3);
+        graph.add_edge(0, 1, 5).unwrap();
+        graph.add_edge(2, 1, 7).unwrap();
+        graph.add_edge(2, 2, 1).unwrap();
+
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(
+            graph.neighbors(1),
+            [Edge { to: 0, weight: 5 }, Edge { to: 2, weight: 7 }]
+        );
+        assert_eq!(
+            graph.edges().collect::<Vec<_>>(),
+            [(0, 1, 5), (1, 2, 7), (2, 2, 1)]
+        );
+        assert_eq!(graph.add_edge(0, 3, 1), Err(GraphError::NoSuchVertex));
+
+        let v = graph.add_vertex();
+        assert_eq!(graph.add_edge(0, v, 1), Ok(()));
+    }
+
+    #[test]
+    fn reversed() {
+        let graph =
+            Graph::from_edges(3, GraphKind::Directed, &[(0, 1, 1), (1, 2, 2), (0, 2, 3)]).unwrap();
+        let reversed = graph.reversed();
+
+        let mut edges = reversed.edges().collect::<Vec<_>>();
+        edges.sort_unstable();
+        assert_eq!(edges, [(1, 0, 1), (2, 0, 3), (2, 1, 2)]);
+        assert_eq!(reversed.edge_count(), 3);
+    }
+}
@@ -0,0 +1,525 @@
+use crate::{
+    algorithm::heap::{BinaryHeap, Handle},
+    core::term::print::FormatConfig,
+    matrix::{IntMatrix, MatrixOperations, SquareMatrix},
+};
+
+use super::{Graph, GraphError, GraphKind, Weight};
+
+/// Shortest paths from one source, as a tree of `previous` links.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPaths {
+    pub source: usize,
+    /// `None` for unreachable vertices.
+    pub distance: Vec<Option<Weight>>,
+    /// Vertex before each one on its shortest path, `None` for the source and unreachable ones.
+    pub previous: Vec<Option<usize>>,
+}
+
+impl ShortestPaths {
+    fn new(len: usize, source: usize) -> Self {
+        let mut distance = vec![None; len];
+        distance[source] = Some(0);
+
+        Self {
+            source,
+            distance,
+            previous: vec![None; len],
+        }
+    }
+
+    /// Vertices from `source` to `target`, both included.
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance[target]?;
+
+        let mut path = vec![target];
+        while let Some(previous) = self.previous[*path.last()?] {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl Graph {
+    /// Using [`BinaryHeap::decrease_key`], so every vertex is in the queue at most once.
+    /// O((V + E) log V).
+    ///
+    /// @If `source` is out of `0..len` -> `Err(NoSuchVertex)`.
+    /// @If some edge weighs less than 0 -> `Err(NegativeWeight)`.
+    /// @If a path's total doesn't fit in [`Weight`] -> `Err(Overflow)`.
+    pub fn dijkstra(&self, source: usize) -> Result<ShortestPaths, GraphError> {
+        self.check_vertex(source)?;
+        if self.edges().any(|(_, _, weight)| weight < 0) {
+            return Err(GraphError::NegativeWeight);
+        }
+
+        let mut paths = ShortestPaths::new(self.len(), source);
+        let mut handles = vec![None::<Handle>; self.len()];
+        let mut queue = BinaryHeap::<(Weight, usize)>::min();
+        handles[source] = Some(queue.push((0, source)));
+
+        while let Some((distance, v)) = queue.pop() {
+            for edge in &self.adjacency[v] {
+                let candidate = distance
+                    .checked_add(edge.weight)
+                    .ok_or(GraphError::Overflow)?;
+                if paths.distance[edge.to].is_some_and(|known| known <= candidate) {
+                    continue;
+                }
+
+                paths.distance[edge.to] = Some(candidate);
+                paths.previous[edge.to] = Some(v);
+                match handles[edge.to] {
+                    Some(handle) => queue
+                        .decrease_key(handle, (candidate, edge.to))
+                        .expect("Settled vertices can't get closer"),
+                    None => handles[edge.to] = Some(queue.push((candidate, edge.to))),
+                }
+            }
+        }
+
+        Ok(paths)
+    }
+
+    /// Relaxes every edge `V - 1` times, with an early exit once nothing changes. O(VE).
+    ///
+    /// @If `source` is out of `0..len` -> `Err(NoSuchVertex)`.
+    /// @If a negative cycle is reachable from `source` -> `Err(NegativeCycle(..))` with it.
+    /// @If a path's total doesn't fit in [`Weight`] -> `Err(Overflow)`, which a negative
+    /// cycle of large weights may run into before it's found.
+    pub fn bellman_ford(&self, source: usize) -> Result<ShortestPaths, GraphError> {
+        self.check_vertex(source)?;
+        let mut paths = ShortestPaths::new(self.len(), source);
+        let edges = self.edges().collect::<Vec<_>>();
+        // @Returns `to` if it got closer.
+        let mut relax = |from: usize, to: usize, weight: Weight| {
+            let Some(distance) = paths.distance[from] else {
+                return Ok(None);
+            };
+            let candidate = distance.checked_add(weight).ok_or(GraphError::Overflow)?;
+            if paths.distance[to].is_some_and(|known| known <= candidate) {
+                return Ok(None);
+            }
+
+            paths.distance[to] = Some(candidate);
+            paths.previous[to] = Some(from);
+            Ok(Some(to))
+        };
+
+        // On the last round any change means a negative cycle.
+        let mut changed = None;
+        for _ in 0..self.len() {
+            changed = None;
+            for &(from, to, weight) in &edges {
+                changed = relax(from, to, weight)?.or(changed);
+                if self.kind == GraphKind::Undirected {
+                    changed = relax(to, from, weight)?.or(changed);
+                }
+            }
+            if changed.is_none() {
+                return Ok(paths);
+            }
+        }
+
+        // `changed` hangs off the cycle: walking back `len` links surely lands on it.
+        let mut v = changed.expect("Loop only ends early if nothing changed");
+        for _ in 0..self.len() {
+            v = paths.previous[v].expect("Relaxed vertices have `previous`");
+        }
+        let mut cycle = vec![v];
+        loop {
+            let previous = paths.previous[*cycle.last().expect("Starts non-empty")];
+            match previous.expect("Cycle vertices have `previous`") {
+                u if u == v => break,
+                u => cycle.push(u),
+            }
+        }
+        cycle.reverse();
+        Err(GraphError::NegativeCycle(cycle))
+    }
+
+    /// All-pairs distances over an [`IntMatrix`], exact in [`Weight`] all the way through.
+    /// O(V³).
+    ///
+    /// @If there is a negative cycle -> `Err(NegativeCycle(..))` with one of them.
+    /// @If a path's total doesn't fit in [`Weight`] -> `Err(Overflow)`, that includes
+    /// reaching [`AllPairs::NO_PATH`].
+    pub fn floyd_warshall(&self) -> Result<AllPairs, GraphError> {
+        let len = self.len();
+        let no_paths = || {
+            IntMatrix::from_rows(vec![vec![AllPairs::NO_PATH; len]; len])
+                .expect("Rows are all `len` long")
+        };
+
+        // Lightest edge between each pair, to walk paths back edge by edge.
+        let mut direct = no_paths();
+        for (from, to, weight) in self.edges() {
+            if weight == AllPairs::NO_PATH {
+                return Err(GraphError::Overflow);
+            }
+
+            let mut set = |from: usize, to: usize| {
+                direct.set(from, to, direct.get(from, to).min(weight));
+            };
+            set(from, to);
+            if !self.is_directed() {
+                set(to, from);
+            }
+        }
+
+        let mut distance = direct.clone();
+        for v in 0..len {
+            distance.set(v, v, distance.get(v, v).min(0));
+        }
+        // Vertex a shortest path goes through, `None` for a single edge.
+        let mut via = vec![vec![None::<usize>; len]; len];
+
+        if let Some(v) = (0..len).find(|&v| distance.get(v, v) < 0) {
+            return Err(GraphError::NegativeCycle(vec![v]));
+        }
+        for k in 0..len {
+            for (i, via) in via.iter_mut().enumerate() {
+                let to_k = distance.get(i, k);
+                if to_k == AllPairs::NO_PATH {
+                    continue;
+                }
+                for (j, via) in via.iter_mut().enumerate() {
+                    let from_k = distance.get(k, j);
+                    if from_k == AllPairs::NO_PATH {
+                        continue;
+                    }
+
+                    let through = to_k
+                        .checked_add(from_k)
+                        .filter(|&through| through != AllPairs::NO_PATH)
+                        .ok_or(GraphError::Overflow)?;
+                    if through < distance.get(i, j) {
+                        distance.set(i, j, through);
+                        *via = Some(k);
+                    }
+                }
+            }
+
+            // Every path so far only goes through `0..=k`, which had no negative cycle
+            // before this round, so the walk behind a negative diagonal is finite.
+            if let Some(v) = (0..len).find(|&v| distance.get(v, v) < 0) {
+                let mut walk = vec![v];
+                unfold(&via, v, k, &mut walk);
+                unfold(&via, k, v, &mut walk);
+                return Err(GraphError::NegativeCycle(negative_cycle(&direct, &walk)));
+            }
+        }
+
+        Ok(AllPairs { distance })
+    }
+}
+
+/// Distances between every pair of vertices.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllPairs {
+    /// `distance[(from, to)]`, [`AllPairs::NO_PATH`] where there's no path.
+    pub distance: IntMatrix,
+}
+
+impl AllPairs {
+    /// Larger than any distance, so taking the minimum just works.
+    pub const NO_PATH: Weight = Weight::MAX;
+
+    /// @Returns `None` where there's no path.
+    pub fn get(&self, from: usize, to: usize) -> Option<Weight> {
+        match self.distance.get(from, to) {
+            Self::NO_PATH => None,
+            distance => Some(distance),
+        }
+    }
+
+    /// Same as [`ShortestPaths::distance`] from `source`.
+    pub fn distances_from(&self, source: usize) -> Vec<Option<Weight>> {
+        (0..self.distance.get_dim().get_n())
+            .map(|to| self.get(source, to))
+            .collect()
+    }
+
+    /// For display: `f32::INFINITY` where there's no path, weights past 2^24 get rounded.
+    pub fn to_matrix(&self) -> SquareMatrix {
+        let len = self.distance.get_dim().get_n();
+        let mut matrix = SquareMatrix::new(len);
+        matrix.fill_fn(|i, j| self.get(i, j).map_or(f32::INFINITY, |d| d as f32));
+        matrix
+    }
+}
+
+/// Pushes the vertices after `from` on the shortest path to `to` recorded in `via`.
+fn unfold(via: &[Vec<Option<usize>>], from: usize, to: usize, walk: &mut Vec<usize>) {
+    match via[from][to] {
+        None => walk.push(to),
+        Some(k) => {
+            unfold(via, from, k, walk);
+            unfold(via, k, to, walk);
+        }
+    }
+}
+
+/// Splits a closed walk of negative total weight into simple cycles, at least one of which
+/// must be negative too. @Returns that one, its vertices in order.
+fn negative_cycle(direct: &IntMatrix, walk: &[usize]) -> Vec<usize> {
+    let weight = |from: usize, to: usize| match direct.get(from, to) {
+        AllPairs::NO_PATH => unreachable!("Walks follow edges"),
+        weight => weight,
+    };
+    let mut stack = vec![walk[0]];
+
+    for &v in &walk[1..] {
+        let Some(at) = stack.iter().position(|&u| u == v) else {
+            stack.push(v);
+            continue;
+        };
+
+        let cycle = stack.split_off(at);
+        // Wider than `Weight`, so no partial sum can overflow.
+        let total = cycle
+            .iter()
+            .zip(cycle.iter().skip(1).chain([&v]))
+            .map(|(&from, &to)| weight(from, to) as i128)
+            .sum::<i128>();
+        if total < 0 {
+            return cycle;
+        }
+        stack.push(v);
+    }
+
+    unreachable!("A negative closed walk holds a negative cycle")
+}
+
+/// Part of [`Graph::showcase`].
+pub(super) fn showcase() -> Result<(), Box<dyn crate::algorithm::Error>> {
+    let roads = Graph::from_edges(
+        5,
+        GraphKind::Undirected,
+        &[
+            (0, 1, 4),
+            (0, 2, 1),
+            (2, 1, 2),
+            (1, 3, 5),
+            (2, 3, 8),
+            (3, 4, 3),
+        ],
+    )?;
+    let paths = roads.dijkstra(0)?;
+    println!("dijkstra(0) distances = {:?}", paths.distance);
+    println!("path to 4 = {:?}", paths.path_to(4));
+
+    let config = FormatConfig::new()
+        .gaps(2, 0)
+        .precision(0)
+        .title(Some("floyd_warshall()"));
+    println!(
+        "{}",
+        roads.floyd_warshall()?.to_matrix().display_with(&config)
+    );
+
+    let mut arbitrage = Graph::from_edges(
+        4,
+        GraphKind::Directed,
+        &[(0, 1, 2), (1, 2, -1), (2, 3, 2), (3, 1, 1)],
+    )?;
+    println!(
+        "bellman_ford(0) distances = {:?}",
+        arbitrage.bellman_ford(0)?.distance
+    );
+    arbitrage.add_edge(3, 1, -2)?;
+    println!(
+        "With 3 -> 1 weighing -2: {:?}",
+        arbitrage.bellman_ford(0).err()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::SeededRandom;
+
+    use super::*;
+
+    fn random_graph(random: &mut SeededRandom, kind: GraphKind, min_weight: Weight) -> Graph {
+        let len = random.get_in_range(1, 10);
+        let mut graph = Graph::new(len, kind);
+        for _ in 0..random.get_in_range(0, 25) {
+            let (from, to) = (random.get_in_range(0, len), random.get_in_range(0, len));
+            graph
+                .add_edge(from, to, random.get_in_range(min_weight, 20))
+                .unwrap();
+        }
+        graph
+    }
+
+    /// Sum of the weights along `path`, taking the lightest of parallel edges.
+    fn path_weight(graph: &Graph, path: &[usize]) -> Weight {
+        path.windows(2)
+            .map(|pair| {
+                let edges = graph.neighbors(pair[0]).iter();
+                edges
+                    .filter(|edge| edge.to == pair[1])
+                    .map(|edge| edge.weight)
+                    .min()
+                    .expect("Consecutive path vertices are joined")
+            })
+            .sum()
+    }
+
+    fn assert_consistent(graph: &Graph, paths: &ShortestPaths) {
+        for target in 0..graph.len() {
+            match paths.path_to(target) {
+                Some(path) => {
+                    assert_eq!(path[0], paths.source);
+                    assert_eq!(Some(path_weight(graph, &path)), paths.distance[target]);
+                }
+                None => assert_eq!(paths.distance[target], None),
+            }
+        }
+    }
+
+    #[test]
+    fn algorithms_agree() {
+        let mut random = SeededRandom::new(482);
+        for kind in [GraphKind::Directed, GraphKind::Undirected] {
+            for _ in 0..200 {
+                let graph = random_graph(&mut random, kind, 0);
+                let all_pairs = graph.floyd_warshall().unwrap();
+
+                for source in 0..graph.len() {
+                    let dijkstra = graph.dijkstra(source).unwrap();
+                    let bellman_ford = graph.bellman_ford(source).unwrap();
+                    assert_eq!(dijkstra.distance, bellman_ford.distance);
+                    assert_consistent(&graph, &dijkstra);
+                    assert_consistent(&graph, &bellman_ford);
+
+                    assert_eq!(all_pairs.distances_from(source), dijkstra.distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn negative_weights() {
+        let mut random = SeededRandom::new(483);
+        let mut cycles = 0;
+        for _ in 0..300 {
+            let graph = random_graph(&mut random, GraphKind::Directed, -5);
+            let all_pairs = graph.floyd_warshall();
+            let any_cycle = (0..graph.len()).any(|v| graph.bellman_ford(v).is_err());
+            assert_eq!(all_pairs.is_err(), any_cycle);
+
+            for source in 0..graph.len() {
+                match graph.bellman_ford(source) {
+                    Ok(paths) => {
+                        assert_consistent(&graph, &paths);
+                        if let Ok(all_pairs) = &all_pairs {
+                            assert_eq!(all_pairs.distances_from(source), paths.distance);
+                        }
+                    }
+                    Err(GraphError::NegativeCycle(cycle)) => {
+                        cycles += 1;
+                        assert!(all_pairs.is_err());
+                        let mut closed = cycle.clone();
+                        closed.push(cycle[0]);
+                        assert!(path_weight(&graph, &closed) < 0);
+                    }
+                    Err(error) => panic!("{error}"),
+                }
+            }
+            if let Err(GraphError::NegativeCycle(cycle)) = all_pairs {
+                let mut closed = cycle.clone();
+                closed.push(cycle[0]);
+                assert!(path_weight(&graph, &closed) < 0);
+            }
+        }
+        assert!(cycles > 0);
+    }
+
+    #[test]
+    fn overflow() {
+        let half = Weight::MAX / 2;
+        let graph = Graph::from_edges(
+            4,
+            GraphKind::Directed,
+            &[(0, 1, half), (1, 2, half), (2, 3, half)],
+        )
+        .unwrap();
+        assert_eq!(graph.dijkstra(0), Err(GraphError::Overflow));
+        assert_eq!(graph.bellman_ford(0), Err(GraphError::Overflow));
+        assert_eq!(graph.floyd_warshall(), Err(GraphError::Overflow));
+
+        // Two edges still fit.
+        let paths = graph.dijkstra(1).unwrap();
+        assert_eq!(paths.distance[3], Some(2 * half));
+        assert_eq!(graph.bellman_ford(1).unwrap(), paths);
+
+        // Would read as no path at all.
+        let graph = Graph::from_edges(2, GraphKind::Directed, &[(0, 1, Weight::MAX)]).unwrap();
+        assert_eq!(graph.floyd_warshall(), Err(GraphError::Overflow));
+    }
+
+    #[test]
+    fn large_weights_stay_exact() {
+        let big = 16_777_217;
+        let graph = Graph::from_edges(
+            3,
+            GraphKind::Directed,
+            &[(0, 1, big), (1, 2, 1), (2, 0, -big - 1)],
+        )
+        .unwrap();
+        assert!(graph.bellman_ford(0).is_ok());
+
+        // A zero-weight cycle, which `f32` would have rounded below zero.
+        let all_pairs = graph.floyd_warshall().unwrap();
+        assert_eq!(
+            all_pairs.distances_from(0),
+            [Some(0), Some(big), Some(big + 1)]
+        );
+        assert_eq!(all_pairs.get(2, 1), Some(-1));
+
+        let mut graph = graph;
+        graph.add_edge(2, 0, -big - 2).unwrap();
+        let Err(GraphError::NegativeCycle(cycle)) = graph.floyd_warshall() else {
+            panic!("0 -> 1 -> 2 -> 0 weighs -1");
+        };
+        let mut closed = cycle.clone();
+        closed.push(cycle[0]);
+        assert_eq!(path_weight(&graph, &closed), -1);
+    }
+
+    #[test]
+    fn negative_loop_is_a_cycle() {
+        let graph = Graph::from_edges(2, GraphKind::Directed, &[(0, 1, 1), (1, 1, -1)]).unwrap();
+        assert_eq!(
+            graph.floyd_warshall(),
+            Err(GraphError::NegativeCycle(vec![1]))
+        );
+    }
+
+    #[test]
+    fn dijkstra_rejects_negative_weights() {
+        let graph = Graph::from_edges(2, GraphKind::Directed, &[(0, 1, -1)]).unwrap();
+        assert_eq!(graph.dijkstra(0), Err(GraphError::NegativeWeight));
+        assert!(graph.bellman_ford(0).is_ok());
+    }
+
+    #[test]
+    fn source_out_of_range() {
+        let graph = Graph::from_edges(2, GraphKind::Directed, &[(0, 1, 1)]).unwrap();
+        assert_eq!(graph.dijkstra(2), Err(GraphError::NoSuchVertex));
+        assert_eq!(graph.bellman_ford(2), Err(GraphError::NoSuchVertex));
+    }
+
+    #[test]
+    fn undirected_negative_edge_is_a_cycle() {
+        let graph = Graph::from_edges(3, GraphKind::Undirected, &[(0, 1, 2), (1, 2, -1)]).unwrap();
+        assert_eq!(
+            graph.bellman_ford(0),
+            Err(GraphError::NegativeCycle(vec![1, 2]))
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::algorithm::{
+    disjoint_set::{DisjointSet, UnionBy},
+    heap::{BinaryHeap, Handle},
+};
+
+use super::{Graph, GraphError, Weight};
+
+/// Minimum spanning forest: a tree per connected component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpanningTree {
+    /// `(from, to, weight)` in the order they were picked.
+    pub edges: Vec<(usize, usize, Weight)>,
+    pub weight: Weight,
+}
+
+impl FromIterator<(usize, usize, Weight)> for SpanningTree {
+    fn from_iter<I: IntoIterator<Item = (usize, usize, Weight)>>(iter: I) -> Self {
+        let edges = iter.into_iter().collect::<Vec<_>>();
+        let weight = edges.iter().map(|&(_, _, weight)| weight).sum();
+        Self { edges, weight }
+    }
+}
+
+impl Graph {
+    /// Lightest edges first, skipping those that would close a cycle. O(E log E).
+    ///
+    /// @If the graph is directed -> `Err(Directed)`.
+    pub fn kruskal(&self) -> Result<SpanningTree, GraphError> {
+        if self.is_directed() {
+            return Err(GraphError::Directed);
+        }
+
+        let mut edges = self.edges().collect::<Vec<_>>();
+        // Stable, so equal weights keep insertion order.
+        edges.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut components = DisjointSet::new(self.len(), UnionBy::Rank);
+        Ok(edges
+            .into_iter()
+            .filter(|&(from, to, _)| components.union(from, to))
+            .collect())
+    }
+
+    /// Grows each tree from its smallest vertex, always taking the lightest edge out of it.
+    /// Every vertex is queued at most once thanks to [`BinaryHeap::decrease_key`]. O(E log V).
+    ///
+    /// @If the graph is directed -> `Err(Directed)`.
+    pub fn prim(&self) -> Result<SpanningTree, GraphError> {
+        if self.is_directed() {
+            return Err(GraphError::Directed);
+        }
+
+        let mut in_tree = vec![false; self.len()];
+        // Lightest known edge into each vertex from the tree: `(weight, from)`.
+        let mut best = vec![None::<(Weight, usize)>; self.len()];
+        let mut handles = vec![None::<Handle>; self.len()];
+        let mut edges = vec![];
+
+        for root in 0..self.len() {
+            if in_tree[root] {
+                continue;
+            }
+
+            let mut queue = BinaryHeap::min();
+            queue.push((0, root));
+            while let Some((_, v)) = queue.pop() {
+                in_tree[v] = true;
+                if let Some((weight, from)) = best[v] {
+                    edges.push((from, v, weight));
+                }
+
+                for edge in &self.adjacency[v] {
+                    let u = edge.to;
+                    if in_tree[u] || best[u].is_some_and(|(known, _)| known <= edge.weight) {
+                        continue;
+                    }
+
+                    best[u] = Some((edge.weight, v));
+                    match handles[u] {
+                        Some(handle) => queue
+                            .decrease_key(handle, (edge.weight, u))
+                            .expect("Queued vertices only get lighter"),
+                        None => handles[u] = Some(queue.push((edge.weight, u))),
+                    }
+                }
+            }
+        }
+
+        Ok(edges.into_iter().collect())
+    }
+}
+
+/// Part of [`Graph::showcase`].
+pub(super) fn showcase() -> Result<(), Box<dyn crate::algorithm::Error>> {
+    let graph = Graph::from_edges(
+        6,
+        super::GraphKind::Undirected,
+        &[
+            (0, 1, 7),
+            (0, 2, 9),
+            (0, 5, 14),
+            (1, 2, 10),
+            (1, 3, 15),
+            (2, 3, 11),
+            (2, 5, 2),
+            (3, 4, 6),
+            (4, 5, 9),
+        ],
+    )?;
+
+    let kruskal = graph.kruskal()?;
+    println!("kruskal() = {:?}, weight {}", kruskal.edges, kruskal.weight);
+    let prim = graph.prim()?;
+    println!("prim() = {:?}, weight {}", prim.edges, prim.weight);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithm::graph::GraphKind, random::SeededRandom};
+
+    use super::*;
+
+    /// Lightest forest with as many edges as possible, by trying every subset.
+    fn brute_force(graph: &Graph) -> Weight {
+        let edges = graph.edges().collect::<Vec<_>>();
+        let mut best = (0, Weight::MAX);
+
+        for mask in 0..1u32 << edges.len() {
+            let mut components = DisjointSet::new(graph.len(), UnionBy::Size);
+            let chosen = (0..edges.len()).filter(|i| mask & (1 << i) != 0);
+            let mut count = 0;
+            let mut weight = 0;
+            for i in chosen {
+                let (from, to, w) = edges[i];
+                if !components.union(from, to) {
+                    count = usize::MAX;
+                    break;
+                }
+                count += 1;
+                weight += w;
+            }
+
+            if count != usize::MAX && (count > best.0 || (count == best.0 && weight < best.1)) {
+                best = (count, weight);
+            }
+        }
+
+        best.1
+    }
+
+    fn assert_spanning_forest(graph: &Graph, tree: &SpanningTree) {
+        let mut components = DisjointSet::new(graph.len(), UnionBy::Size);
+        for &(from, to, weight) in &tree.edges {
+            assert!(components.union(from, to), "Tree edges never close a cycle");
+            assert!(graph
+                .neighbors(from)
+                .iter()
+                .any(|edge| edge.to == to && edge.weight == weight));
+        }
+
+        let mut connected = DisjointSet::new(graph.len(), UnionBy::Size);
+        graph.edges().for_each(|(from, to, _)| {
+            connected.union(from, to);
+        });
+        assert_eq!(components.components(), connected.components());
+    }
+
+    #[test]
+    fn against_brute_force() {
+        let mut random = SeededRandom::new(49);
+        for _ in 0..150 {
+            let len = random.get_in_range(1, 7);
+            let mut graph = Graph::undirected(len);
+            for _ in 0..random.get_in_range(0, 11) {
+                let (from, to) = (random.get_in_range(0, len), random.get_in_range(0, len));
+                graph
+                    .add_edge(from, to, random.get_in_range(-5, 10))
+                    .unwrap();
+            }
+
+            let (kruskal, prim) = (graph.kruskal().unwrap(), graph.prim().unwrap());
+            assert_spanning_forest(&graph, &kruskal);
+            assert_spanning_forest(&graph, &prim);
+            assert_eq!(kruskal.weight, prim.weight);
+            assert_eq!(kruskal.weight, brute_force(&graph));
+        }
+    }
+
+    #[test]
+    fn directed_is_rejected() {
+        let graph = Graph::new(2, GraphKind::Directed);
+        assert_eq!(graph.kruskal(), Err(GraphError::Directed));
+        assert_eq!(graph.prim(), Err(GraphError::Directed));
+    }
+}
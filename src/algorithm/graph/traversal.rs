@@ -0,0 +1,408 @@
+use std::collections::VecDeque;
+
+use super::{Graph, GraphError};
+
+/// DFS state of a vertex.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Unvisited,
+    // On the current path.
+    Open,
+    Done,
+}
+
+impl Graph {
+    /// Vertices reachable from `source`, nearest first, neighbors in the order edges were added.
+    ///
+    /// @If `source` is out of `0..len` -> `Err(NoSuchVertex)`.
+    pub fn bfs(&self, source: usize) -> Result<Vec<usize>, GraphError> {
+        self.check_vertex(source)?;
+        let mut seen = vec![false; self.len()];
+        let mut order = vec![source];
+        seen[source] = true;
+
+        let mut next = 0;
+        while let Some(&v) = order.get(next) {
+            next += 1;
+            for edge in &self.adjacency[v] {
+                if !seen[edge.to] {
+                    seen[edge.to] = true;
+                    order.push(edge.to);
+                }
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Fewest edges from `source` to each vertex, ignoring weights.
+    ///
+    /// @If `source` is out of `0..len` -> `Err(NoSuchVertex)`.
+    pub fn hops(&self, source: usize) -> Result<Vec<Option<usize>>, GraphError> {
+        self.check_vertex(source)?;
+        let mut hops = vec![None; self.len()];
+        let mut queue = VecDeque::from([source]);
+        hops[source] = Some(0);
+
+        while let Some(v) = queue.pop_front() {
+            let next = hops[v].map(|h| h + 1);
+            for edge in &self.adjacency[v] {
+                if hops[edge.to].is_none() {
+                    hops[edge.to] = next;
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        Ok(hops)
+    }
+
+    /// Vertices reachable from `source` in preorder, the same a recursive DFS gives.
+    /// Iterative, so long paths don't overflow the stack.
+    ///
+    /// @If `source` is out of `0..len` -> `Err(NoSuchVertex)`.
+    pub fn dfs(&self, source: usize) -> Result<Vec<usize>, GraphError> {
+        self.check_vertex(source)?;
+        let mut seen = vec![false; self.len()];
+        let mut order = vec![source];
+        // Vertex and the index of its next edge to try.
+        let mut stack = vec![(source, 0)];
+        seen[source] = true;
+
+        while let Some((v, next)) = stack.last_mut() {
+            let Some(edge) = self.adjacency[*v].get(*next) else {
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+
+            if !seen[edge.to] {
+                seen[edge.to] = true;
+                order.push(edge.to);
+                stack.push((edge.to, 0));
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Vertices so that every edge points forward.
+    /// Kahn's algorithm: vertices are taken in the order they became ready.
+    ///
+    /// @If the graph is undirected -> `Err(Undirected)`.
+    /// @If there's a cycle -> `Err(Cycle(..))` with one of them.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, GraphError> {
+        if !self.is_directed() {
+            return Err(GraphError::Undirected);
+        }
+
+        let mut in_degree = vec![0; self.len()];
+        for (_, to, _) in self.edges() {
+            in_degree[to] += 1;
+        }
+
+        let mut order = (0..self.len())
+            .filter(|&v| in_degree[v] == 0)
+            .collect::<Vec<_>>();
+        let mut next = 0;
+        while let Some(&v) = order.get(next) {
+            next += 1;
+            for edge in &self.adjacency[v] {
+                in_degree[edge.to] -= 1;
+                if in_degree[edge.to] == 0 {
+                    order.push(edge.to);
+                }
+            }
+        }
+
+        match order.len() == self.len() {
+            true => Ok(order),
+            false => Err(GraphError::Cycle(
+                self.find_cycle()
+                    .expect("Vertices left over lie on a cycle"),
+            )),
+        }
+    }
+
+    /// Vertices of some cycle in order, the last one leading back to the first.
+    /// An undirected edge doesn't make a cycle with itself, but two parallel ones do.
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        let mut color = vec![Color::Unvisited; self.len()];
+        let mut parent = vec![usize::MAX; self.len()];
+
+        for start in 0..self.len() {
+            if color[start] != Color::Unvisited {
+                continue;
+            }
+
+            color[start] = Color::Open;
+            // Vertex, index of its next edge, whether the edge back to `parent` was skipped.
+            let mut stack = vec![(start, 0, false)];
+            while let Some((v, next, skipped)) = stack.last_mut() {
+                let v = *v;
+                let Some(edge) = self.adjacency[v].get(*next) else {
+                    color[v] = Color::Done;
+                    stack.pop();
+                    continue;
+                };
+                *next += 1;
+
+                if !self.is_directed() && edge.to == parent[v] && !*skipped {
+                    *skipped = true;
+                    continue;
+                }
+                match color[edge.to] {
+                    Color::Unvisited => {
+                        color[edge.to] = Color::Open;
+                        parent[edge.to] = v;
+                        stack.push((edge.to, 0, false));
+                    }
+                    Color::Open => {
+                        let mut cycle = vec![v];
+                        while *cycle.last()? != edge.to {
+                            cycle.push(parent[*cycle.last()?]);
+                        }
+                        cycle.reverse();
+                        return Some(cycle);
+                    }
+                    Color::Done => {}
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Tarjan's algorithm, iterative. Each component ascending, components in reverse
+    /// topological order: no edge leads from a component to a later one.
+    /// For an undirected graph these are the connected components.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+
+        // Discovery time, and the earliest one reachable through the DFS subtree
+        // plus one edge into a vertex still on `path`.
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_path = vec![false; self.len()];
+        let mut path = vec![];
+        let mut time = 0;
+        let mut components = vec![];
+
+        for start in 0..self.len() {
+            if index[start] != UNVISITED {
+                continue;
+            }
+
+            // Vertex and the index of its next edge, `next == 0` on the first visit.
+            let mut stack = vec![(start, 0)];
+            while let Some(&(v, next)) = stack.last() {
+                if next == 0 {
+                    (index[v], low[v], on_path[v]) = (time, time, true);
+                    time += 1;
+                    path.push(v);
+                }
+
+                if let Some(edge) = self.adjacency[v].get(next) {
+                    stack.last_mut().expect("Just peeked").1 += 1;
+                    let u = edge.to;
+                    if index[u] == UNVISITED {
+                        stack.push((u, 0));
+                    } else if on_path[u] {
+                        low[v] = low[v].min(index[u]);
+                    }
+                    continue;
+                }
+
+                stack.pop();
+                if let Some(&(parent, _)) = stack.last() {
+                    low[parent] = low[parent].min(low[v]);
+                }
+                if low[v] == index[v] {
+                    let at = path
+                        .iter()
+                        .rposition(|&u| u == v)
+                        .expect("`v` is on the path");
+                    let mut component = path.split_off(at);
+                    component.iter().for_each(|&u| on_path[u] = false);
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::SeededRandom;
+
+    use super::{super::GraphKind, *};
+
+    fn random_graph(random: &mut SeededRandom, len: usize, edges: usize, kind: GraphKind) -> Graph {
+        let mut graph = Graph::new(len, kind);
+        for _ in 0..edges {
+            let (from, to) = (random.get_in_range(0, len), random.get_in_range(0, len));
+            graph.add_edge(from, to, 1).unwrap();
+        }
+        graph
+    }
+
+    /// Whether `to` is reachable from `from`, by plain BFS.
+    fn reaches(graph: &Graph, from: usize, to: usize) -> bool {
+        graph.bfs(from).unwrap().contains(&to)
+    }
+
+    fn assert_is_cycle(graph: &Graph, cycle: &[usize]) {
+        assert!(!cycle.is_empty());
+        for (i, &v) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(graph.neighbors(v).iter().any(|edge| edge.to == next));
+        }
+    }
+
+    #[test]
+    fn bfs_dfs_hops() {
+        let graph = Graph::from_edges(
+            6,
+            GraphKind::Directed,
+            &[
+                (0, 1, 1),
+                (0, 2, 1),
+                (1, 3, 1),
+                (2, 3, 1),
+                (3, 4, 1),
+                (1, 4, 1),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(graph.bfs(0).unwrap(), [0, 1, 2, 3, 4]);
+        assert_eq!(graph.dfs(0).unwrap(), [0, 1, 3, 4, 2]);
+        assert_eq!(
+            graph.hops(0).unwrap(),
+            [Some(0), Some(1), Some(1), Some(2), Some(2), None]
+        );
+        assert_eq!(graph.bfs(5).unwrap(), [5]);
+
+        assert_eq!(graph.bfs(6), Err(GraphError::NoSuchVertex));
+        assert_eq!(graph.dfs(6), Err(GraphError::NoSuchVertex));
+        assert_eq!(graph.hops(6), Err(GraphError::NoSuchVertex));
+    }
+
+    #[test]
+    fn long_path_doesnt_overflow() {
+        let len = 200_000;
+        let mut graph = Graph::directed(len);
+        for v in 1..len {
+            graph.add_edge(v - 1, v, 1).unwrap();
+        }
+
+        assert_eq!(graph.dfs(0).unwrap().len(), len);
+        assert_eq!(graph.strongly_connected_components().len(), len);
+        assert!(!graph.has_cycle());
+    }
+
+    #[test]
+    fn topological_sort() {
+        let mut random = SeededRandom::new(48);
+        for _ in 0..50 {
+            // Edges only go up, so it's acyclic.
+            let mut graph = Graph::directed(20);
+            for _ in 0..40 {
+                let (a, b) = (random.get_in_range(0, 20), random.get_in_range(0, 20));
+                if a != b {
+                    graph.add_edge(a.min(b), a.max(b), 1).unwrap();
+                }
+            }
+
+            let order = graph.topological_sort().unwrap();
+            let mut position = vec![0; graph.len()];
+            order.iter().enumerate().for_each(|(i, &v)| position[v] = i);
+            assert!(graph
+                .edges()
+                .all(|(from, to, _)| position[from] < position[to]));
+            assert!(!graph.has_cycle());
+        }
+
+        let cyclic =
+            Graph::from_edges(3, GraphKind::Directed, &[(0, 1, 1), (1, 2, 1), (2, 1, 1)]).unwrap();
+        assert_eq!(
+            cyclic.topological_sort(),
+            Err(GraphError::Cycle(vec![1, 2]))
+        );
+        assert_eq!(
+            Graph::undirected(2).topological_sort(),
+            Err(GraphError::Undirected)
+        );
+    }
+
+    #[test]
+    fn cycles() {
+        let mut random = SeededRandom::new(480);
+        for kind in [GraphKind::Directed, GraphKind::Undirected] {
+            for _ in 0..200 {
+                let len = random.get_in_range(1, 8);
+                let edges = random.get_in_range(0, 8);
+                let graph = random_graph(&mut random, len, edges, kind);
+
+                // Has a cycle iff some edge's endpoints are connected without it.
+                let expected = graph.edges().enumerate().any(|(i, (from, to, _))| {
+                    let mut without = Graph::new(len, kind);
+                    graph
+                        .edges()
+                        .enumerate()
+                        .filter(|&(j, _)| j != i)
+                        .for_each(|(_, (a, b, w))| without.add_edge(a, b, w).unwrap());
+                    reaches(&without, to, from)
+                });
+
+                match graph.find_cycle() {
+                    Some(cycle) => assert_is_cycle(&graph, &cycle),
+                    None => assert!(!expected),
+                }
+                assert_eq!(graph.has_cycle(), expected);
+            }
+        }
+
+        let mut path = Graph::undirected(3);
+        path.add_edge(0, 1, 1).unwrap();
+        path.add_edge(1, 2, 1).unwrap();
+        assert!(!path.has_cycle());
+        path.add_edge(2, 1, 1).unwrap();
+        assert_eq!(path.find_cycle(), Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let mut random = SeededRandom::new(481);
+        for _ in 0..100 {
+            let len = random.get_in_range(1, 12);
+            let edges = random.get_in_range(0, 20);
+            let graph = random_graph(&mut random, len, edges, GraphKind::Directed);
+            let components = graph.strongly_connected_components();
+
+            let mut component_of = vec![usize::MAX; len];
+            for (i, component) in components.iter().enumerate() {
+                component.iter().for_each(|&v| component_of[v] = i);
+            }
+            assert!(component_of.iter().all(|&c| c != usize::MAX));
+
+            for a in 0..len {
+                for b in 0..len {
+                    let together = reaches(&graph, a, b) && reaches(&graph, b, a);
+                    assert_eq!(component_of[a] == component_of[b], together);
+                }
+            }
+            // Reverse topological order.
+            assert!(graph
+                .edges()
+                .all(|(from, to, _)| component_of[from] >= component_of[to]));
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::{hash::Hash, mem};
+
+use crate::algorithm::linked_list::LinkedList;
+
+use super::{hash, Stats, MIN_CAPACITY};
+
+/// Hash map keeping colliding entries in a [`LinkedList`] per bucket.
+pub struct ChainedMap<K, V> {
+    // Length is a power of two.
+    buckets: Vec<LinkedList<(K, V)>>,
+    len: usize,
+}
+
+impl<K: Hash + Eq, V> ChainedMap<K, V> {
+    pub fn new() -> Self {
+        Self::with_capacity(MIN_CAPACITY)
+    }
+
+    /// Rounds `capacity` up to a power of two.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(MIN_CAPACITY).next_power_of_two();
+
+        Self {
+            buckets: (0..capacity).map(|_| LinkedList::new()).collect(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Buckets.
+    pub fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+
+    fn bucket(&self, key: &K) -> usize {
+        hash(key) as usize & (self.capacity() - 1)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.buckets[self.bucket(key)]
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let bucket = self.bucket(key);
+        self.buckets[bucket]
+            .iter_mut()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// @Returns previous value of `key`.
+    /// @Mutate doubles the buckets once there are more keys than buckets.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(mem::replace(old, value));
+        }
+
+        if self.len + 1 > self.capacity() {
+            self.rehash(self.capacity() * 2);
+        }
+
+        let bucket = self.bucket(&key);
+        self.buckets[bucket].push((key, value));
+        self.len += 1;
+        None
+    }
+
+    fn rehash(&mut self, capacity: usize) {
+        let buckets = (0..capacity).map(|_| LinkedList::new()).collect();
+        let old = mem::replace(&mut self.buckets, buckets);
+
+        for (key, value) in old.into_iter().flatten() {
+            let bucket = self.bucket(&key);
+            self.buckets[bucket].push((key, value));
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let bucket = self.bucket(key);
+        let mut cursor = self.buckets[bucket].cursor_front_mut();
+
+        while cursor.index().is_some() {
+            let found = matches!(cursor.current(), Some((k, _)) if k == key);
+            if found {
+                let (_, value) = cursor.remove_current()?;
+                self.len -= 1;
+                return Some(value);
+            }
+            cursor.move_next();
+        }
+
+        None
+    }
+
+    /// Bucket order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.buckets.iter().flatten().map(|(k, v)| (k, v))
+    }
+
+    /// Probe length is the position in the chain, counting from 1.
+    pub fn stats(&self) -> Stats {
+        let probes = self.buckets.iter().flat_map(|bucket| 1..=bucket.size());
+
+        Stats::new(self.len, self.capacity(), 0, probes)
+    }
+}
+
+impl<K: Hash + Eq, V> Default for ChainedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::super::Algorithm for ChainedMap<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut map = ChainedMap::new();
+        for key in 0..2_000 {
+            map.insert(key * 7, key);
+        }
+        println!("2000 inserts");
+        println!("{}", map.stats());
+
+        for key in (0..2_000).step_by(3) {
+            map.remove(&(key * 7));
+        }
+        println!("Every 3rd removed, chains just get shorter");
+        println!("{}", map.stats());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        ChainedMap::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_std() {
+        let mut random = SeededRandom::new(450);
+        let mut map = ChainedMap::new();
+        let mut reference = HashMap::new();
+
+        for i in 0..4_000 {
+            let key = random.get_in_range(0, 500u32);
+            match random.get_bool(0.55) {
+                true => assert_eq!(map.insert(key, i), reference.insert(key, i)),
+                false => assert_eq!(map.remove(&key), reference.remove(&key)),
+            }
+
+            assert_eq!(map.get(&key), reference.get(&key));
+            assert_eq!(map.len(), reference.len());
+            assert!(map.len() <= map.capacity());
+        }
+
+        let mut entries = map.iter().map(|(&k, &v)| (k, v)).collect::<Vec<_>>();
+        let mut expected = reference.into_iter().collect::<Vec<_>>();
+        entries.sort();
+        expected.sort();
+        assert_eq!(entries, expected);
+    }
+
+    #[test]
+    fn stats() {
+        let mut map = ChainedMap::with_capacity(8);
+        for key in 0..8u32 {
+            map.insert(key, ());
+        }
+        assert_eq!(map.capacity(), 8);
+
+        let stats = map.stats();
+        assert_eq!(stats.load_factor, 1.);
+        assert_eq!(stats.tombstones, 0);
+        assert_eq!(stats.histogram.iter().sum::<usize>(), 8);
+
+        map.insert(8, ());
+        assert_eq!(map.capacity(), 16);
+        *map.get_mut(&8).unwrap() = ();
+        assert!(map.contains_key(&8));
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+mod chained;
+mod open;
+pub use chained::*;
+pub use open::*;
+
+/// Buckets in a new map, always a power of two.
+const MIN_CAPACITY: usize = 8;
+
+/// Fixed keys, so runs (and their statistics) are reproducible.
+fn hash<K: Hash>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Snapshot of how well a map spreads its keys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub len: usize,
+    /// Slots, or buckets for chaining.
+    pub capacity: usize,
+    pub tombstones: usize,
+    pub load_factor: f64,
+    /// `histogram[n]` keys are found after looking at `n + 1` slots (or chain nodes).
+    pub histogram: Vec<usize>,
+}
+
+impl Stats {
+    fn new(
+        len: usize,
+        capacity: usize,
+        tombstones: usize,
+        probes: impl Iterator<Item = usize>,
+    ) -> Self {
+        let mut histogram = vec![];
+        for probe in probes {
+            if histogram.len() < probe {
+                histogram.resize(probe, 0);
+            }
+            histogram[probe - 1] += 1;
+        }
+
+        Self {
+            len,
+            capacity,
+            tombstones,
+            load_factor: len as f64 / capacity as f64,
+            histogram,
+        }
+    }
+
+    /// Slots looked at by the longest successful lookup.
+    pub fn max_probe(&self) -> usize {
+        self.histogram.len()
+    }
+
+    /// Slots looked at by an average successful lookup.
+    pub fn mean_probe(&self) -> f64 {
+        let total = self
+            .histogram
+            .iter()
+            .enumerate()
+            .map(|(i, count)| (i + 1) * count)
+            .sum::<usize>();
+
+        match self.len {
+            0 => 0.,
+            len => total as f64 / len as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const BAR: usize = 40;
+
+        writeln!(
+            f,
+            "len {}, capacity {}, load factor {:.2}, tombstones {}",
+            self.len, self.capacity, self.load_factor, self.tombstones
+        )?;
+
+        let most = self.histogram.iter().copied().max().unwrap_or(0).max(1);
+        for (i, &count) in self.histogram.iter().enumerate() {
+            let bar = "█".repeat((count * BAR).div_ceil(most));
+            writeln!(f, "{:>4} | {bar} {count}", i + 1)?;
+        }
+
+        writeln!(
+            f,
+            "mean probe {:.2}, max probe {}",
+            self.mean_probe(),
+            self.max_probe()
+        )
+    }
+}
@@ -0,0 +1,415 @@
+use std::{hash::Hash, mem};
+
+use super::{hash, Stats, MIN_CAPACITY};
+
+/// Grow (or clean up tombstones) once this share of slots is taken.
+const MAX_LOAD: f64 = 0.75;
+
+/// Where to look next after a collision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Probing {
+    /// `h, h + 1, h + 2, ...`
+    #[default]
+    Linear,
+    /// `h, h + 1, h + 3, h + 6, ...`: triangular steps, which visit every slot
+    /// of a power-of-two table.
+    Quadratic,
+    /// Linear, but an insert takes the slot of any key closer to its home.
+    /// Removal shifts the run back instead of leaving tombstones.
+    RobinHood,
+}
+
+struct Entry<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+enum Slot<K, V> {
+    Empty,
+    /// Removed entry: lookups go on past it, inserts may reuse it.
+    Tombstone,
+    Full(Entry<K, V>),
+}
+
+/// Hash map storing entries right in its table, collisions resolved by [`Probing`].
+pub struct OpenMap<K, V> {
+    // Length is a power of two.
+    slots: Vec<Slot<K, V>>,
+    len: usize,
+    tombstones: usize,
+    probing: Probing,
+}
+
+impl<K: Hash + Eq, V> OpenMap<K, V> {
+    pub fn new(probing: Probing) -> Self {
+        Self::with_capacity(probing, MIN_CAPACITY)
+    }
+
+    /// Rounds `capacity` up to a power of two.
+    pub fn with_capacity(probing: Probing, capacity: usize) -> Self {
+        let capacity = capacity.max(MIN_CAPACITY).next_power_of_two();
+
+        Self {
+            slots: (0..capacity).map(|_| Slot::Empty).collect(),
+            len: 0,
+            tombstones: 0,
+            probing,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn probing(&self) -> Probing {
+        self.probing
+    }
+
+    /// Slot visited `i`th when looking for `hash`.
+    fn probe(&self, hash: u64, i: usize) -> usize {
+        let offset = match self.probing {
+            Probing::Linear | Probing::RobinHood => i,
+            Probing::Quadratic => i * (i + 1) / 2,
+        };
+
+        (hash as usize).wrapping_add(offset) & (self.capacity() - 1)
+    }
+
+    /// Steps from the home slot of `hash` to `index`, linear probing only.
+    fn distance(&self, hash: u64, index: usize) -> usize {
+        index.wrapping_sub(hash as usize) & (self.capacity() - 1)
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        let hash = hash(key);
+
+        for i in 0..self.capacity() {
+            let index = self.probe(hash, i);
+            match &self.slots[index] {
+                Slot::Empty => return None,
+                Slot::Tombstone => {}
+                Slot::Full(entry) if entry.hash == hash && entry.key == *key => return Some(index),
+                // Robin Hood: `key` would have displaced this entry.
+                Slot::Full(entry) => {
+                    if self.probing == Probing::RobinHood && self.distance(entry.hash, index) < i {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match &self.slots[self.find(key)?] {
+            Slot::Full(entry) => Some(&entry.value),
+            _ => unreachable!("`find` returns full slots"),
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.find(key)?;
+        match &mut self.slots[index] {
+            Slot::Full(entry) => Some(&mut entry.value),
+            _ => unreachable!("`find` returns full slots"),
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// @Returns previous value of `key`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(mem::replace(old, value));
+        }
+
+        self.reserve_one();
+        let hash = hash(&key);
+        self.place(Entry { hash, key, value });
+        None
+    }
+
+    /// Puts a key that isn't in the map yet.
+    fn place(&mut self, mut entry: Entry<K, V>) {
+        self.len += 1;
+
+        // `reserve_one` keeps a free slot, so this ends.
+        let mut i = 0;
+        loop {
+            let index = self.probe(entry.hash, i);
+            match &self.slots[index] {
+                Slot::Empty => break self.slots[index] = Slot::Full(entry),
+                Slot::Tombstone => {
+                    self.tombstones -= 1;
+                    break self.slots[index] = Slot::Full(entry);
+                }
+                Slot::Full(other) => {
+                    // Rich (close to home) entry gives its slot to the poor one
+                    // and carries on probing from there.
+                    let distance = self.distance(other.hash, index);
+                    if self.probing == Probing::RobinHood && distance < i {
+                        if let Slot::Full(other) = &mut self.slots[index] {
+                            mem::swap(other, &mut entry);
+                        }
+                        i = distance;
+                    }
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    /// Rehashes before the insert that would push the load over [`MAX_LOAD`]:
+    /// into twice the slots if live entries need them, otherwise in place, dropping tombstones.
+    fn reserve_one(&mut self) {
+        let limit = self.capacity() as f64 * MAX_LOAD;
+        if ((self.len + self.tombstones + 1) as f64) <= limit {
+            return;
+        }
+
+        let capacity = match (self.len + 1) as f64 > limit / 2. {
+            true => self.capacity() * 2,
+            false => self.capacity(),
+        };
+        self.rehash(capacity);
+    }
+
+    fn rehash(&mut self, capacity: usize) {
+        let slots = (0..capacity).map(|_| Slot::Empty).collect();
+        let old = mem::replace(&mut self.slots, slots);
+        (self.len, self.tombstones) = (0, 0);
+
+        for slot in old {
+            if let Slot::Full(entry) = slot {
+                self.place(entry);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let mut index = self.find(key)?;
+        self.len -= 1;
+
+        let replacement = match self.probing {
+            Probing::RobinHood => Slot::Empty,
+            Probing::Linear | Probing::Quadratic => Slot::Tombstone,
+        };
+        let Slot::Full(removed) = mem::replace(&mut self.slots[index], replacement) else {
+            unreachable!("`find` returns full slots");
+        };
+
+        match self.probing {
+            Probing::Linear | Probing::Quadratic => self.tombstones += 1,
+            // Backward shift: pull the rest of the run one slot closer to home.
+            Probing::RobinHood => loop {
+                let next = (index + 1) & (self.capacity() - 1);
+                match &self.slots[next] {
+                    Slot::Full(entry) if self.distance(entry.hash, next) > 0 => {
+                        self.slots.swap(index, next);
+                        index = next;
+                    }
+                    _ => break,
+                }
+            },
+        }
+
+        Some(removed.value)
+    }
+
+    /// Table order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slots.iter().filter_map(|slot| match slot {
+            Slot::Full(entry) => Some((&entry.key, &entry.value)),
+            _ => None,
+        })
+    }
+
+    /// Slots a successful lookup of the entry at `index` looks at.
+    fn probe_length(&self, hash: u64, index: usize) -> usize {
+        (0..self.capacity())
+            .position(|i| self.probe(hash, i) == index)
+            .expect("Every slot is on the probe sequence")
+            + 1
+    }
+
+    pub fn stats(&self) -> Stats {
+        let probes = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match slot {
+                Slot::Full(entry) => Some(self.probe_length(entry.hash, index)),
+                _ => None,
+            });
+
+        Stats::new(self.len, self.capacity(), self.tombstones, probes)
+    }
+
+    /// Counters match the table, every entry is reachable,
+    /// Robin Hood runs never skip a slot.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let count = |f: fn(&Slot<K, V>) -> bool| self.slots.iter().filter(|slot| f(slot)).count();
+        let (full, tombstones) = (
+            count(|slot| matches!(slot, Slot::Full(_))),
+            count(|slot| matches!(slot, Slot::Tombstone)),
+        );
+        if (full, tombstones) != (self.len, self.tombstones) {
+            return Err(format!(
+                "Counted {full} entries and {tombstones} tombstones, stored {} and {}",
+                self.len, self.tombstones
+            ));
+        }
+        if !self.capacity().is_power_of_two()
+            || (full + tombstones) as f64 > self.capacity() as f64 * MAX_LOAD
+        {
+            return Err(format!(
+                "{} slots taken out of {}",
+                full + tombstones,
+                self.capacity()
+            ));
+        }
+
+        for (index, slot) in self.slots.iter().enumerate() {
+            let Slot::Full(entry) = slot else {
+                continue;
+            };
+            if self.find(&entry.key) != Some(index) {
+                return Err(format!("Entry at {index} is unreachable"));
+            }
+
+            let distance = self.distance(entry.hash, index);
+            if self.probing == Probing::RobinHood && distance > 0 {
+                let previous = (index + self.capacity() - 1) & (self.capacity() - 1);
+                match &self.slots[previous] {
+                    Slot::Full(other) if self.distance(other.hash, previous) + 1 >= distance => {}
+                    _ => return Err(format!("Entry at {index} could sit closer to home")),
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl super::super::Algorithm for OpenMap<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        for probing in [Probing::Linear, Probing::Quadratic, Probing::RobinHood] {
+            let mut map = OpenMap::new(probing);
+            for key in 0..2_000 {
+                map.insert(key * 7, key);
+            }
+            // Linear and quadratic leave tombstones behind.
+            for key in (0..2_000).step_by(3) {
+                map.remove(&(key * 7));
+            }
+
+            println!("{probing:?}: 2000 inserts, every 3rd removed");
+            println!("{}", map.stats());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    const ALL: [Probing; 3] = [Probing::Linear, Probing::Quadratic, Probing::RobinHood];
+
+    #[test]
+    fn showcase() {
+        OpenMap::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_std() {
+        for probing in ALL {
+            let mut random = SeededRandom::new(45);
+            let mut map = OpenMap::new(probing);
+            let mut reference = HashMap::new();
+
+            for i in 0..4_000 {
+                let key = random.get_in_range(0, 500u32);
+                match random.get_bool(0.55) {
+                    true => assert_eq!(map.insert(key, i), reference.insert(key, i)),
+                    false => assert_eq!(map.remove(&key), reference.remove(&key)),
+                }
+
+                map.check_invariants().unwrap();
+                assert_eq!(map.get(&key), reference.get(&key));
+                assert_eq!(map.len(), reference.len());
+            }
+
+            let mut entries = map.iter().map(|(&k, &v)| (k, v)).collect::<Vec<_>>();
+            let mut expected = reference.into_iter().collect::<Vec<_>>();
+            entries.sort();
+            expected.sort();
+            assert_eq!(entries, expected);
+        }
+    }
+
+    #[test]
+    fn tombstones_are_cleaned_up() {
+        let mut map = OpenMap::new(Probing::Linear);
+        // Churn through many keys while never holding more than a few.
+        for key in 0..10_000u32 {
+            map.insert(key, ());
+            if key >= 4 {
+                map.remove(&(key - 4));
+            }
+        }
+
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.capacity(), 2 * MIN_CAPACITY);
+        map.check_invariants().unwrap();
+    }
+
+    #[test]
+    fn robin_hood_has_no_tombstones() {
+        let mut map = OpenMap::new(Probing::RobinHood);
+        for key in 0..1_000u32 {
+            map.insert(key, key);
+        }
+        for key in (0..1_000).step_by(2) {
+            map.remove(&key);
+        }
+
+        let stats = map.stats();
+        assert_eq!(stats.tombstones, 0);
+        assert_eq!(stats.len, 500);
+        assert_eq!(stats.histogram.iter().sum::<usize>(), 500);
+        assert!(stats.mean_probe() >= 1.);
+    }
+
+    #[test]
+    fn stats() {
+        let mut map = OpenMap::with_capacity(Probing::Quadratic, 100);
+        assert_eq!(map.capacity(), 128);
+        assert_eq!(map.stats().mean_probe(), 0.);
+
+        for key in 0..64u32 {
+            map.insert(key, ());
+        }
+        let stats = map.stats();
+        assert_eq!(stats.load_factor, 0.5);
+        assert_eq!(stats.max_probe(), stats.histogram.len());
+        assert!(stats.histogram[0] > 0);
+    }
+}
@@ -0,0 +1,483 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HeapError {
+    /// Element was already popped.
+    InvalidHandle,
+    /// New key would move the element away from the top.
+    WrongDirection,
+}
+
+impl super::Error for HeapError {}
+impl Display for HeapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeapOrder {
+    /// Smallest element on top.
+    Min,
+    /// Largest element on top.
+    #[default]
+    Max,
+}
+
+/// Stable reference to a pushed element, valid until it's popped.
+///
+/// Handles must only be passed back to the heap that gave them out, another heap
+/// may take one for an element of its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle {
+    slot: usize,
+    generation: usize,
+}
+
+/// Where a handle's element sits in the heap. Slots are reused once their element
+/// is popped, the generation tells the old handles from the new one.
+#[derive(Clone, Copy, Debug)]
+struct Slot {
+    position: Option<usize>,
+    generation: usize,
+}
+
+struct Entry<T> {
+    handle: Handle,
+    value: T,
+}
+
+/// Binary heap that can change keys of its elements through [`Handle`]s.
+pub struct BinaryHeap<T> {
+    data: Vec<Entry<T>>,
+    slots: Vec<Slot>,
+    // Slots of popped elements, to be given out again.
+    free: Vec<usize>,
+    order: HeapOrder,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new(order: HeapOrder) -> Self {
+        Self {
+            data: vec![],
+            slots: vec![],
+            free: vec![],
+            order,
+        }
+    }
+
+    pub fn min() -> Self {
+        Self::new(HeapOrder::Min)
+    }
+
+    pub fn max() -> Self {
+        Self::new(HeapOrder::Max)
+    }
+
+    /// Builds the heap bottom-up in O(n).
+    /// Handles are given out in the order of `values`.
+    pub fn heapify(values: Vec<T>, order: HeapOrder) -> (Self, Vec<Handle>) {
+        let handles = (0..values.len())
+            .map(|slot| Handle {
+                slot,
+                generation: 0,
+            })
+            .collect::<Vec<_>>();
+        let mut heap = Self {
+            slots: (0..values.len())
+                .map(|i| Slot {
+                    position: Some(i),
+                    generation: 0,
+                })
+                .collect(),
+            free: vec![],
+            data: values
+                .into_iter()
+                .zip(&handles)
+                .map(|(value, &handle)| Entry { handle, value })
+                .collect(),
+            order,
+        };
+
+        for i in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+
+        (heap, handles)
+    }
+
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn order(&self) -> HeapOrder {
+        self.order
+    }
+
+    /// `a` should be closer to the top than `b`.
+    fn is_higher(&self, a: &T, b: &T) -> bool {
+        match self.order {
+            HeapOrder::Min => a < b,
+            HeapOrder::Max => a > b,
+        }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+        self.slots[self.data[i].handle.slot].position = Some(i);
+        self.slots[self.data[j].handle.slot].position = Some(j);
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.is_higher(&self.data[i].value, &self.data[parent].value) {
+                break;
+            }
+
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let top = [2 * i + 1, 2 * i + 2]
+                .into_iter()
+                .filter(|&child| child < self.data.len())
+                .fold(i, |top, child| {
+                    match self.is_higher(&self.data[child].value, &self.data[top].value) {
+                        true => child,
+                        false => top,
+                    }
+                });
+
+            if top == i {
+                break;
+            }
+
+            self.swap(i, top);
+            i = top;
+        }
+    }
+
+    fn position(&self, handle: Handle) -> Result<usize, HeapError> {
+        self.slots
+            .get(handle.slot)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.position)
+            .ok_or(HeapError::InvalidHandle)
+    }
+
+    pub fn push(&mut self, value: T) -> Handle {
+        let position = Some(self.data.len());
+        let handle = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot].position = position;
+                Handle {
+                    slot,
+                    generation: self.slots[slot].generation,
+                }
+            }
+            None => {
+                self.slots.push(Slot {
+                    position,
+                    generation: 0,
+                });
+                Handle {
+                    slot: self.slots.len() - 1,
+                    generation: 0,
+                }
+            }
+        };
+        self.data.push(Entry { handle, value });
+        self.sift_up(self.data.len() - 1);
+
+        handle
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first().map(|entry| &entry.value)
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_with_handle().map(|(_, value)| value)
+    }
+
+    pub fn pop_with_handle(&mut self) -> Option<(Handle, T)> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let last = self.data.len() - 1;
+        self.swap(0, last);
+        let entry = self.data.pop()?;
+        let slot = &mut self.slots[entry.handle.slot];
+        slot.position = None;
+        slot.generation += 1;
+        self.free.push(entry.handle.slot);
+        self.sift_down(0);
+
+        Some((entry.handle, entry.value))
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        let i = self.position(handle).ok()?;
+        Some(&self.data[i].value)
+    }
+
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_ok()
+    }
+
+    /// Moves the element towards the top: the key may only get smaller
+    /// in a [`HeapOrder::Min`] heap and only larger in a [`HeapOrder::Max`] one.
+    pub fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), HeapError> {
+        let i = self.position(handle)?;
+        if self.is_higher(&self.data[i].value, &value) {
+            return Err(HeapError::WrongDirection);
+        }
+
+        self.data[i].value = value;
+        self.sift_up(i);
+
+        Ok(())
+    }
+
+    /// Sets any key, moving the element whichever way it needs to go.
+    pub fn update_key(&mut self, handle: Handle, value: T) -> Result<(), HeapError> {
+        let i = self.position(handle)?;
+
+        self.data[i].value = value;
+        self.sift_up(i);
+        if let Ok(i) = self.position(handle) {
+            self.sift_down(i);
+        }
+
+        Ok(())
+    }
+
+    /// Elements from the top to the bottom of the heap. O(n log n).
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        std::iter::from_fn(|| self.pop()).collect()
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new(HeapOrder::default())
+    }
+}
+
+impl<T: Ord> Extend<T> for BinaryHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| {
+            self.push(value);
+        });
+    }
+}
+
+/// In-place ascending heap sort. O(n log n), not stable.
+/// Same as [`Sort::Heap`](super::sort::Sort::Heap), without the stats.
+pub fn heap_sort<T: Ord>(v: &mut [T]) {
+    super::sort::Sort::Heap.sort(v);
+}
+
+impl super::Algorithm for BinaryHeap<u32> {
+    fn showcase() -> Result<(), Box<dyn super::Error>> {
+        let mut heap = BinaryHeap::<u32>::min();
+
+        let handles = [52, 30, 727, 1000, 144]
+            .into_iter()
+            .map(|v| (v, heap.push(v)))
+            .collect::<Vec<_>>();
+        println!("Top of min-heap: {:?}", heap.peek());
+
+        let (value, handle) = handles[2];
+        heap.decrease_key(handle, 1)?;
+        println!("Decreased {value} -> 1, top: {:?}", heap.peek());
+
+        if let Err(e) = heap.decrease_key(handles[3].1, 2000) {
+            println!("Can't decrease 1000 -> 2000: {e}");
+        }
+
+        println!("Popped: {:?}", heap.pop());
+        println!("Rest in order: {:?}", heap.into_sorted_vec());
+
+        let (max_heap, _) = BinaryHeap::heapify(vec![3, 1, 4, 1, 5, 9, 2, 6], HeapOrder::Max);
+        println!("Heapified max-heap: {:?}", max_heap.into_sorted_vec());
+
+        let mut v = vec![5, 2, 8, 1, 9];
+        heap_sort(&mut v);
+        println!("Heap sort: {v:?}");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use crate::random::SeededRandom;
+
+    use super::super::Algorithm;
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        BinaryHeap::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_std() {
+        let mut random = SeededRandom::new(38);
+
+        let mut max_heap = BinaryHeap::max();
+        let mut min_heap = BinaryHeap::min();
+        let mut std_max = std::collections::BinaryHeap::new();
+        let mut std_min = std::collections::BinaryHeap::new();
+
+        for _ in 0..10_000 {
+            if random.get_bool(0.6) {
+                let v = random.get_in_range(0, 100u32);
+                max_heap.push(v);
+                min_heap.push(v);
+                std_max.push(v);
+                std_min.push(Reverse(v));
+            } else {
+                assert_eq!(max_heap.pop(), std_max.pop());
+                assert_eq!(min_heap.pop(), std_min.pop().map(|Reverse(v)| v));
+            }
+
+            assert_eq!(max_heap.peek(), std_max.peek());
+            assert_eq!(min_heap.size(), std_min.len());
+        }
+
+        assert_eq!(max_heap.into_sorted_vec(), {
+            let mut v = std_max.into_sorted_vec();
+            v.reverse();
+            v
+        });
+    }
+
+    #[test]
+    fn heapify() {
+        let mut random = SeededRandom::new(83);
+
+        for size in [0, 1, 2, 5, 100] {
+            let values = random.get_vec::<i32>(size);
+            let (heap, handles) = BinaryHeap::heapify(values.clone(), HeapOrder::Min);
+
+            for (handle, value) in handles.iter().zip(&values) {
+                assert_eq!(heap.get(*handle), Some(value));
+            }
+
+            let mut sorted = values;
+            sorted.sort();
+            assert_eq!(heap.into_sorted_vec(), sorted);
+        }
+    }
+
+    #[test]
+    fn decrease_key() {
+        let mut random = SeededRandom::new(380);
+        let mut heap = BinaryHeap::min();
+        // Naive model: every live (handle, value).
+        let mut model = Vec::<(Handle, u32)>::new();
+
+        for _ in 0..5_000 {
+            match random.get_in_range(0, 3) {
+                0 => {
+                    let v = random.get_in_range(0, 1_000);
+                    model.push((heap.push(v), v));
+                }
+                1 if !model.is_empty() => {
+                    let i = random.get_in_range(0, model.len());
+                    let (handle, old) = model[i];
+                    let v = random.get_in_range(0, old + 1);
+
+                    heap.decrease_key(handle, v).unwrap();
+                    model[i].1 = v;
+                }
+                _ => {
+                    let (handle, v) = heap.pop_with_handle().unzip();
+                    let min = model.iter().map(|&(_, v)| v).min();
+                    assert_eq!(v, min);
+
+                    if let Some(handle) = handle {
+                        let i = model.iter().position(|&(h, _)| h == handle).unwrap();
+                        assert_eq!(model.swap_remove(i).1, v.unwrap());
+                        assert!(!heap.contains(handle));
+                    }
+                }
+            }
+
+            assert_eq!(heap.size(), model.len());
+        }
+    }
+
+    #[test]
+    fn slots_are_reused() {
+        let mut heap = BinaryHeap::min();
+        let stale = heap.push(1);
+        heap.pop();
+
+        for i in 0..100 {
+            let handle = heap.push(i);
+            assert_ne!(handle, stale);
+            assert!(!heap.contains(stale));
+            heap.pop();
+        }
+        assert_eq!(heap.slots.len(), 1);
+        assert_eq!(heap.decrease_key(stale, 0), Err(HeapError::InvalidHandle));
+    }
+
+    #[test]
+    fn key_errors() {
+        let mut heap = BinaryHeap::max();
+        let a = heap.push(10);
+        let b = heap.push(20);
+
+        assert_eq!(heap.decrease_key(a, 5), Err(HeapError::WrongDirection));
+        assert_eq!(heap.decrease_key(a, 30), Ok(()));
+        assert_eq!(heap.peek(), Some(&30));
+
+        assert_eq!(heap.update_key(a, 0), Ok(()));
+        assert_eq!(heap.pop_with_handle(), Some((b, 20)));
+        assert_eq!(heap.pop(), Some(0));
+
+        assert_eq!(heap.decrease_key(a, 1), Err(HeapError::InvalidHandle));
+        assert_eq!(
+            heap.update_key(
+                Handle {
+                    slot: 42,
+                    generation: 0
+                },
+                1
+            ),
+            Err(HeapError::InvalidHandle)
+        );
+        assert_eq!(heap.get(b), None);
+    }
+
+    #[test]
+    fn sort() {
+        let mut random = SeededRandom::new(8);
+
+        for size in [0, 1, 2, 3, 10, 1000] {
+            let mut v = (0..size)
+                .map(|_| random.get_in_range(0, 20))
+                .collect::<Vec<i32>>();
+            let mut expected = v.clone();
+
+            heap_sort(&mut v);
+            expected.sort();
+            assert_eq!(v, expected);
+        }
+    }
+}
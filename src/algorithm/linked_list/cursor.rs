@@ -0,0 +1,225 @@
+use super::*;
+
+/// Cursor that can edit a [`LinkedList`] in place, every operation is O(1).
+///
+/// Between `tail` and `head` there is a "ghost" position that points at no
+/// element: moving past either end lands there, and moving on from it wraps around.
+pub struct CursorMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+    current: Option<NodeRef<T>>,
+    // `list.size` at the ghost position.
+    index: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head.clone(),
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail(),
+            index: self.size.saturating_sub(1),
+            list: self,
+        }
+    }
+}
+
+impl<T> CursorMut<'_, T> {
+    /// @Returns `None` at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.current.as_ref().map(|_| self.index)
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        let node = self.current.as_ref()?;
+
+        // SAFETY: node access invariant on `LinkedList`. The cursor borrows the list
+        // exclusively and the reference borrows the cursor the same way, so nothing
+        // else reads or relinks the node while it lives.
+        Some(unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next_node = match &self.current {
+            None => self.list.head.clone(),
+            Some(node) => node.borrow().next.clone(),
+        }?;
+
+        // SAFETY: same as `current`, for the next node.
+        Some(unsafe { &mut (*next_node.as_ptr()).value })
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let previous_node = match &self.current {
+            None => self.list.tail(),
+            // `head.previous` is the `tail`, but the ghost sits in between.
+            Some(_) if self.index == 0 => None,
+            Some(node) => node.borrow().previous(),
+        }?;
+
+        // SAFETY: same as `current`, for the previous node.
+        Some(unsafe { &mut (*previous_node.as_ptr()).value })
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            None => {
+                self.current = self.list.head.clone();
+                self.index = 0;
+            }
+            Some(node) => {
+                self.current = node.borrow().next.clone();
+                self.index += 1;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current.take() {
+            None => {
+                self.current = self.list.tail();
+                self.index = self.list.size.saturating_sub(1);
+            }
+            Some(_) if self.index == 0 => {
+                self.index = self.list.size;
+            }
+            Some(node) => {
+                self.current = node.borrow().previous();
+                self.index -= 1;
+            }
+        }
+    }
+
+    /// At the ghost position inserts a new `head`.
+    pub fn insert_after(&mut self, value: T) {
+        let next_node = match &self.current {
+            None => self.list.head.clone(),
+            Some(node) => node.borrow().next.clone(),
+        };
+
+        let new_node = Rc::new(RefCell::new(Node::new(value)));
+        self.list.link_before(next_node, new_node);
+
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// At the ghost position inserts a new `tail`.
+    pub fn insert_before(&mut self, value: T) {
+        let new_node = Rc::new(RefCell::new(Node::new(value)));
+        self.list.link_before(self.current.clone(), new_node);
+
+        self.index += 1;
+    }
+
+    /// Cursor moves to the next element (or the ghost position after `tail`).
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        self.current = node.borrow().next.clone();
+        self.list.unlink(&node);
+
+        Some(LinkedList::into_value(node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_vec(linked_list: &LinkedList<u32>) -> Vec<u32> {
+        linked_list.iter().copied().collect()
+    }
+
+    #[test]
+    fn moves() {
+        let mut linked_list = (1..=3).collect::<LinkedList<u32>>();
+        let mut cursor = linked_list.cursor_front_mut();
+
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 2));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.peek_next(), None);
+
+        // Past the `tail` -> ghost -> wraps to `head`.
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 3));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+
+        // Before the `head` -> ghost -> wraps to `tail`.
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        let mut cursor = linked_list.cursor_back_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(1));
+        *cursor.current().unwrap() = 20;
+        assert_eq!(to_vec(&linked_list), [1, 20, 3]);
+    }
+
+    #[test]
+    fn insert() {
+        let mut linked_list = LinkedList::<u32>::new();
+        let mut cursor = linked_list.cursor_front_mut();
+
+        // Empty list: cursor is at the ghost position.
+        cursor.insert_after(2);
+        cursor.insert_before(4);
+        assert_eq!(cursor.index(), None);
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.insert_before(1);
+        cursor.insert_after(3);
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.peek_next(), Some(&mut 3));
+
+        assert_eq!(to_vec(&linked_list), [1, 2, 3, 4]);
+        assert_eq!(linked_list.back(), Some(&4));
+    }
+
+    #[test]
+    fn remove() {
+        let mut linked_list = (1..=5).collect::<LinkedList<u32>>();
+        let mut cursor = linked_list.cursor_front_mut();
+
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(5));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+
+        assert_eq!(to_vec(&linked_list), [2, 4]);
+        assert_eq!(linked_list.back(), Some(&4));
+        assert_eq!(linked_list.size(), 2);
+
+        let mut cursor = linked_list.cursor_back_mut();
+        cursor.remove_current();
+        cursor.move_prev();
+        cursor.remove_current();
+        assert!(linked_list.is_empty());
+        assert!(linked_list.head.is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+/// Weak reference to a node, for structures that find nodes on their own
+/// (e.g. through a hash map) and need O(1) removal. Valid until the node is removed.
+///
+/// Handles must only be passed back to the list that holds the node, so not after
+/// `append`, `split_off` or `splice_at` moved it to another one.
+pub struct NodeHandle<T>(WeakNodeRef<T>);
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> NodeHandle<T> {
+    /// @Returns `false` once the node is removed.
+    pub fn is_attached(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+}
+
+// Crate-only: a handle into another list would let that list's nodes be relinked
+// while it hands out references to them.
+impl<T> LinkedList<T> {
+    pub(crate) fn push_front_with_handle(&mut self, value: T) -> NodeHandle<T> {
+        let new_node = Rc::new(RefCell::new(Node::new(value)));
+        let handle = NodeHandle(Rc::downgrade(&new_node));
+        self.link_before(self.head.clone(), new_node);
+
+        handle
+    }
+
+    /// O(1).
+    ///
+    /// @If the node was already removed -> `Err(Detached)`.
+    pub(crate) fn remove_handle(&mut self, handle: &NodeHandle<T>) -> Result<T, LinkedListError> {
+        let node = handle.0.upgrade().ok_or(LinkedListError::Detached)?;
+        self.unlink(&node);
+
+        Ok(Self::into_value(node))
+    }
+
+    /// Makes the node `head`. O(1).
+    ///
+    /// @If the node was already removed -> `Err(Detached)`.
+    pub(crate) fn move_to_front(&mut self, handle: &NodeHandle<T>) -> Result<(), LinkedListError> {
+        let node = handle.0.upgrade().ok_or(LinkedListError::Detached)?;
+        self.unlink(&node);
+        self.link_before(self.head.clone(), node);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_vec(linked_list: &LinkedList<u32>) -> Vec<u32> {
+        linked_list.iter().copied().collect()
+    }
+
+    #[test]
+    fn remove_and_move() {
+        let mut linked_list = LinkedList::new();
+        let handles = (0..=4)
+            .rev()
+            .map(|value| linked_list.push_front_with_handle(value))
+            .collect::<Vec<_>>();
+        let [four, three, _, one, zero] = &handles[..] else {
+            unreachable!()
+        };
+        assert_eq!(to_vec(&linked_list), [0, 1, 2, 3, 4]);
+
+        linked_list.move_to_front(three).unwrap();
+        assert_eq!(to_vec(&linked_list), [3, 0, 1, 2, 4]);
+        linked_list.move_to_front(four).unwrap();
+        assert_eq!(to_vec(&linked_list), [4, 3, 0, 1, 2]);
+        linked_list.move_to_front(four).unwrap();
+        assert_eq!(to_vec(&linked_list), [4, 3, 0, 1, 2]);
+
+        assert_eq!(linked_list.remove_handle(four).unwrap(), 4);
+        assert_eq!(linked_list.remove_handle(zero).unwrap(), 0);
+        assert_eq!(linked_list.remove_handle(&handles[2]).unwrap(), 2);
+        assert_eq!(to_vec(&linked_list), [3, 1]);
+        assert_eq!(linked_list.back(), Some(&1));
+        assert_eq!(linked_list.size(), 2);
+
+        assert!(!zero.is_attached());
+        assert!(one.is_attached());
+        assert!(matches!(
+            linked_list.remove_handle(zero),
+            Err(LinkedListError::Detached)
+        ));
+        assert!(matches!(
+            linked_list.move_to_front(zero),
+            Err(LinkedListError::Detached)
+        ));
+    }
+
+    #[test]
+    fn single_node() {
+        let mut linked_list = LinkedList::new();
+        let only = linked_list.push_front_with_handle(7);
+
+        linked_list.move_to_front(&only).unwrap();
+        assert_eq!(to_vec(&linked_list), [7]);
+
+        assert_eq!(linked_list.remove_handle(&only).unwrap(), 7);
+        assert!(linked_list.is_empty());
+        assert_eq!(linked_list.front(), None);
+    }
+}
@@ -0,0 +1,280 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use super::*;
+
+type NodePtr<T> = *const RefCell<Node<T>>;
+
+fn head_ptr<T>(linked_list: &LinkedList<T>) -> Option<NodePtr<T>> {
+    linked_list.head.as_ref().map(Rc::as_ptr)
+}
+
+fn tail_ptr<T>(linked_list: &LinkedList<T>) -> Option<NodePtr<T>> {
+    let head = linked_list.head.as_ref()?;
+    let tail = head.borrow().previous.as_ref().map(Weak::as_ptr);
+    tail
+}
+
+// Iterators walk raw node pointers instead of cloning `Rc`s: the list is
+// borrowed for `'a`, so every node stays alive and unchanged meanwhile.
+// `remaining` keeps both ends from crossing each other.
+
+pub struct Iter<'a, T> {
+    front: Option<NodePtr<T>>,
+    back: Option<NodePtr<T>>,
+    remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // SAFETY: node access invariant on `LinkedList`. `front` points at a node of
+        // the list borrowed for `'a`, so it's alive and no `borrow_mut` happens meanwhile.
+        let node = unsafe { &*(*self.front?).as_ptr() };
+        self.front = node.next.as_ref().map(Rc::as_ptr);
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // SAFETY: same as `next`, for `back`.
+        let node = unsafe { &*(*self.back?).as_ptr() };
+        self.back = node.previous.as_ref().map(Weak::as_ptr);
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    front: Option<NodePtr<T>>,
+    back: Option<NodePtr<T>>,
+    remaining: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // SAFETY: node access invariant on `LinkedList`. `front` points at a node of
+        // the list exclusively borrowed for `'a`, and every node is yielded once,
+        // so the `&mut` never aliases.
+        unsafe {
+            let node = (*self.front?).as_ptr();
+            self.front = (*node).next.as_ref().map(Rc::as_ptr);
+            self.remaining -= 1;
+
+            Some(&mut (*node).value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // SAFETY: same as `next`.
+        unsafe {
+            let node = (*self.back?).as_ptr();
+            self.back = (*node).previous.as_ref().map(Weak::as_ptr);
+            self.remaining -= 1;
+
+            Some(&mut (*node).value)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.size, Some(self.0.size))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> LinkedList<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: head_ptr(self),
+            back: tail_ptr(self),
+            remaining: self.size,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            front: head_ptr(self),
+            back: tail_ptr(self),
+            remaining: self.size,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut linked_list = Self::new();
+        linked_list.extend(iter);
+        linked_list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let linked_list = (1..=5).collect::<LinkedList<u32>>();
+
+        assert_eq!(
+            linked_list.iter().copied().collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            linked_list.iter().rev().copied().collect::<Vec<_>>(),
+            [5, 4, 3, 2, 1]
+        );
+        assert_eq!(linked_list.iter().len(), 5);
+        assert_eq!((&linked_list).into_iter().sum::<u32>(), 15);
+
+        assert!(LinkedList::<u32>::new().iter().next().is_none());
+    }
+
+    #[test]
+    fn iter_both_ends() {
+        let linked_list = (1..=5).collect::<LinkedList<u32>>();
+        let mut iter = linked_list.iter();
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut linked_list = (1..=4).collect::<LinkedList<u32>>();
+
+        linked_list.iter_mut().for_each(|v| *v *= 10);
+        if let Some(last) = linked_list.iter_mut().next_back() {
+            *last += 1;
+        }
+        for v in &mut linked_list {
+            *v += 1;
+        }
+
+        assert_eq!(
+            linked_list.iter().copied().collect::<Vec<_>>(),
+            [11, 21, 31, 42]
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let linked_list = ["a", "b", "c"]
+            .into_iter()
+            .map(String::from)
+            .collect::<LinkedList<_>>();
+        let mut iter = linked_list.into_iter();
+
+        assert_eq!(iter.next_back().as_deref(), Some("c"));
+        assert_eq!(iter.collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn extend() {
+        let mut linked_list = LinkedList::new();
+        linked_list.extend([1, 2]);
+        linked_list.extend(vec![3]);
+
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(linked_list.size(), 3);
+    }
+}
@@ -0,0 +1,564 @@
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    rc::{Rc, Weak},
+};
+
+mod cursor;
+mod handle;
+mod iter;
+mod sort;
+mod splice;
+pub use cursor::*;
+pub use handle::*;
+pub use iter::*;
+
+#[derive(Debug)]
+pub enum LinkedListError {
+    OutOfBounds,
+    /// [`NodeHandle`] to a node that was removed.
+    Detached,
+}
+
+impl super::Error for LinkedListError {}
+impl Display for LinkedListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+pub type NodeRef<T> = Rc<RefCell<Node<T>>>;
+pub type WeakNodeRef<T> = Weak<RefCell<Node<T>>>;
+
+// Nodes are owned through `next`; `previous` is only a back-link,
+// so the list never forms a reference cycle.
+#[derive(Clone, Debug)]
+pub struct Node<T> {
+    pub value: T,
+    next: Option<NodeRef<T>>,
+    previous: Option<WeakNodeRef<T>>,
+}
+impl<T> Node<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            next: None,
+            previous: None,
+        }
+    }
+
+    fn previous(&self) -> Option<NodeRef<T>> {
+        self.previous.as_ref()?.upgrade()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+// Circular double linked list: `head.previous` links back to the tail,
+// so both ends are reachable in O(1). Forward links stop at the tail.
+//
+// Node access invariant, what every `RefCell::as_ptr` below relies on: nodes never
+// leave this module (`NodeRef`s aren't handed out, a `NodeHandle` is only a `Weak`
+// that goes back through `&mut self`), and every `RefCell` borrow ends before the
+// method that took it returns. So a reference made from `as_ptr` that lives as long
+// as a borrow of the list can't overlap a `borrow_mut`, and the list can't relink or
+// drop the node meanwhile. `Ref`s can't be returned instead: they'd borrow the local
+// `Rc` the node was reached through.
+pub struct LinkedList<T> {
+    head: Option<NodeRef<T>>,
+    size: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            size: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn tail(&self) -> Option<NodeRef<T>> {
+        self.head.as_ref()?.borrow().previous()
+    }
+
+    fn get_raw(&self, at: usize) -> Option<NodeRef<T>> {
+        if at >= self.size {
+            return None;
+        }
+
+        // Walk from the closer end.
+        match at < self.size / 2 {
+            true => {
+                let mut node = self.head.clone()?;
+                for _ in 0..at {
+                    let next = node.borrow().next.clone()?;
+                    node = next;
+                }
+
+                Some(node)
+            }
+            false => {
+                let mut node = self.tail()?;
+                for _ in at..self.size - 1 {
+                    let previous = node.borrow().previous()?;
+                    node = previous;
+                }
+
+                Some(node)
+            }
+        }
+    }
+
+    /// Links `new_node` right before `next_node`, or at the end if it's `None`. O(1).
+    fn link_before(&mut self, next_node: Option<NodeRef<T>>, new_node: NodeRef<T>) {
+        match (self.head.clone(), next_node) {
+            // LinkedList is empty yet -> `new_node` is both `head` and `tail`.
+            (None, _) => {
+                new_node.borrow_mut().previous = Some(Rc::downgrade(&new_node));
+                self.head = Some(new_node);
+            }
+            // New `tail`: `old_tail`.next = `new_node`, `head`.previous = `new_node`.
+            (Some(head), None) => {
+                let tail = head
+                    .borrow_mut()
+                    .previous
+                    .replace(Rc::downgrade(&new_node))
+                    .and_then(|tail| tail.upgrade())
+                    .expect("Head should link to the tail");
+
+                new_node.borrow_mut().previous = Some(Rc::downgrade(&tail));
+                tail.borrow_mut().next = Some(new_node);
+            }
+            // `previous_node` <-> `new_node` <-> `next_node`.
+            // If `next_node` is `head`, `previous_node` is `tail` and `new_node` becomes `head`.
+            (Some(head), Some(next_node)) => {
+                let previous_node = next_node
+                    .borrow_mut()
+                    .previous
+                    .replace(Rc::downgrade(&new_node))
+                    .and_then(|previous_node| previous_node.upgrade())
+                    .expect("Every node should have `previous`");
+
+                new_node.borrow_mut().previous = Some(Rc::downgrade(&previous_node));
+                new_node.borrow_mut().next = Some(next_node.clone());
+
+                match Rc::ptr_eq(&head, &next_node) {
+                    true => self.head = Some(new_node),
+                    false => previous_node.borrow_mut().next = Some(new_node),
+                }
+            }
+        }
+
+        self.size += 1;
+    }
+
+    /// Unlinks `node`, which must belong to this list. O(1).
+    fn unlink(&mut self, node: &NodeRef<T>) {
+        let previous_node = node.borrow_mut().previous.take();
+        let next_node = node.borrow_mut().next.take();
+        let head = self
+            .head
+            .clone()
+            .expect("Non-empty list should have `head`");
+
+        match (Rc::ptr_eq(&head, node), next_node) {
+            // The only node.
+            (true, None) => {
+                self.head = None;
+            }
+            // `head`: `next_node` becomes `head` and inherits the link to the `tail`.
+            (true, Some(next_node)) => {
+                next_node.borrow_mut().previous = previous_node;
+                self.head = Some(next_node);
+            }
+            // `tail`: `head`.previous = `previous_node`.
+            (false, None) => {
+                let previous_node = previous_node
+                    .and_then(|previous_node| previous_node.upgrade())
+                    .expect("Every node should have `previous`");
+
+                previous_node.borrow_mut().next = None;
+                head.borrow_mut().previous = Some(Rc::downgrade(&previous_node));
+            }
+            // `previous_node` <-> `next_node`.
+            (false, Some(next_node)) => {
+                let previous_node = previous_node
+                    .and_then(|previous_node| previous_node.upgrade())
+                    .expect("Every node should have `previous`");
+
+                next_node.borrow_mut().previous = Some(Rc::downgrade(&previous_node));
+                previous_node.borrow_mut().next = Some(next_node);
+            }
+        }
+
+        self.size -= 1;
+    }
+
+    fn into_value(node: NodeRef<T>) -> T {
+        Rc::try_unwrap(node)
+            .ok()
+            .expect("Unlinked node should be uniquely owned")
+            .into_inner()
+            .value
+    }
+
+    pub fn get(&self, at: usize) -> Option<&T> {
+        let node = self.get_raw(at)?;
+
+        // SAFETY: node access invariant on `LinkedList`. `&self` keeps the node alive
+        // and unchanged for as long as the returned reference.
+        Some(unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn get_mut(&mut self, at: usize) -> Option<&mut T> {
+        let node = self.get_raw(at)?;
+
+        // SAFETY: node access invariant on `LinkedList`. `&mut self` also keeps any
+        // other reference into the list from existing meanwhile.
+        Some(unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.size.checked_sub(1)?)
+    }
+
+    pub fn insert(&mut self, value: T, to: usize) -> Result<(), LinkedListError> {
+        if self.size < to {
+            return Err(LinkedListError::OutOfBounds);
+        }
+
+        let new_node = Rc::new(RefCell::new(Node::new(value)));
+        self.link_before(self.get_raw(to), new_node);
+
+        Ok(())
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.insert(value, self.size)
+            .expect("Error on `push` should not be raised!")
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.insert(value, 0)
+            .expect("Error on `push_front` should not be raised!")
+    }
+
+    pub fn remove(&mut self, at: usize) -> Result<T, LinkedListError> {
+        let node = self.get_raw(at).ok_or(LinkedListError::OutOfBounds)?;
+        self.unlink(&node);
+
+        Ok(Self::into_value(node))
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0).ok()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.size.checked_sub(1)?).ok()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    // Unlink nodes one by one: dropping `head` as is would recurse through
+    // every `next` and overflow the stack on long lists.
+    fn drop(&mut self) {
+        let mut current_node = self.head.take();
+        while let Some(node) = current_node {
+            current_node = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Display for LinkedList<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for value in self {
+            f.write_fmt(format_args!("{} ", value))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl super::Algorithm for LinkedList<u32> {
+    fn showcase() -> Result<(), Box<dyn super::Error>> {
+        let mut linked_list = LinkedList::<u32>::new();
+
+        linked_list.push(1);
+        linked_list.push(2);
+        linked_list.push(3);
+
+        linked_list.insert(727, 1)?;
+        linked_list.insert(1000, 2)?;
+
+        println!("LinkedList at 2: {}", linked_list.get(2).unwrap());
+        println!("LinkedList size: {}", linked_list.size);
+        println!("LinkedList: {linked_list}");
+
+        linked_list.push_front(0);
+        println!("Popped back: {:?}", linked_list.pop_back());
+        println!("Popped front: {:?}", linked_list.pop_front());
+        println!("Removed at 1: {}", linked_list.remove(1)?);
+        println!("LinkedList: {linked_list}");
+
+        let mut cursor = linked_list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_after(52);
+        cursor.remove_current();
+        println!("After cursor edits: {linked_list}");
+
+        linked_list.splice_at(1, [9, 4, 7].into_iter().collect())?;
+        linked_list.sort();
+        println!("Spliced and sorted: {linked_list}");
+        linked_list.reverse();
+        println!("Reversed: {linked_list}");
+
+        let doubled = linked_list.iter().map(|v| v * 2).collect::<LinkedList<_>>();
+        println!(
+            "Doubled, reversed: {:?}",
+            doubled.iter().rev().collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::super::Algorithm;
+    use super::*;
+
+    #[test]
+    fn linked_list() {
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list.push(52);
+        linked_list.push(30);
+
+        assert!(linked_list.insert(120, 1).is_ok());
+        assert!(linked_list.insert(144, 2).is_ok());
+        assert!(linked_list.insert(1, 0).is_ok());
+
+        assert!(linked_list.get_raw(4).is_some());
+        assert!(linked_list.get_raw(0).is_some());
+
+        assert_eq!(linked_list.get_raw(0), linked_list.head);
+        assert_eq!(linked_list.size, 5);
+    }
+
+    #[test]
+    fn common_get() {
+        let mut linked_list = LinkedList::<u32>::new();
+
+        linked_list.push(52);
+        linked_list.push(102);
+
+        assert_eq!(linked_list.get(1), Some(&102));
+        assert_eq!(linked_list.get(0), Some(&52));
+
+        *linked_list.get_mut(0).unwrap() += 1;
+        assert_eq!(linked_list.front(), Some(&53));
+        assert_eq!(linked_list.back(), Some(&102));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut linked_list = LinkedList::<u32>::new();
+
+        assert!(linked_list.insert(10, 1).is_err());
+        assert!(linked_list.head.is_none());
+        assert!(linked_list.get_raw(0).is_none());
+        assert_eq!(linked_list.size, 0);
+    }
+
+    #[test]
+    fn showcase() {
+        LinkedList::showcase().unwrap();
+    }
+
+    fn to_vec(linked_list: &LinkedList<u32>) -> Vec<u32> {
+        linked_list.iter().copied().collect()
+    }
+
+    #[test]
+    fn circular() {
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list.push(1);
+        assert_eq!(linked_list.tail(), linked_list.head);
+
+        linked_list.push(2);
+        linked_list.push_front(0);
+
+        let tail = linked_list.tail().unwrap();
+        assert_eq!(tail.borrow().value, 2);
+        assert!(tail.borrow().next.is_none());
+        assert_eq!(linked_list.get_raw(2), Some(tail));
+    }
+
+    #[test]
+    fn push_front() {
+        let mut linked_list = LinkedList::<u32>::new();
+        linked_list.push_front(3);
+        linked_list.push_front(2);
+        linked_list.push(4);
+        linked_list.push_front(1);
+
+        assert_eq!(to_vec(&linked_list), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn remove() {
+        let mut linked_list = LinkedList::<u32>::new();
+        (0..6).for_each(|v| linked_list.push(v));
+
+        assert_eq!(linked_list.remove(2).unwrap(), 2);
+        assert_eq!(linked_list.remove(0).unwrap(), 0);
+        assert_eq!(linked_list.remove(3).unwrap(), 5);
+        assert_eq!(to_vec(&linked_list), [1, 3, 4]);
+        assert_eq!(linked_list.tail().unwrap().borrow().value, 4);
+
+        assert!(matches!(
+            linked_list.remove(3),
+            Err(LinkedListError::OutOfBounds)
+        ));
+        assert_eq!(linked_list.size(), 3);
+    }
+
+    #[test]
+    fn pop() {
+        let mut linked_list = LinkedList::<u32>::new();
+        assert!(linked_list.pop_front().is_none());
+        assert!(linked_list.pop_back().is_none());
+
+        (1..=4).for_each(|v| linked_list.push(v));
+
+        assert_eq!(linked_list.pop_back(), Some(4));
+        assert_eq!(linked_list.pop_front(), Some(1));
+        assert_eq!(linked_list.pop_back(), Some(3));
+        assert_eq!(linked_list.pop_back(), Some(2));
+        assert_eq!(linked_list.pop_back(), None);
+        assert!(linked_list.head.is_none());
+        assert!(linked_list.is_empty());
+
+        linked_list.push(7);
+        assert_eq!(to_vec(&linked_list), [7]);
+    }
+
+    #[test]
+    fn clear() {
+        let mut linked_list = LinkedList::<u32>::new();
+        (0..10).for_each(|v| linked_list.push(v));
+
+        linked_list.clear();
+        assert!(linked_list.head.is_none());
+        assert_eq!(linked_list.size(), 0);
+        assert!(linked_list.get(0).is_none());
+    }
+
+    /// Value that keeps `live` equal to the number of its instances alive.
+    /// Every node owns exactly one, so `live` counts nodes as well.
+    struct Tracked {
+        live: Rc<Cell<usize>>,
+    }
+
+    impl Tracked {
+        fn new(live: &Rc<Cell<usize>>) -> Self {
+            live.set(live.get() + 1);
+            Self { live: live.clone() }
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.live.set(self.live.get() - 1);
+        }
+    }
+
+    fn node_probes<T>(linked_list: &LinkedList<T>) -> Vec<WeakNodeRef<T>> {
+        (0..linked_list.size())
+            .map(|i| Rc::downgrade(&linked_list.get_raw(i).unwrap()))
+            .collect()
+    }
+
+    fn live_nodes<T>(probes: &[WeakNodeRef<T>]) -> usize {
+        probes.iter().filter(|node| node.strong_count() > 0).count()
+    }
+
+    #[test]
+    fn no_reference_cycles() {
+        for size in 1..=4 {
+            let mut linked_list = LinkedList::<u32>::new();
+            (0..size).for_each(|v| linked_list.push(v));
+
+            let probes = node_probes(&linked_list);
+            assert_eq!(live_nodes(&probes), size as usize);
+
+            drop(linked_list);
+            assert_eq!(live_nodes(&probes), 0);
+        }
+    }
+
+    #[test]
+    fn removed_nodes_are_freed() {
+        let mut linked_list = LinkedList::<u32>::new();
+        (0..5).for_each(|v| linked_list.push(v));
+        let probes = node_probes(&linked_list);
+
+        linked_list.remove(2).unwrap();
+        linked_list.pop_front();
+        linked_list.pop_back();
+        assert_eq!(live_nodes(&probes), 2);
+
+        linked_list.clear();
+        assert_eq!(live_nodes(&probes), 0);
+    }
+
+    #[test]
+    fn million_nodes_drop() {
+        let live = Rc::new(Cell::new(0));
+
+        let mut linked_list = LinkedList::new();
+        for _ in 0..1_000_000 {
+            linked_list.push(Tracked::new(&live));
+        }
+        linked_list.push_front(Tracked::new(&live));
+        linked_list.insert(Tracked::new(&live), 500_000).unwrap();
+        assert_eq!(live.get(), 1_000_002);
+
+        drop(linked_list.pop_back());
+        assert_eq!(live.get(), 1_000_001);
+
+        drop(linked_list);
+        assert_eq!(live.get(), 0);
+    }
+}
@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+impl<T> LinkedList<T> {
+    /// Reverses the list in place by swapping links, values are not moved. O(n).
+    pub fn reverse(&mut self) {
+        let Some(old_head) = self.head.take() else {
+            return;
+        };
+
+        // Every node now owns the one that used to precede it.
+        let mut new_next = None;
+        let mut current_node = Some(old_head.clone());
+        while let Some(node) = current_node {
+            let old_next = node.borrow_mut().next.take();
+            node.borrow_mut().next = new_next;
+            node.borrow_mut().previous = old_next.as_ref().map(Rc::downgrade);
+
+            new_next = Some(node);
+            current_node = old_next;
+        }
+
+        // The old `tail` is the new `head` and links back to the old `head`.
+        let new_head = new_next.expect("Non-empty list should have `head`");
+        new_head.borrow_mut().previous = Some(Rc::downgrade(&old_head));
+        self.head = Some(new_head);
+    }
+
+    /// Stable merge sort that relinks nodes instead of moving values.
+    /// O(n log n) comparisons, O(log n) stack. If `compare` panics, the list is left
+    /// whole, in its original order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let Some(head) = self.head.take() else {
+            return;
+        };
+        let guard = Relink::new(&mut self.head, head.clone());
+
+        // Sort as a singly linked chain, then restore the back-links.
+        let head = merge_sort(head, self.size, &mut compare);
+        guard.defuse();
+
+        let mut previous_node = head.clone();
+        let mut current_node = head.borrow().next.clone();
+        while let Some(node) = current_node {
+            node.borrow_mut().previous = Some(Rc::downgrade(&previous_node));
+            current_node = node.borrow().next.clone();
+            previous_node = node;
+        }
+        head.borrow_mut().previous = Some(Rc::downgrade(&previous_node));
+
+        self.head = Some(head);
+    }
+
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+}
+
+/// Keeps every node alive while the chain is cut apart for sorting. Dropped during a
+/// panic, it links them back in their original order and puts the list's `head` back.
+struct Relink<'a, T> {
+    head: &'a mut Option<NodeRef<T>>,
+    nodes: Vec<NodeRef<T>>,
+}
+
+impl<'a, T> Relink<'a, T> {
+    fn new(head: &'a mut Option<NodeRef<T>>, first: NodeRef<T>) -> Self {
+        let mut nodes = vec![];
+        let mut current_node = Some(first);
+        while let Some(node) = current_node {
+            current_node = node.borrow().next.clone();
+            nodes.push(node);
+        }
+        Self { head, nodes }
+    }
+
+    /// The sort finished, the nodes are linked the way they should be.
+    fn defuse(mut self) {
+        self.nodes.clear();
+    }
+}
+
+impl<T> Drop for Relink<'_, T> {
+    fn drop(&mut self) {
+        let (Some(first), Some(last)) = (self.nodes.first(), self.nodes.last()) else {
+            return;
+        };
+
+        for pair in self.nodes.windows(2) {
+            pair[0].borrow_mut().next = Some(pair[1].clone());
+            pair[1].borrow_mut().previous = Some(Rc::downgrade(&pair[0]));
+        }
+        last.borrow_mut().next = None;
+        first.borrow_mut().previous = Some(Rc::downgrade(last));
+        *self.head = Some(first.clone());
+    }
+}
+
+/// Sorts the chain of `size` nodes starting at `head`, the last `next` is `None`.
+fn merge_sort<T, F>(head: NodeRef<T>, size: usize, compare: &mut F) -> NodeRef<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if size <= 1 {
+        return head;
+    }
+
+    let left_size = size / 2;
+    let mut left_tail = head.clone();
+    for _ in 1..left_size {
+        let next = left_tail
+            .borrow()
+            .next
+            .clone()
+            .expect("Chain is shorter than `size`");
+        left_tail = next;
+    }
+    let right = left_tail
+        .borrow_mut()
+        .next
+        .take()
+        .expect("Chain is shorter than `size`");
+
+    let left = merge_sort(head, left_size, compare);
+    let right = merge_sort(right, size - left_size, compare);
+
+    merge(left, right, compare)
+}
+
+/// Merges two sorted chains; on ties the node from `left` goes first.
+fn merge<T, F>(left: NodeRef<T>, right: NodeRef<T>, compare: &mut F) -> NodeRef<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (mut left, mut right) = (Some(left), Some(right));
+    let mut head: Option<NodeRef<T>> = None;
+    let mut tail: Option<NodeRef<T>> = None;
+
+    loop {
+        let source = match (&left, &right) {
+            (Some(l), Some(r)) => {
+                let ordering = compare(&r.borrow().value, &l.borrow().value);
+                match ordering {
+                    Ordering::Less => &mut right,
+                    _ => &mut left,
+                }
+            }
+            // One chain is exhausted: the rest of the other one is already in order.
+            (rest, None) | (None, rest) => {
+                let rest = rest.clone();
+                match &tail {
+                    Some(tail) => tail.borrow_mut().next = rest,
+                    None => head = rest,
+                }
+                break;
+            }
+        };
+
+        let node = source.take().expect("Source chain is not empty");
+        *source = node.borrow_mut().next.take();
+
+        match &tail {
+            Some(tail) => tail.borrow_mut().next = Some(node.clone()),
+            None => head = Some(node.clone()),
+        }
+        tail = Some(node);
+    }
+
+    head.expect("Merged chain is not empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::SeededRandom;
+
+    use super::*;
+
+    fn assert_same(linked_list: &LinkedList<u32>, v: &[u32]) {
+        assert_eq!(linked_list.iter().copied().collect::<Vec<_>>(), v);
+        assert!(linked_list.iter().rev().eq(v.iter().rev()));
+        assert_eq!(linked_list.size(), v.len());
+    }
+
+    #[test]
+    fn reverse() {
+        for size in 0..=5 {
+            let mut linked_list = (0..size).collect::<LinkedList<u32>>();
+            let mut v = (0..size).collect::<Vec<u32>>();
+
+            linked_list.reverse();
+            v.reverse();
+            assert_same(&linked_list, &v);
+
+            linked_list.push(10);
+            linked_list.push_front(20);
+            v.push(10);
+            v.insert(0, 20);
+            assert_same(&linked_list, &v);
+        }
+    }
+
+    #[test]
+    fn sort() {
+        let mut random = SeededRandom::new(34);
+
+        for size in [0, 1, 2, 3, 7, 64, 1000] {
+            let mut v = (0..size)
+                .map(|_| random.get_in_range(0, 50))
+                .collect::<Vec<u32>>();
+            let mut linked_list = v.iter().copied().collect::<LinkedList<_>>();
+
+            linked_list.sort();
+            v.sort();
+            assert_same(&linked_list, &v);
+
+            linked_list.sort_by(|a, b| b.cmp(a));
+            v.sort_by(|a, b| b.cmp(a));
+            assert_same(&linked_list, &v);
+        }
+    }
+
+    #[test]
+    fn panicking_compare_keeps_list() {
+        let v = (0..100).rev().collect::<Vec<u32>>();
+        let mut linked_list = v.iter().copied().collect::<LinkedList<_>>();
+
+        let mut comparisons = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            linked_list.sort_by(|a, b| {
+                comparisons += 1;
+                assert!(comparisons < 150, "Comparator gave up");
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_same(&linked_list, &v);
+
+        linked_list.sort();
+        assert_same(&linked_list, &(0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sort_is_stable() {
+        let mut random = SeededRandom::new(43);
+
+        // (key, original position)
+        let mut v = (0..500)
+            .map(|i| (random.get_in_range(0, 10), i))
+            .collect::<Vec<(u32, u32)>>();
+        let mut linked_list = v.iter().copied().collect::<LinkedList<_>>();
+
+        linked_list.sort_by_key(|&(key, _)| key);
+        v.sort_by_key(|&(key, _)| key);
+
+        assert!(linked_list.iter().eq(v.iter()));
+    }
+
+    #[test]
+    fn sort_relinks_nodes() {
+        let mut linked_list = [3, 1, 2].into_iter().collect::<LinkedList<u32>>();
+        let address_of = |linked_list: &LinkedList<u32>, value: u32| {
+            linked_list
+                .iter()
+                .find(|&&v| v == value)
+                .map(|v| v as *const u32)
+        };
+
+        let before = (1..=3)
+            .map(|v| address_of(&linked_list, v))
+            .collect::<Vec<_>>();
+        linked_list.sort();
+        linked_list.reverse();
+        let after = (1..=3)
+            .map(|v| address_of(&linked_list, v))
+            .collect::<Vec<_>>();
+
+        assert_eq!(before, after);
+        assert_same(&linked_list, &[3, 2, 1]);
+    }
+}
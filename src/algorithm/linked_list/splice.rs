@@ -0,0 +1,181 @@
+use super::*;
+
+impl<T> LinkedList<T> {
+    /// Moves every node of `other` to the end of `self`. O(1).
+    ///
+    /// @Mutate `other` is left empty.
+    pub fn append(&mut self, other: &mut Self) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+        let other_size = std::mem::take(&mut other.size);
+
+        let Some(head) = self.head.clone() else {
+            self.head = Some(other_head);
+            self.size = other_size;
+            return;
+        };
+
+        // `tail` <-> `other_head`, `head`.previous = `other_tail`.
+        let other_tail = other_head
+            .borrow_mut()
+            .previous
+            .take()
+            .expect("Head should link to the tail");
+        let tail = head
+            .borrow_mut()
+            .previous
+            .replace(other_tail)
+            .and_then(|tail| tail.upgrade())
+            .expect("Head should link to the tail");
+
+        other_head.borrow_mut().previous = Some(Rc::downgrade(&tail));
+        tail.borrow_mut().next = Some(other_head);
+
+        self.size += other_size;
+    }
+
+    /// Splits the list in two: `self` keeps `[0, at)`, the rest is returned.
+    ///
+    /// @If `at` > `size` -> `Err(OutOfBounds)`.
+    pub fn split_off(&mut self, at: usize) -> Result<Self, LinkedListError> {
+        if self.size < at {
+            return Err(LinkedListError::OutOfBounds);
+        }
+        if at == 0 {
+            return Ok(std::mem::take(self));
+        }
+
+        let Some(new_head) = self.get_raw(at) else {
+            return Ok(Self::new());
+        };
+        let head = self
+            .head
+            .clone()
+            .expect("Non-empty list should have `head`");
+
+        // `new_tail` <-/-> `new_head`: each half becomes circular on its own.
+        let new_tail = new_head
+            .borrow()
+            .previous()
+            .expect("Every node should have `previous`");
+        let tail = head
+            .borrow_mut()
+            .previous
+            .replace(Rc::downgrade(&new_tail))
+            .expect("Head should link to the tail");
+
+        new_tail.borrow_mut().next = None;
+        new_head.borrow_mut().previous = Some(tail);
+
+        let other = Self {
+            head: Some(new_head),
+            size: self.size - at,
+        };
+        self.size = at;
+
+        Ok(other)
+    }
+
+    /// Inserts every node of `other` before position `at`.
+    /// Finding `at` is O(min(at, size - at)), relinking itself is O(1).
+    ///
+    /// @If `at` > `size` -> `Err(OutOfBounds)`.
+    pub fn splice_at(&mut self, at: usize, mut other: Self) -> Result<(), LinkedListError> {
+        let mut rest = self.split_off(at)?;
+        self.append(&mut other);
+        self.append(&mut rest);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_vec(linked_list: &LinkedList<u32>) -> Vec<u32> {
+        linked_list.iter().copied().collect()
+    }
+
+    /// Walks the list backwards, so broken `previous` links show up.
+    fn to_vec_rev(linked_list: &LinkedList<u32>) -> Vec<u32> {
+        let mut v = linked_list.iter().rev().copied().collect::<Vec<_>>();
+        v.reverse();
+        v
+    }
+
+    fn assert_same(linked_list: &LinkedList<u32>, v: &[u32]) {
+        assert_eq!(to_vec(linked_list), v);
+        assert_eq!(to_vec_rev(linked_list), v);
+        assert_eq!(linked_list.size(), v.len());
+        assert_eq!(linked_list.back(), v.last());
+    }
+
+    #[test]
+    fn append() {
+        for (a, b) in [(0, 0), (0, 3), (3, 0), (1, 1), (4, 3)] {
+            let mut linked_list = (0..a).collect::<LinkedList<u32>>();
+            let mut other = (10..10 + b).collect::<LinkedList<u32>>();
+            let mut v = (0..a).collect::<Vec<u32>>();
+            let mut other_v = (10..10 + b).collect::<Vec<u32>>();
+
+            linked_list.append(&mut other);
+            v.append(&mut other_v);
+
+            assert_same(&linked_list, &v);
+            assert_same(&other, &other_v);
+
+            // Both lists stay usable after the move.
+            other.push(99);
+            linked_list.push(100);
+            v.push(100);
+            assert_same(&linked_list, &v);
+            assert_same(&other, &[99]);
+        }
+    }
+
+    #[test]
+    fn split_off() {
+        for size in 0..=4 {
+            for at in 0..=size {
+                let mut linked_list = (0..size).collect::<LinkedList<u32>>();
+                let mut v = (0..size).collect::<Vec<u32>>();
+
+                let other = linked_list.split_off(at as usize).unwrap();
+                let other_v = v.split_off(at as usize);
+
+                assert_same(&linked_list, &v);
+                assert_same(&other, &other_v);
+            }
+        }
+
+        let mut linked_list = (0..3).collect::<LinkedList<u32>>();
+        assert!(matches!(
+            linked_list.split_off(4),
+            Err(LinkedListError::OutOfBounds)
+        ));
+        assert_same(&linked_list, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn splice_at() {
+        for at in 0..=4 {
+            let mut linked_list = (0..4).collect::<LinkedList<u32>>();
+            let mut v = (0..4).collect::<Vec<u32>>();
+
+            linked_list
+                .splice_at(at, [10, 11].into_iter().collect())
+                .unwrap();
+            v.splice(at..at, [10, 11]);
+
+            assert_same(&linked_list, &v);
+        }
+
+        let mut linked_list = LinkedList::new();
+        linked_list.splice_at(0, LinkedList::new()).unwrap();
+        linked_list.splice_at(0, (1..3).collect()).unwrap();
+        assert_same(&linked_list, &[1, 2]);
+        assert!(linked_list.splice_at(3, LinkedList::new()).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+//! Queues synchronized by atomics alone, no locks.
+//!
+//! - [`spsc`]: bounded ring buffer, one producer and one consumer.
+//! - [`mpsc`]: unbounded linked queue, any number of producers and one consumer.
+
+pub mod mpsc;
+pub mod spsc;
+
+/// Keeps atomics touched by different threads on separate cache lines,
+/// so the producer and the consumer don't invalidate each other's cache.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+impl<T> std::ops::Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
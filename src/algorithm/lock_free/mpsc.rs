@@ -0,0 +1,195 @@
+use std::{
+    cell::UnsafeCell,
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Arc,
+    },
+};
+
+use super::CachePadded;
+
+struct Node<T> {
+    value: Option<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(value: Option<T>) -> *mut Self {
+        Box::into_raw(Box::new(Self {
+            value,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+}
+
+// Vyukov's intrusive MPSC queue with a stub node: `head` is always a node whose
+// value is already taken, and the values are in `head.next ..= tail`.
+//
+// Producers link in with a single `swap` on `tail` and then set the old tail's
+// `next`, no CAS loop, so `send` is wait-free. The consumer can't free that old tail
+// before its `next` is set, so nodes are reclaimed without hazard pointers. The price
+// is that the consumer isn't lock-free: between a producer's `swap` and its store the
+// chain is cut, so the queue briefly looks empty, even to values pushed after it.
+struct Inner<T> {
+    head: CachePadded<UnsafeCell<*mut Node<T>>>,
+    tail: CachePadded<AtomicPtr<Node<T>>>,
+}
+
+// SAFETY: `head` is only touched by the single `Receiver`, `tail` is atomic.
+unsafe impl<T: Send> Send for Inner<T> {}
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        let mut node = *self.head.0.get_mut();
+        while !node.is_null() {
+            // SAFETY: every node is owned by the queue and freed exactly once here.
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+pub struct Sender<T> {
+    inner: Arc<Inner<T>>,
+}
+
+// Derived `Clone` would require `T: Clone`.
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    inner: Arc<Inner<T>>,
+}
+
+/// Unbounded multi-producer / single-consumer queue. `send` is wait-free, `try_recv` can
+/// briefly see the queue as empty while a `send` is in progress.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let stub = Node::new(None);
+    let inner = Arc::new(Inner {
+        head: CachePadded(UnsafeCell::new(stub)),
+        tail: CachePadded(AtomicPtr::new(stub)),
+    });
+
+    (
+        Sender {
+            inner: inner.clone(),
+        },
+        Receiver { inner },
+    )
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) {
+        let node = Node::new(Some(value));
+        let previous = self.inner.tail.swap(node, Ordering::AcqRel);
+
+        // SAFETY: `previous` is still reachable from `head` (its `next` is null),
+        // so the consumer can't have freed it.
+        unsafe { (*previous).next.store(node, Ordering::Release) };
+    }
+}
+
+impl<T> Receiver<T> {
+    /// @Returns `None` if the queue is empty, or a producer is halfway through
+    /// linking the next node (it shows up on one of the following calls).
+    pub fn try_recv(&mut self) -> Option<T> {
+        // SAFETY: `&mut self` on the only `Receiver` makes this the only access to `head`.
+        let head = unsafe { &mut *self.inner.head.get() };
+        let next = unsafe { (**head).next.load(Ordering::Acquire) };
+
+        if next.is_null() {
+            return None;
+        }
+
+        // `next` becomes the stub: its value is moved out, the old stub is freed.
+        // SAFETY: `next` was fully initialized before it was published.
+        let value = unsafe { (*next).value.take() };
+        let stub = std::mem::replace(head, next);
+        drop(unsafe { Box::from_raw(stub) });
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn single_thread() {
+        let (sender, mut receiver) = channel();
+
+        assert_eq!(receiver.try_recv(), None);
+        (0..5).for_each(|v| sender.send(v));
+        sender.clone().send(5);
+
+        assert_eq!(
+            std::iter::from_fn(|| receiver.try_recv()).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(receiver.try_recv(), None);
+
+        sender.send(6);
+        assert_eq!(receiver.try_recv(), Some(6));
+    }
+
+    #[test]
+    fn stress() {
+        const PRODUCERS: usize = 8;
+        const PER_PRODUCER: usize = 100_000;
+
+        let (sender, mut receiver) = channel();
+        let producers = (0..PRODUCERS)
+            .map(|id| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    (0..PER_PRODUCER).for_each(|v| sender.send((id, v)));
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut next = [0; PRODUCERS];
+        let mut received = 0;
+        while received < PRODUCERS * PER_PRODUCER {
+            match receiver.try_recv() {
+                // FIFO per producer.
+                Some((id, v)) => {
+                    assert_eq!(v, next[id]);
+                    next[id] += 1;
+                    received += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+
+        producers.into_iter().for_each(|p| p.join().unwrap());
+        assert_eq!(receiver.try_recv(), None);
+        assert!(next.iter().all(|&n| n == PER_PRODUCER));
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let counter = Arc::new(());
+        let (sender, mut receiver) = channel();
+
+        for _ in 0..5 {
+            sender.send(counter.clone());
+        }
+        receiver.try_recv();
+        assert_eq!(Arc::strong_count(&counter), 1 + 4);
+
+        drop(receiver);
+        assert_eq!(Arc::strong_count(&counter), 1 + 4);
+        drop(sender);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+}
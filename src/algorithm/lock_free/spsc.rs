@@ -0,0 +1,251 @@
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use super::CachePadded;
+
+// `head` and `tail` only ever grow (wrapping), a slot is `index & (slots.len() - 1)`.
+// There's a power of two of slots so that stays right when the indices wrap, `capacity`
+// is what was asked for and bounds the queue.
+// `tail - head` is the number of elements, so full and empty never look alike.
+// Each index has a single writer: the producer owns `tail`, the consumer owns `head`.
+struct Buffer<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    capacity: usize,
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+}
+
+// SAFETY: a slot is accessed either by the producer (before `tail` is published)
+// or by the consumer (before `head` is published), never by both at once.
+unsafe impl<T: Send> Sync for Buffer<T> {}
+
+impl<T> Buffer<T> {
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn slot(&self, index: usize) -> *mut MaybeUninit<T> {
+        self.slots[index & (self.slots.len() - 1)].get()
+    }
+
+    fn len(&self) -> usize {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+}
+
+impl<T> Drop for Buffer<T> {
+    fn drop(&mut self) {
+        let (head, tail) = (*self.head.0.get_mut(), *self.tail.0.get_mut());
+
+        let mut index = head;
+        while index != tail {
+            // SAFETY: slots in `head..tail` are initialized, and both halves are gone.
+            unsafe { (*self.slot(index)).assume_init_drop() };
+            index = index.wrapping_add(1);
+        }
+    }
+}
+
+pub struct Producer<T> {
+    buffer: Arc<Buffer<T>>,
+}
+
+pub struct Consumer<T> {
+    buffer: Arc<Buffer<T>>,
+}
+
+/// Bounded single-producer / single-consumer queue.
+///
+/// @If `capacity` == 0 -> panics.
+pub fn channel<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(capacity > 0, "SPSC capacity should be positive");
+
+    let buffer = Arc::new(Buffer {
+        slots: (0..capacity.next_power_of_two())
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect(),
+        capacity,
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+    });
+
+    (
+        Producer {
+            buffer: buffer.clone(),
+        },
+        Consumer { buffer },
+    )
+}
+
+impl<T> Producer<T> {
+    /// @Returns `Err(value)` if the queue is full.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let tail = self.buffer.tail.load(Ordering::Relaxed);
+        let head = self.buffer.head.load(Ordering::Acquire);
+
+        if tail.wrapping_sub(head) == self.buffer.capacity() {
+            return Err(value);
+        }
+
+        // SAFETY: the slot at `tail` is free (the consumer released it via `head`)
+        // and stays invisible to the consumer until `tail` is published.
+        unsafe { (*self.buffer.slot(tail)).write(value) };
+        self.buffer
+            .tail
+            .store(tail.wrapping_add(1), Ordering::Release);
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+impl<T> Consumer<T> {
+    pub fn pop(&mut self) -> Option<T> {
+        let head = self.buffer.head.load(Ordering::Relaxed);
+        let tail = self.buffer.tail.load(Ordering::Acquire);
+
+        if head == tail {
+            return None;
+        }
+
+        // SAFETY: the slot at `head` was initialized before `tail` was published,
+        // and the producer won't reuse it until `head` moves past it.
+        let value = unsafe { (*self.buffer.slot(head)).assume_init_read() };
+        self.buffer
+            .head
+            .store(head.wrapping_add(1), Ordering::Release);
+
+        Some(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn single_thread() {
+        let (mut producer, mut consumer) = channel(2);
+
+        assert_eq!(consumer.pop(), None);
+        assert_eq!(producer.push(1), Ok(()));
+        assert_eq!(producer.push(2), Ok(()));
+        assert_eq!(producer.push(3), Err(3));
+        assert_eq!(consumer.len(), 2);
+
+        // Indices wrap around the buffer many times.
+        for v in 3..100 {
+            assert_eq!(consumer.pop(), Some(v - 2));
+            assert_eq!(producer.push(v), Ok(()));
+        }
+        assert_eq!(consumer.pop(), Some(98));
+        assert_eq!(consumer.pop(), Some(99));
+        assert!(producer.is_empty());
+    }
+
+    #[test]
+    fn indices_wrap_around_usize() {
+        // Not a power of two, so `index % capacity` would jump slots when `usize` wraps.
+        let (mut producer, mut consumer) = channel(3);
+        let start = usize::MAX - 4;
+        producer.buffer.head.store(start, Ordering::Relaxed);
+        producer.buffer.tail.store(start, Ordering::Relaxed);
+
+        for v in 0..3 {
+            assert_eq!(producer.push(v), Ok(()));
+        }
+        assert_eq!(producer.push(3), Err(3));
+        for v in 3..20 {
+            assert_eq!(consumer.pop(), Some(v - 3));
+            assert_eq!(producer.push(v), Ok(()));
+        }
+        assert_eq!(
+            std::iter::from_fn(|| consumer.pop()).collect::<Vec<_>>(),
+            [17, 18, 19]
+        );
+    }
+
+    #[test]
+    fn stress() {
+        const COUNT: u64 = 1_000_000;
+        let (mut producer, mut consumer) = channel(64);
+
+        let producer = thread::spawn(move || {
+            for v in 0..COUNT {
+                let mut value = v;
+                while let Err(back) = producer.push(value) {
+                    value = back;
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut expected = 0;
+        while expected < COUNT {
+            match consumer.pop() {
+                Some(v) => {
+                    assert_eq!(v, expected);
+                    expected += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+
+        producer.join().unwrap();
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let counter = Arc::new(());
+        let (mut producer, mut consumer) = channel(4);
+
+        for _ in 0..3 {
+            producer.push(counter.clone()).unwrap();
+        }
+        consumer.pop();
+        for _ in 0..2 {
+            producer.push(counter.clone()).unwrap();
+        }
+        assert_eq!(Arc::strong_count(&counter), 1 + 4);
+
+        // Whichever half goes last frees the buffer.
+        drop(producer);
+        assert_eq!(Arc::strong_count(&counter), 1 + 4);
+        drop(consumer);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+}
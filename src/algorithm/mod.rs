@@ -1,9 +1,22 @@
 pub use std::error::Error;
 
+pub mod b_tree;
+pub mod blocking_queue;
+pub mod cache;
+pub mod disjoint_set;
+pub mod graph;
+pub mod hash_map;
+pub mod heap;
 pub mod linked_list;
+pub mod lock_free;
+pub mod prefix_tree;
 pub mod queue;
 pub mod queue2;
+pub mod registry;
+pub mod skip_list;
+pub mod sort;
 pub mod stack;
+pub mod tree;
 
 // Should be implemented for <u32>.
 pub trait Algorithm {
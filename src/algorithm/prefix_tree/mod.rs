@@ -0,0 +1,21 @@
+mod radix;
+mod trie;
+pub use radix::*;
+pub use trie::*;
+
+/// Used by the showcases.
+const WORDS: &[&str] = &[
+    "tea", "team", "teal", "ten", "tent", "test", "toast", "to", "in", "inn", "interval",
+];
+
+/// First `limit` entries, shortest key first, ties in iteration (lexicographic) order.
+fn by_length<'a, V>(
+    entries: impl Iterator<Item = (String, &'a V)>,
+    limit: usize,
+) -> Vec<(String, &'a V)> {
+    let mut entries = entries.collect::<Vec<_>>();
+    // Stable, keeps lexicographic order among equal lengths.
+    entries.sort_by_key(|(key, _)| key.chars().count());
+    entries.truncate(limit);
+    entries
+}
@@ -0,0 +1,412 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    mem,
+};
+
+use super::by_length;
+
+struct Node<V> {
+    // Edge label from the parent, empty only for the root.
+    label: String,
+    value: Option<V>,
+    // Keyed by the first char of the child's label.
+    children: BTreeMap<char, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new(label: &str, value: Option<V>) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            children: BTreeMap::new(),
+        }
+    }
+
+    fn count(&self) -> usize {
+        1 + self.children.values().map(Node::count).sum::<usize>()
+    }
+
+    /// Child whose label starts like `key`.
+    fn child(&self, key: &str) -> Option<&Self> {
+        self.children.get(&key.chars().next()?)
+    }
+}
+
+/// Length in bytes of the longest common prefix, always on a char boundary.
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((i, _), _)| i)
+}
+
+/// String-keyed map that compresses chains of single-child nodes into one labeled edge,
+/// so there are at most `2 * len()` nodes.
+pub struct RadixTree<V> {
+    root: Node<V>,
+    size: usize,
+}
+
+impl<V> RadixTree<V> {
+    pub fn new() -> Self {
+        Self {
+            root: Node::new("", None),
+            size: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Root included, compare with [`super::Trie::node_count`].
+    pub fn node_count(&self) -> usize {
+        self.root.count()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let (mut node, mut rest) = (&self.root, key);
+        while !rest.is_empty() {
+            node = node.child(rest)?;
+            rest = rest.strip_prefix(node.label.as_str())?;
+        }
+
+        node.value.as_ref()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// @Returns previous value of `key`.
+    /// @Mutate splits an edge when `key` branches off in the middle of it.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        fn insert<V>(node: &mut Node<V>, key: &str, value: V) -> Option<V> {
+            let Some(first) = key.chars().next() else {
+                return node.value.replace(value);
+            };
+
+            let Some(child) = node.children.get_mut(&first) else {
+                node.children.insert(first, Node::new(key, Some(value)));
+                return None;
+            };
+
+            let common = common_prefix(&child.label, key);
+            if common < child.label.len() {
+                let rest = child.label.split_off(common);
+                let tail = Node {
+                    value: child.value.take(),
+                    children: mem::take(&mut child.children),
+                    label: rest,
+                };
+                let first = tail.label.chars().next().expect("Split inside the label");
+                child.children.insert(first, tail);
+            }
+
+            insert(child, &key[common..], value)
+        }
+
+        let old = insert(&mut self.root, key, value);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    /// @Mutate drops the emptied node, or merges it into its only child.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        fn remove<V>(node: &mut Node<V>, key: &str) -> Option<V> {
+            let Some(first) = key.chars().next() else {
+                return node.value.take();
+            };
+
+            let child = node.children.get_mut(&first)?;
+            let rest = key.strip_prefix(child.label.as_str())?;
+            let removed = remove(child, rest)?;
+
+            if child.value.is_none() {
+                match child.children.len() {
+                    0 => {
+                        node.children.remove(&first);
+                    }
+                    1 => {
+                        let (_, only) = child.children.pop_first().expect("Has one child");
+                        child.label.push_str(&only.label);
+                        child.value = only.value;
+                        child.children = only.children;
+                    }
+                    _ => {}
+                }
+            }
+
+            Some(removed)
+        }
+
+        let removed = remove(&mut self.root, key)?;
+        self.size -= 1;
+        Some(removed)
+    }
+
+    /// Lexicographic.
+    pub fn iter(&self) -> RadixIter<'_, V> {
+        self.iter_prefix("")
+    }
+
+    /// Entries whose key starts with `prefix`, lexicographic.
+    pub fn iter_prefix(&self, prefix: &str) -> RadixIter<'_, V> {
+        let (mut node, mut key, mut rest) = (Some(&self.root), String::new(), prefix);
+
+        while let Some(current) = node.filter(|_| !rest.is_empty()) {
+            node = current.child(rest);
+            if let Some(child) = node {
+                key.push_str(&child.label);
+                // `prefix` may end in the middle of the edge.
+                rest = match rest.strip_prefix(child.label.as_str()) {
+                    Some(rest) => rest,
+                    None if child.label.starts_with(rest) => "",
+                    None => return RadixIter { stack: vec![] },
+                };
+            }
+        }
+
+        RadixIter {
+            stack: node.map(|node| (key, node)).into_iter().collect(),
+        }
+    }
+
+    /// Longest key that is a prefix of `query`.
+    pub fn longest_prefix<'q>(&self, query: &'q str) -> Option<(&'q str, &V)> {
+        let mut best = self.root.value.as_ref().map(|value| ("", value));
+        let (mut node, mut consumed) = (&self.root, 0);
+
+        while let Some(child) = node.child(&query[consumed..]) {
+            if !query[consumed..].starts_with(child.label.as_str()) {
+                break;
+            }
+
+            node = child;
+            consumed += child.label.len();
+            if let Some(value) = &node.value {
+                best = Some((&query[..consumed], value));
+            }
+        }
+
+        best
+    }
+
+    /// At most `limit` completions of `prefix`, shortest first, then lexicographic.
+    pub fn suggest(&self, prefix: &str, limit: usize) -> Vec<(String, &V)> {
+        by_length(self.iter_prefix(prefix), limit)
+    }
+
+    /// Edges drawn top-down as `label`, `label = value` where a key ends.
+    pub fn shape(&self) -> String
+    where
+        V: Display,
+    {
+        fn write_node<V: Display>(out: &mut String, node: &Node<V>, prefix: &str) {
+            let last = node.children.len().saturating_sub(1);
+            for (i, child) in node.children.values().enumerate() {
+                let (connector, indent) = match i == last {
+                    true => ("└── ", "    "),
+                    false => ("├── ", "│   "),
+                };
+                let _ = match &child.value {
+                    Some(value) => writeln!(out, "{prefix}{connector}{} = {value}", child.label),
+                    None => writeln!(out, "{prefix}{connector}{}", child.label),
+                };
+                write_node(out, child, &format!("{prefix}{indent}"));
+            }
+        }
+
+        let mut out = match &self.root.value {
+            Some(value) => format!("· = {value}\n"),
+            None => "·\n".to_string(),
+        };
+        write_node(&mut out, &self.root, "");
+        out
+    }
+}
+
+impl<V> Default for RadixTree<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: AsRef<str>, V> FromIterator<(K, V)> for RadixTree<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        iter.into_iter().for_each(|(key, value)| {
+            tree.insert(key.as_ref(), value);
+        });
+        tree
+    }
+}
+
+pub struct RadixIter<'a, V> {
+    // Key so far and the node it leads to, next node on top.
+    stack: Vec<(String, &'a Node<V>)>,
+}
+
+impl<'a, V> Iterator for RadixIter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, node) = self.stack.pop()?;
+            for child in node.children.values().rev() {
+                self.stack.push((format!("{key}{}", child.label), child));
+            }
+
+            if let Some(value) = &node.value {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+impl super::super::Algorithm for RadixTree<u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut tree = super::WORDS
+            .iter()
+            .enumerate()
+            .map(|(i, word)| (word, i as u32))
+            .collect::<RadixTree<u32>>();
+
+        println!("{} words in {} nodes:", tree.len(), tree.node_count());
+        print!("{}", tree.shape());
+
+        tree.remove("tea");
+        tree.remove("ten");
+        println!("\nAfter removing \"tea\" and \"ten\" (edges merge back):");
+        print!("{}", tree.shape());
+
+        println!(
+            "\niter_prefix(\"te\") = {:?}",
+            tree.iter_prefix("te").map(|(k, _)| k).collect::<Vec<_>>()
+        );
+        println!(
+            "longest_prefix(\"intervals\") = {:?}",
+            tree.longest_prefix("intervals")
+        );
+
+        let suggestions = tree.suggest("in", 2);
+        println!(
+            "suggest(\"in\", 2) = {:?}",
+            suggestions.iter().map(|(k, _)| k).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        algorithm::{prefix_tree::Trie, Algorithm},
+        random::SeededRandom,
+    };
+
+    use super::*;
+
+    /// No chain of valueless single-child nodes, labels match their keys.
+    fn check_compressed<V>(tree: &RadixTree<V>) {
+        fn check<V>(node: &Node<V>, is_root: bool) {
+            if !is_root {
+                assert!(!node.label.is_empty());
+                assert!(node.value.is_some() || node.children.len() >= 2);
+            }
+            for (first, child) in &node.children {
+                assert!(child.label.starts_with(*first));
+                check(child, false);
+            }
+        }
+
+        check(&tree.root, true);
+    }
+
+    #[test]
+    fn showcase() {
+        RadixTree::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_btree_map() {
+        let mut random = SeededRandom::new(440);
+        let mut tree = RadixTree::new();
+        let mut reference = BTreeMap::new();
+
+        for i in 0..3_000 {
+            let len = random.get_in_range(0, 6);
+            let key = (0..len)
+                .map(|_| random.get_in_range(b'a', b'd') as char)
+                .collect::<String>();
+
+            match random.get_bool(0.6) {
+                true => assert_eq!(tree.insert(&key, i), reference.insert(key.clone(), i)),
+                false => assert_eq!(tree.remove(&key), reference.remove(&key)),
+            }
+            check_compressed(&tree);
+            assert_eq!(tree.len(), reference.len());
+            assert_eq!(tree.get(&key), reference.get(&key));
+        }
+
+        assert!(tree
+            .iter()
+            .eq(reference.iter().map(|(k, v)| (k.clone(), v))));
+        for key in reference.keys() {
+            tree.remove(key);
+            check_compressed(&tree);
+        }
+        assert_eq!(tree.node_count(), 1);
+    }
+
+    #[test]
+    fn matches_trie() {
+        let words = [
+            "",
+            "a",
+            "ab",
+            "abc",
+            "abd",
+            "b",
+            "ünï",
+            "ünïcode",
+            "romane",
+            "romanus",
+        ];
+        let tree = words.iter().map(|k| (k, ())).collect::<RadixTree<()>>();
+        let trie = words.iter().map(|k| (k, ())).collect::<Trie<()>>();
+
+        for prefix in ["", "a", "ab", "abx", "ü", "ünïc", "rom", "romanx", "z"] {
+            assert!(tree.iter_prefix(prefix).eq(trie.iter_prefix(prefix)));
+            assert_eq!(tree.suggest(prefix, 3), trie.suggest(prefix, 3));
+        }
+        for query in ["abcd", "ünïco", "romanes", "x", ""] {
+            assert_eq!(tree.longest_prefix(query), trie.longest_prefix(query));
+        }
+        assert!(tree.node_count() < trie.node_count());
+    }
+
+    #[test]
+    fn shape() {
+        let tree = ["romane", "romanus", "rubens"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, k)| (k, i))
+            .collect::<RadixTree<usize>>();
+
+        assert_eq!(
+            tree.shape(),
+            "·\n└── r\n    ├── oman\n    │   ├── e = 0\n    │   └── us = 1\n    └── ubens = 2\n"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+
+use super::by_length;
+
+struct Node<V> {
+    value: Option<V>,
+    // Sorted, so iteration is lexicographic.
+    children: BTreeMap<char, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    fn count(&self) -> usize {
+        1 + self.children.values().map(Node::count).sum::<usize>()
+    }
+}
+
+/// String-keyed map with one node per character.
+pub struct Trie<V> {
+    root: Node<V>,
+    size: usize,
+}
+
+impl<V> Trie<V> {
+    pub fn new() -> Self {
+        Self {
+            root: Node::new(),
+            size: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Root included, compare with [`super::RadixTree::node_count`].
+    pub fn node_count(&self) -> usize {
+        self.root.count()
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node<V>> {
+        prefix
+            .chars()
+            .try_fold(&self.root, |node, c| node.children.get(&c))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.find(key)?.value.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        key.chars()
+            .try_fold(&mut self.root, |node, c| node.children.get_mut(&c))?
+            .value
+            .as_mut()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// @Returns previous value of `key`.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let node = key.chars().fold(&mut self.root, |node, c| {
+            node.children.entry(c).or_insert_with(Node::new)
+        });
+
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    /// @Mutate prunes the branch that held only `key`.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        // @Returns removed value and whether `node` became useless.
+        fn remove<V>(node: &mut Node<V>, mut chars: std::str::Chars) -> Option<(V, bool)> {
+            let removed = match chars.next() {
+                None => node.value.take()?,
+                Some(c) => {
+                    let (removed, prune) = remove(node.children.get_mut(&c)?, chars)?;
+                    if prune {
+                        node.children.remove(&c);
+                    }
+                    removed
+                }
+            };
+
+            Some((removed, node.value.is_none() && node.children.is_empty()))
+        }
+
+        let (removed, _) = remove(&mut self.root, key.chars())?;
+        self.size -= 1;
+        Some(removed)
+    }
+
+    /// Lexicographic.
+    pub fn iter(&self) -> TrieIter<'_, V> {
+        self.iter_prefix("")
+    }
+
+    /// Entries whose key starts with `prefix`, lexicographic.
+    pub fn iter_prefix(&self, prefix: &str) -> TrieIter<'_, V> {
+        TrieIter {
+            stack: self
+                .find(prefix)
+                .map(|node| (prefix.to_string(), node))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Longest key that is a prefix of `query`.
+    pub fn longest_prefix<'q>(&self, query: &'q str) -> Option<(&'q str, &V)> {
+        let mut best = self.root.value.as_ref().map(|value| ("", value));
+        let mut node = &self.root;
+
+        for (i, c) in query.char_indices() {
+            match node.children.get(&c) {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(value) = &node.value {
+                best = Some((&query[..i + c.len_utf8()], value));
+            }
+        }
+
+        best
+    }
+
+    /// At most `limit` completions of `prefix`, shortest first, then lexicographic.
+    pub fn suggest(&self, prefix: &str, limit: usize) -> Vec<(String, &V)> {
+        by_length(self.iter_prefix(prefix), limit)
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: AsRef<str>, V> FromIterator<(K, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        iter.into_iter().for_each(|(key, value)| {
+            trie.insert(key.as_ref(), value);
+        });
+        trie
+    }
+}
+
+pub struct TrieIter<'a, V> {
+    // Key so far and the node it leads to, next node on top.
+    stack: Vec<(String, &'a Node<V>)>,
+}
+
+impl<'a, V> Iterator for TrieIter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, node) = self.stack.pop()?;
+            for (c, child) in node.children.iter().rev() {
+                self.stack.push((format!("{key}{c}"), child));
+            }
+
+            if let Some(value) = &node.value {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+impl super::super::Algorithm for Trie<u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let words = super::WORDS;
+        let trie = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| (word, i as u32))
+            .collect::<Trie<u32>>();
+
+        println!("{} words in {} nodes", trie.len(), trie.node_count());
+        println!("get(\"team\") = {:?}", trie.get("team"));
+        println!(
+            "iter_prefix(\"tea\") = {:?}",
+            trie.iter_prefix("tea").map(|(k, _)| k).collect::<Vec<_>>()
+        );
+        println!(
+            "longest_prefix(\"teammate\") = {:?}",
+            trie.longest_prefix("teammate").map(|(k, _)| k)
+        );
+
+        let suggestions = trie.suggest("t", 4);
+        println!(
+            "suggest(\"t\", 4) = {:?}",
+            suggestions.iter().map(|(k, _)| k).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        Trie::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_btree_map() {
+        let mut random = SeededRandom::new(44);
+        let mut trie = Trie::new();
+        let mut reference = BTreeMap::new();
+
+        for i in 0..3_000 {
+            let len = random.get_in_range(0, 5);
+            let key = (0..len)
+                .map(|_| random.get_in_range(b'a', b'd') as char)
+                .collect::<String>();
+
+            match random.get_bool(0.6) {
+                true => assert_eq!(trie.insert(&key, i), reference.insert(key.clone(), i)),
+                false => assert_eq!(trie.remove(&key), reference.remove(&key)),
+            }
+            assert_eq!(trie.len(), reference.len());
+            assert_eq!(trie.get(&key), reference.get(&key));
+        }
+
+        assert!(trie
+            .iter()
+            .eq(reference.iter().map(|(k, v)| (k.clone(), v))));
+        for key in reference.keys() {
+            trie.remove(key);
+        }
+        assert_eq!(trie.node_count(), 1);
+    }
+
+    #[test]
+    fn prefixes() {
+        let trie = ["", "a", "ab", "abc", "abd", "b", "ünï", "ünïcode"]
+            .into_iter()
+            .map(|k| (k, ()))
+            .collect::<Trie<()>>();
+        let keys = |prefix| trie.iter_prefix(prefix).map(|(k, _)| k).collect::<Vec<_>>();
+
+        assert_eq!(keys("ab"), ["ab", "abc", "abd"]);
+        assert_eq!(keys("ü"), ["ünï", "ünïcode"]);
+        assert!(keys("abx").is_empty());
+        assert_eq!(keys("").len(), 8);
+
+        assert_eq!(trie.longest_prefix("abcd").map(|(k, _)| k), Some("abc"));
+        assert_eq!(trie.longest_prefix("ünïco").map(|(k, _)| k), Some("ünï"));
+        assert_eq!(trie.longest_prefix("x").map(|(k, _)| k), Some(""));
+    }
+
+    #[test]
+    fn suggest() {
+        let trie = ["abcd", "abd", "abc", "ab", "b"]
+            .into_iter()
+            .map(|k| (k, ()))
+            .collect::<Trie<()>>();
+        let suggest = |prefix, limit| {
+            trie.suggest(prefix, limit)
+                .into_iter()
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(suggest("a", 3), ["ab", "abc", "abd"]);
+        assert_eq!(suggest("abc", 10), ["abc", "abcd"]);
+        assert!(suggest("c", 10).is_empty());
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut trie = Trie::new();
+        trie.insert("key", 1);
+        *trie.get_mut("key").unwrap() += 1;
+
+        assert_eq!(trie.get("key"), Some(&2));
+        assert_eq!(trie.get_mut("ke"), None);
+    }
+}
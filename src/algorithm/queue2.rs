@@ -2,96 +2,150 @@ use std::{cmp::max, fmt::Display, mem::MaybeUninit};
 
 const DEFAULT_CAPACITY: usize = 10;
 const GROW_FACTOR: usize = 2;
+// Shrink by `GROW_FACTOR` once no more than 1 / `SHRINK_THRESHOLD` is occupied.
+const SHRINK_THRESHOLD: usize = 4;
 
-pub struct Queue {
+/// FIFO queue over a ring buffer: slots are reused as `head` wraps around,
+/// and the buffer is reallocated only when it is full (or mostly empty).
+pub struct RingQueue<T> {
     size: usize,
-    inner: Box<[MaybeUninit<u8>]>,
-    inner_start: usize, // Start of the slice
-    inner_end: usize,   // End of the slice capacity
+    inner: Box<[MaybeUninit<T>]>,
+    head: usize, // Slot of the first element
 }
 
-impl Queue {
+impl<T> RingQueue<T> {
     pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// At least 1, so there is always a slot for `head`.
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
             size: 0,
-            inner: Box::<[u8]>::new_uninit_slice(DEFAULT_CAPACITY),
-            inner_start: 0,
-            inner_end: DEFAULT_CAPACITY,
+            inner: Box::<[T]>::new_uninit_slice(max(capacity, 1)),
+            head: 0,
         }
     }
 
-    unsafe fn resize(&mut self) {
-        let new_capacity = max(self.inner_end - self.inner_start, DEFAULT_CAPACITY) * GROW_FACTOR;
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
 
-        let mut slice = Box::<[u8]>::new_uninit_slice(new_capacity);
-        for (i, value) in self.inner[self.inner_start..self.inner_end]
-            .iter()
-            .enumerate()
-        {
-            slice[i].as_mut_ptr().write(value.assume_init());
-        }
+    pub fn capacity(&self) -> usize {
+        self.inner.len()
+    }
 
-        self.inner_start = 0;
-        self.inner_end = new_capacity;
-        self.inner = slice;
+    /// Slot of the `i`-th element, counting from `head`.
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % self.capacity()
     }
 
-    pub fn enqueue(&mut self, value: u8) {
-        if self.size == self.inner_end - self.inner_start {
-            unsafe { self.resize() };
+    /// Moves elements into a new buffer of `new_capacity`, `head` becomes 0.
+    fn resize(&mut self, new_capacity: usize) {
+        debug_assert!(new_capacity >= self.size);
+
+        let mut slice = Box::<[T]>::new_uninit_slice(new_capacity);
+        for (i, new_slot) in slice.iter_mut().take(self.size).enumerate() {
+            // SAFETY: the first `size` slots from `head` are initialized, and each
+            // is read exactly once; the old buffer never drops its contents.
+            new_slot.write(unsafe { self.inner[self.slot(i)].assume_init_read() });
         }
 
-        unsafe {
-            self.inner[self.inner_start + self.size]
-                .as_mut_ptr()
-                .write(value);
+        self.head = 0;
+        self.inner = slice;
+    }
+
+    pub fn enqueue(&mut self, value: T) {
+        if self.size == self.capacity() {
+            self.resize(max(self.capacity(), DEFAULT_CAPACITY) * GROW_FACTOR);
         }
 
+        let tail = self.slot(self.size);
+        self.inner[tail].write(value);
         self.size += 1;
     }
 
-    pub fn dequeue(&mut self) -> Option<u8> {
+    pub fn dequeue(&mut self) -> Option<T> {
         if self.size == 0 {
             return None;
         }
 
-        self.inner_start += 1;
+        // SAFETY: `head` is initialized while `size` > 0 and is forgotten right after.
+        let value = unsafe { self.inner[self.head].assume_init_read() };
+        self.head = self.slot(1);
         self.size -= 1;
 
-        Some(unsafe { self.inner[self.inner_start - 1].assume_init() })
+        if self.capacity() > DEFAULT_CAPACITY && self.size <= self.capacity() / SHRINK_THRESHOLD {
+            self.resize(max(self.capacity() / GROW_FACTOR, DEFAULT_CAPACITY));
+        }
+
+        Some(value)
     }
 
-    pub fn peek(&self) -> Option<&u8> {
+    pub fn peek(&self) -> Option<&T> {
         if self.size == 0 {
             return None;
         }
 
-        Some(unsafe { self.inner[self.inner_start].assume_init_ref() })
+        // SAFETY: `head` is initialized while `size` > 0, and `&self` keeps it from
+        // being read out or dropped while the reference lives.
+        Some(unsafe { self.inner[self.head].assume_init_ref() })
     }
 
-    pub fn size(&self) -> usize {
-        self.size
+    /// Down to `size`, but never below a capacity of 1.
+    pub fn shrink_to_fit(&mut self) {
+        self.resize(max(self.size, 1));
+    }
+
+    /// Elements from `head` to `tail`.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        // SAFETY: the first `size` slots from `head` are initialized.
+        (0..self.size).map(|i| unsafe { self.inner[self.slot(i)].assume_init_ref() })
+    }
+}
+
+impl<T> Drop for RingQueue<T> {
+    fn drop(&mut self) {
+        for i in 0..self.size {
+            let slot = self.slot(i);
+            // SAFETY: each initialized slot is dropped once, the buffer is freed afterwards.
+            unsafe { self.inner[slot].assume_init_drop() };
+        }
     }
 }
 
-impl Display for Queue {
+impl<T> Default for RingQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Display for RingQueue<T>
+where
+    T: Display,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!(
             "Size: {}, underlying_array_capacity: {}, inner: ",
             self.size,
-            self.inner_end - self.inner_start
+            self.capacity()
         ))?;
 
-        for i in self.inner_start..self.inner_start + self.size {
-            f.write_fmt(format_args!("{} ", unsafe { self.inner[i].assume_init() }))?;
+        for value in self.iter() {
+            f.write_fmt(format_args!("{value} "))?;
         }
 
         Ok(())
     }
 }
-impl super::Algorithm for Queue {
+
+impl super::Algorithm for RingQueue<u32> {
     fn showcase() -> Result<(), Box<dyn std::error::Error>> {
-        let mut q = Queue::new();
+        let mut q = RingQueue::new();
 
         for v in 1..=100 {
             q.enqueue(v);
@@ -115,20 +169,23 @@ impl super::Algorithm for Queue {
     }
 }
 
+// Exercises every `unsafe` path above, so they are worth running under `cargo miri test`.
 #[cfg(test)]
 mod test {
-    use crate::algorithm::Algorithm;
+    use std::{collections::VecDeque, rc::Rc};
 
-    use super::Queue;
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
 
     #[test]
     fn showcase() {
-        Queue::showcase().unwrap();
+        RingQueue::showcase().unwrap();
     }
 
     #[test]
     fn test() {
-        let mut q = Queue::new();
+        let mut q = RingQueue::new();
 
         for v in 1..=100 {
             q.enqueue(v);
@@ -152,4 +209,134 @@ mod test {
 
         assert_eq!(q.size(), 47);
     }
+
+    #[test]
+    fn reuses_slots() {
+        let mut q = RingQueue::new();
+
+        // Never more than 3 elements at once: the buffer must not grow.
+        for v in 0..1000 {
+            q.enqueue(v);
+            if v >= 2 {
+                assert_eq!(q.dequeue(), Some(v - 2));
+            }
+        }
+
+        assert_eq!(q.capacity(), DEFAULT_CAPACITY);
+        assert_eq!(q.iter().copied().collect::<Vec<_>>(), [998, 999]);
+    }
+
+    #[test]
+    fn grows_and_shrinks() {
+        let mut q = RingQueue::new();
+
+        for v in 0..DEFAULT_CAPACITY {
+            q.enqueue(v);
+        }
+        assert_eq!(q.capacity(), DEFAULT_CAPACITY);
+
+        q.enqueue(DEFAULT_CAPACITY);
+        assert_eq!(q.capacity(), DEFAULT_CAPACITY * GROW_FACTOR);
+
+        for v in 0..1000 {
+            q.enqueue(v);
+        }
+        let grown = q.capacity();
+        while q.size() > 10 {
+            q.dequeue();
+        }
+        assert!(q.capacity() < grown);
+        assert!(q.capacity() >= q.size());
+
+        q.shrink_to_fit();
+        assert_eq!(q.capacity(), 10);
+        assert_eq!(
+            q.iter().copied().collect::<Vec<_>>(),
+            (990..1000).collect::<Vec<_>>()
+        );
+
+        q.enqueue(0);
+        assert_eq!(q.size(), 11);
+    }
+
+    #[test]
+    fn shrink_empty() {
+        let mut q = RingQueue::new();
+        q.enqueue(1);
+        q.dequeue();
+
+        q.shrink_to_fit();
+        assert_eq!(q.capacity(), 1);
+        assert_eq!(RingQueue::<u32>::with_capacity(0).capacity(), 1);
+
+        q.enqueue(2);
+        q.enqueue(3);
+        assert_eq!(q.peek(), Some(&2));
+        assert_eq!(q.iter().copied().collect::<Vec<_>>(), [2, 3]);
+    }
+
+    #[test]
+    fn against_vec_deque() {
+        let mut random = SeededRandom::new(35);
+        let mut q = RingQueue::with_capacity(0);
+        let mut reference = VecDeque::new();
+
+        for i in 0..20_000 {
+            // Drifts between growing and draining phases.
+            let p = match (i / 2_000) % 2 {
+                0 => 0.7,
+                _ => 0.3,
+            };
+
+            if random.get_bool(p) {
+                let value = i.to_string();
+                q.enqueue(value.clone());
+                reference.push_back(value);
+            } else {
+                assert_eq!(q.dequeue(), reference.pop_front());
+            }
+
+            assert_eq!(q.size(), reference.len());
+            assert_eq!(q.peek(), reference.front());
+        }
+
+        assert!(q.iter().eq(reference.iter()));
+        assert!(q.iter().rev().eq(reference.iter().rev()));
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let counter = Rc::new(());
+
+        let mut q = RingQueue::new();
+        for _ in 0..20 {
+            q.enqueue(counter.clone());
+        }
+        for _ in 0..5 {
+            q.dequeue();
+        }
+        // Buffer is full up to its end, so `tail` wraps around to the freed slots.
+        for _ in 0..3 {
+            q.enqueue(counter.clone());
+        }
+        assert_eq!(q.capacity(), 20);
+        assert_eq!(Rc::strong_count(&counter), 1 + 18);
+
+        drop(q);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut q = RingQueue::new();
+        for _ in 0..100 {
+            q.enqueue(());
+        }
+        for _ in 0..60 {
+            assert_eq!(q.dequeue(), Some(()));
+        }
+
+        assert_eq!(q.size(), 40);
+        assert_eq!(q.iter().count(), 40);
+    }
 }
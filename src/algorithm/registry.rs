@@ -0,0 +1,222 @@
+use super::{
+    b_tree::{BPlusTree, BTree},
+    blocking_queue::BlockingQueue,
+    cache::{LfuCache, LruCache},
+    disjoint_set::{DisjointSet, RollbackDisjointSet},
+    graph::Graph,
+    hash_map::{ChainedMap, OpenMap},
+    heap::BinaryHeap,
+    linked_list::LinkedList,
+    prefix_tree::{RadixTree, Trie},
+    queue::Queue,
+    queue2::RingQueue,
+    skip_list::SkipList,
+    sort::Sort,
+    stack::{BoundedStack, MinStack, PersistentStack, Stack},
+    tree::{AvlMap, RedBlackMap},
+    Algorithm, Error,
+};
+
+/// Entry of [`SHOWCASES`]: one [`Algorithm`] implementor.
+pub struct Showcase {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub run: fn() -> Result<(), Box<dyn Error>>,
+}
+
+// New `Algorithm` implementors should be listed here, so the runner can find them.
+pub static SHOWCASES: &[Showcase] = &[
+    Showcase {
+        name: "linked_list",
+        description: "Circular doubly linked list with cursors, splicing and merge sort",
+        run: LinkedList::<u32>::showcase,
+    },
+    Showcase {
+        name: "queue",
+        description: "FIFO queue over `VecDeque`",
+        run: Queue::<u32>::showcase,
+    },
+    Showcase {
+        name: "ring_queue",
+        description: "FIFO queue over a growable ring buffer",
+        run: RingQueue::<u32>::showcase,
+    },
+    Showcase {
+        name: "blocking_queue",
+        description: "Bounded MPMC queue on `Mutex` + `Condvar`, producer/consumer demo",
+        run: BlockingQueue::<u32>::showcase,
+    },
+    Showcase {
+        name: "binary_heap",
+        description: "Min/max binary heap with handles and `decrease_key`",
+        run: BinaryHeap::<u32>::showcase,
+    },
+    Showcase {
+        name: "stack",
+        description: "LIFO stack over `Vec`",
+        run: Stack::<u8>::showcase,
+    },
+    Showcase {
+        name: "min_stack",
+        description: "Stack with O(1) min and max",
+        run: MinStack::<u32>::showcase,
+    },
+    Showcase {
+        name: "persistent_stack",
+        description: "Immutable stack with structural sharing, undo history demo",
+        run: PersistentStack::<u32>::showcase,
+    },
+    Showcase {
+        name: "bounded_stack",
+        description: "Fixed-capacity stack that reports overflow",
+        run: BoundedStack::<u32>::showcase,
+    },
+    Showcase {
+        name: "avl_map",
+        description: "Ordered map over an AVL tree, prints the tree shape",
+        run: AvlMap::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "red_black_map",
+        description: "Ordered map over a left-leaning red-black tree, prints the tree shape",
+        run: RedBlackMap::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "b_tree",
+        description: "B-tree of configurable order, printed level by level",
+        run: BTree::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "b_plus_tree",
+        description: "B+ tree with linked leaves, range scans along the links",
+        run: BPlusTree::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "skip_list",
+        description: "Ordered set over a seeded skip list, prints every level",
+        run: SkipList::<u32>::showcase,
+    },
+    Showcase {
+        name: "trie",
+        description: "String map with a node per char, prefix search and suggestions",
+        run: Trie::<u32>::showcase,
+    },
+    Showcase {
+        name: "radix_tree",
+        description: "Compressed trie, prints its edges",
+        run: RadixTree::<u32>::showcase,
+    },
+    Showcase {
+        name: "open_map",
+        description: "Open-addressing hash map, linear vs quadratic vs Robin Hood probe lengths",
+        run: OpenMap::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "chained_map",
+        description: "Hash map chaining collisions in linked lists, prints chain lengths",
+        run: ChainedMap::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "lru_cache",
+        description: "Least recently used cache over a hash map and a linked list",
+        run: LruCache::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "lfu_cache",
+        description: "Least frequently used cache with a linked list per use count",
+        run: LfuCache::<u32, u32>::showcase,
+    },
+    Showcase {
+        name: "disjoint_set",
+        description: "Union-find with union by rank/size and path compression",
+        run: DisjointSet::showcase,
+    },
+    Showcase {
+        name: "rollback_disjoint_set",
+        description: "Union-find with undo, answers dynamic connectivity offline",
+        run: RollbackDisjointSet::showcase,
+    },
+    Showcase {
+        name: "graph",
+        description: "Adjacency-list graph: traversals, SCC, shortest paths, MST, flow, matching",
+        run: Graph::showcase,
+    },
+    Showcase {
+        name: "sort",
+        description: "Sorting algorithms side by side, comparisons and swaps per input shape",
+        run: Sort::showcase,
+    },
+];
+
+fn normalize(name: &str) -> String {
+    name.to_lowercase().replace('-', "_")
+}
+
+/// Case-insensitive, `-` and `_` are interchangeable.
+pub fn find(name: &str) -> Option<&'static Showcase> {
+    let name = normalize(name);
+    SHOWCASES.iter().find(|showcase| showcase.name == name)
+}
+
+/// Names starting with `prefix`, shortest first. Normalized like [`find`].
+pub fn complete(prefix: &str) -> Vec<&'static str> {
+    let names = SHOWCASES
+        .iter()
+        .map(|showcase| (showcase.name, showcase.name))
+        .collect::<RadixTree<_>>();
+
+    let suggestions = names.suggest(&normalize(prefix), SHOWCASES.len());
+    suggestions.into_iter().map(|(_, &name)| name).collect()
+}
+
+/// Completions of the longest start of `name` that any showcase shares, for typos.
+pub fn suggest(name: &str) -> Vec<&'static str> {
+    let mut prefix = normalize(name);
+    while !prefix.is_empty() {
+        match complete(&prefix) {
+            names if names.is_empty() => prefix.pop(),
+            names => return names,
+        };
+    }
+
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn unique_names() {
+        let names = SHOWCASES.iter().map(|s| s.name).collect::<HashSet<_>>();
+        assert_eq!(names.len(), SHOWCASES.len());
+    }
+
+    #[test]
+    fn find_by_name() {
+        assert_eq!(find("linked_list").map(|s| s.name), Some("linked_list"));
+        assert_eq!(find("Min-Stack").map(|s| s.name), Some("min_stack"));
+        assert!(find("nope").is_none());
+    }
+
+    #[test]
+    fn complete_and_suggest() {
+        assert_eq!(complete("B-"), ["b_tree", "b_plus_tree"]);
+        assert_eq!(complete("stack"), ["stack"]);
+        assert_eq!(complete("").len(), SHOWCASES.len());
+        assert!(complete("nope").is_empty());
+
+        assert_eq!(suggest("b_trie"), ["b_tree"]);
+        assert_eq!(suggest("stak"), ["stack"]);
+        assert!(suggest("xyz").is_empty());
+    }
+
+    #[test]
+    fn run_all() {
+        for showcase in SHOWCASES {
+            assert!((showcase.run)().is_ok(), "{} failed", showcase.name);
+        }
+    }
+}
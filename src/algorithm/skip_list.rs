@@ -0,0 +1,438 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Write},
+    ops::{Bound, RangeBounds, RangeFull},
+};
+
+use crate::random::{Random, SeededRandom};
+
+/// Levels never grow past this, enough for 2^32 elements at `p = 1/2`.
+pub const MAX_LEVEL: usize = 32;
+
+/// Index into [`SkipList::nodes`].
+type NodeId = usize;
+
+struct Node<T> {
+    value: T,
+    // One link per level the node is on, bottom first.
+    next: Vec<Option<NodeId>>,
+}
+
+/// Ordered set over a skip list: sorted linked lists stacked on each other,
+/// every element climbs one level higher with probability 1/2.
+/// O(log n) expected for `insert`, `remove` and `contains`.
+pub struct SkipList<T> {
+    // Links out of the head, one per level.
+    head: Vec<Option<NodeId>>,
+    // `None` marks a freed slot, reused before the arena grows.
+    nodes: Vec<Option<Node<T>>>,
+    free: Vec<NodeId>,
+    // Number of non-empty levels.
+    level: usize,
+    size: usize,
+    random: SeededRandom,
+}
+
+impl<T: Ord> SkipList<T> {
+    /// Levels come from a randomly seeded generator, see [`SkipList::with_seed`].
+    pub fn new() -> Self {
+        Self::with_seed(Random::<u64>::get())
+    }
+
+    /// Same seed and same operations give the same levels.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            head: vec![None; MAX_LEVEL],
+            nodes: vec![],
+            free: vec![],
+            level: 0,
+            size: 0,
+            random: SeededRandom::new(seed),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Number of non-empty levels.
+    pub fn height(&self) -> usize {
+        self.level
+    }
+
+    fn node(&self, id: NodeId) -> &Node<T> {
+        self.nodes[id].as_ref().expect("Linked nodes are alive")
+    }
+
+    /// Link out of `from` on `level`, the head if `from` is `None`.
+    fn next_of(&self, from: Option<NodeId>, level: usize) -> Option<NodeId> {
+        match from {
+            Some(id) => self.node(id).next[level],
+            None => self.head[level],
+        }
+    }
+
+    fn set_next(&mut self, from: Option<NodeId>, level: usize, to: Option<NodeId>) {
+        match from {
+            Some(id) => {
+                self.nodes[id]
+                    .as_mut()
+                    .expect("Linked nodes are alive")
+                    .next[level] = to
+            }
+            None => self.head[level] = to,
+        }
+    }
+
+    /// @Returns last node before the first element `!is_before` on every level, indexed by level.
+    fn descend(&self, is_before: impl Fn(&T) -> bool) -> Vec<Option<NodeId>> {
+        let mut path = vec![None; self.level];
+        let mut current = None;
+
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next_of(current, level) {
+                match is_before(&self.node(next).value) {
+                    true => current = Some(next),
+                    false => break,
+                }
+            }
+            path[level] = current;
+        }
+
+        path
+    }
+
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.random.get_bool(0.5) {
+            level += 1;
+        }
+
+        level
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let path = self.descend(|v| v < value);
+        let found = path.first().and_then(|&before| self.next_of(before, 0));
+
+        found.is_some_and(|id| self.node(id).value == *value)
+    }
+
+    /// @Returns `false` if `value` was already there.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut path = self.descend(|v| *v < value);
+        let found = self.next_of(path.first().copied().flatten(), 0);
+        if found.is_some_and(|id| self.node(id).value == value) {
+            return false;
+        }
+
+        let level = self.random_level();
+        path.resize(level.max(self.level), None);
+        self.level = self.level.max(level);
+
+        let next = (0..level).map(|l| self.next_of(path[l], l)).collect();
+        let node = Node { value, next };
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.nodes[id] = Some(node);
+                id
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+
+        for (l, &before) in path.iter().enumerate().take(level) {
+            self.set_next(before, l, Some(id));
+        }
+        self.size += 1;
+        true
+    }
+
+    /// @Returns removed element.
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let path = self.descend(|v| v < value);
+        let id = self.next_of(*path.first()?, 0)?;
+        if self.node(id).value != *value {
+            return None;
+        }
+
+        let node = self.nodes[id].take().expect("Linked nodes are alive");
+        for (l, &next) in node.next.iter().enumerate() {
+            self.set_next(path[l], l, next);
+        }
+        self.free.push(id);
+        self.size -= 1;
+
+        while self.level > 0 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        Some(node.value)
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.head[0].map(|id| &self.node(id).value)
+    }
+
+    /// Rides the top levels as far right as they go. O(log n) expected.
+    pub fn last(&self) -> Option<&T> {
+        let path = self.descend(|_| true);
+        path.first()
+            .copied()
+            .flatten()
+            .map(|id| &self.node(id).value)
+    }
+
+    /// Ascending.
+    pub fn iter(&self) -> Range<'_, T, RangeFull> {
+        self.range(..)
+    }
+
+    /// Ascending elements in `range`. O(log n + elements) expected.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T, R> {
+        let path = self.descend(|v| match range.start_bound() {
+            Bound::Included(start) => v < start,
+            Bound::Excluded(start) => v <= start,
+            Bound::Unbounded => false,
+        });
+
+        Range {
+            list: self,
+            next: self.next_of(path.first().copied().flatten(), 0),
+            range,
+        }
+    }
+
+    /// Every level as a row, top first, elements in columns aligned with the bottom row.
+    pub fn shape(&self) -> String
+    where
+        T: Display,
+    {
+        let mut rows = vec![String::new(); self.level];
+        let mut current = self.head[0];
+
+        while let Some(id) = current {
+            let node = self.node(id);
+            let label = node.value.to_string();
+            for (level, row) in rows.iter_mut().enumerate() {
+                match level < node.next.len() {
+                    true => row.push_str(&label),
+                    false => row.push_str(&"-".repeat(label.chars().count())),
+                }
+                row.push_str("--");
+            }
+            current = node.next[0];
+        }
+
+        let mut out = String::new();
+        for (level, row) in rows.iter().enumerate().rev() {
+            let _ = writeln!(out, "L{level:<2} {row}> ·");
+        }
+        out
+    }
+
+    /// Every level is sorted, sits inside the one below and `level`, `len` are up to date.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let mut below = None::<Vec<NodeId>>;
+
+        for level in 0..MAX_LEVEL {
+            let mut ids = vec![];
+            let mut current = self.head[level];
+            while let Some(id) = current {
+                let node = self.nodes[id].as_ref().ok_or("Link to a freed node")?;
+                if node.next.len() <= level {
+                    return Err(format!(
+                        "Node of height {} linked on level {level}",
+                        node.next.len()
+                    ));
+                }
+                if let Some(&previous) = ids.last() {
+                    if self.node(previous).value.cmp(&node.value) != Ordering::Less {
+                        return Err(format!("Level {level} is out of order"));
+                    }
+                }
+
+                ids.push(id);
+                current = node.next[level];
+            }
+
+            if (level < self.level) == ids.is_empty() {
+                return Err(format!(
+                    "Level {level} disagrees with height {}",
+                    self.level
+                ));
+            }
+            if let Some(below) = &below {
+                if ids.iter().any(|id| !below.contains(id)) {
+                    return Err(format!("Level {level} skips level {}", level - 1));
+                }
+            }
+            if level == 0 && ids.len() != self.size {
+                return Err(format!(
+                    "Holds {} elements, len is {}",
+                    ids.len(),
+                    self.size
+                ));
+            }
+
+            below = Some(ids);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Ord> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for SkipList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| {
+            self.insert(value);
+        });
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SkipList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+pub struct Range<'a, T, R> {
+    list: &'a SkipList<T>,
+    next: Option<NodeId>,
+    range: R,
+}
+
+impl<'a, T: Ord, R: RangeBounds<T>> Iterator for Range<'a, T, R> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.list.node(self.next?);
+        let is_below_end = match self.range.end_bound() {
+            Bound::Included(end) => node.value <= *end,
+            Bound::Excluded(end) => node.value < *end,
+            Bound::Unbounded => true,
+        };
+
+        self.next = match is_below_end {
+            true => node.next[0],
+            false => return None,
+        };
+        Some(&node.value)
+    }
+}
+
+impl super::Algorithm for SkipList<u32> {
+    fn showcase() -> Result<(), Box<dyn super::Error>> {
+        let mut random = SeededRandom::new(43);
+        let mut list = SkipList::with_seed(43);
+        list.extend((0..16).map(|_| random.get_in_range(0, 100u32)));
+        println!(
+            "{} elements, {} levels:\n{}",
+            list.len(),
+            list.height(),
+            list.shape()
+        );
+
+        let removed = list.iter().step_by(3).copied().collect::<Vec<_>>();
+        for value in &removed {
+            list.remove(value);
+        }
+        println!("After removing {removed:?}:\n{}", list.shape());
+
+        println!(
+            "range(20..60) = {:?}",
+            list.range(20..60).collect::<Vec<_>>()
+        );
+        println!("first = {:?}, last = {:?}", list.first(), list.last());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::algorithm::Algorithm;
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        SkipList::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_btree_set() {
+        let mut random = SeededRandom::new(430);
+        let mut list = SkipList::with_seed(7);
+        let mut reference = BTreeSet::new();
+
+        for _ in 0..3_000 {
+            let value = random.get_in_range(0, 300u32);
+            match random.get_bool(0.6) {
+                true => assert_eq!(list.insert(value), reference.insert(value)),
+                false => assert_eq!(list.remove(&value), reference.take(&value)),
+            }
+
+            list.check_invariants().unwrap();
+            assert_eq!(list.contains(&value), reference.contains(&value));
+        }
+
+        assert!(list.iter().eq(reference.iter()));
+        assert_eq!(list.first(), reference.first());
+        assert_eq!(list.last(), reference.last());
+    }
+
+    #[test]
+    fn same_seed_same_shape() {
+        let build = |seed| {
+            let mut list = SkipList::with_seed(seed);
+            list.extend(0..100u32);
+            list.shape()
+        };
+
+        assert_eq!(build(1), build(1));
+        assert_ne!(build(1), build(2));
+    }
+
+    #[test]
+    fn range() {
+        let list = (0..50).map(|v| v * 3).collect::<SkipList<u32>>();
+        let reference = (0..50).map(|v| v * 3).collect::<BTreeSet<u32>>();
+
+        for (start, end) in [(0, 200), (10, 20), (9, 21), (140, 300), (30, 30)] {
+            assert!(list.range(start..end).eq(reference.range(start..end)));
+            assert!(list.range(start..=end).eq(reference.range(start..=end)));
+            let excluded = (Bound::Excluded(start), Bound::Included(end));
+            assert!(list.range(excluded).eq(reference.range(excluded)));
+        }
+        assert!(list.range(..40).eq(reference.range(..40)));
+        assert!(list.range(100..).eq(reference.range(100..)));
+    }
+
+    #[test]
+    fn empty() {
+        let mut list = SkipList::<u32>::with_seed(0);
+
+        assert_eq!(list.remove(&1), None);
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.shape(), "");
+        list.check_invariants().unwrap();
+    }
+}
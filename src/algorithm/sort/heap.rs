@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+
+use super::Counter;
+
+pub(super) fn heap_sort<T, F>(v: &mut [T], counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // Floyd's construction: sift down every parent, last one first. O(n).
+    for i in (0..v.len() / 2).rev() {
+        sift_down(v, i, counter);
+    }
+
+    // The root is the largest of what's left, it goes right behind the heap.
+    for end in (1..v.len()).rev() {
+        counter.swap(v, 0, end);
+        sift_down(&mut v[..end], 0, counter);
+    }
+}
+
+/// Moves `v[i]` down until both children are no larger.
+fn sift_down<T, F>(v: &mut [T], mut i: usize, counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let (left, right) = (2 * i + 1, 2 * i + 2);
+        if left >= v.len() {
+            return;
+        }
+
+        let larger = match right < v.len() && counter.less(&v[left], &v[right]) {
+            true => right,
+            false => left,
+        };
+        if !counter.less(&v[i], &v[larger]) {
+            return;
+        }
+
+        counter.swap(v, i, larger);
+        i = larger;
+    }
+}
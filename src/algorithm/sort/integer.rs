@@ -0,0 +1,116 @@
+use super::SortStats;
+
+/// Stable, by a small integer key: counts every key, then writes each element straight
+/// into its place. O(n + k) for keys below `k`, with `k` counters allocated.
+/// Compares nothing, every element is written once.
+pub fn counting_sort_by_key<T, F>(v: &mut [T], key: F) -> SortStats
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let mut stats = SortStats::default();
+    if let Some(max) = v.iter().map(&key).max() {
+        stats.writes += distribute(v, max + 1, key);
+    }
+    stats
+}
+
+/// Stable, least significant byte first: a counting pass per byte of the key, skipping
+/// bytes that are the same everywhere. O(n) for at most 8 passes.
+pub fn radix_sort_by_key<T, F>(v: &mut [T], key: F) -> SortStats
+where
+    T: Clone,
+    F: Fn(&T) -> u64,
+{
+    let mut stats = SortStats::default();
+    for shift in (0..u64::BITS).step_by(8) {
+        let byte = |x: &T| (key(x) >> shift) as usize & 0xff;
+        let Some(first) = v.first().map(byte) else {
+            break;
+        };
+
+        if v.iter().any(|x| byte(x) != first) {
+            stats.writes += distribute(v, 1 << 8, byte);
+        }
+    }
+    stats
+}
+
+/// One stable counting pass by `bucket`, which must be below `buckets`.
+/// @Returns the elements written, all of them.
+fn distribute<T, F>(v: &mut [T], buckets: usize, bucket: F) -> usize
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    // First free slot of every bucket, after counting how many fall below it.
+    let mut next = vec![0; buckets + 1];
+    v.iter().for_each(|x| next[bucket(x) + 1] += 1);
+    for i in 1..next.len() {
+        next[i] += next[i - 1];
+    }
+
+    // `v` gets overwritten while it's read, so read from a copy.
+    let elements = v.to_vec();
+    for x in elements {
+        let slot = &mut next[bucket(&x)];
+        v[*slot] = x;
+        *slot += 1;
+    }
+    v.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::SeededRandom;
+
+    use super::*;
+
+    #[test]
+    fn against_std_stable_sort() {
+        let mut random = SeededRandom::new(505);
+        for len in [0, 1, 10, 500] {
+            let input = (0..len)
+                .map(|i| (random.get_in_range(0, 50usize), i))
+                .collect::<Vec<_>>();
+            let mut expected = input.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            let mut v = input.clone();
+            let stats = counting_sort_by_key(&mut v, |&(key, _)| key);
+            assert_eq!(v, expected);
+            assert_eq!(
+                stats,
+                SortStats {
+                    writes: len,
+                    ..Default::default()
+                }
+            );
+
+            let mut v = input.clone();
+            radix_sort_by_key(&mut v, |&(key, _)| key as u64);
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn radix_wide_keys() {
+        let mut random = SeededRandom::new(506);
+        let mut v = random.get_vec::<u64>(1_000);
+        v.extend([0, u64::MAX, u64::MAX, 1 << 63]);
+        let mut expected = v.clone();
+        expected.sort_unstable();
+
+        radix_sort_by_key(&mut v, |&x| x);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn radix_skips_constant_bytes() {
+        // Only the second byte differs.
+        let mut v = (0..100u64).rev().map(|x| x << 8 | 0xab).collect::<Vec<_>>();
+        let stats = radix_sort_by_key(&mut v, |&x| x);
+        assert!(v.is_sorted());
+        assert_eq!(stats.writes, 100);
+    }
+}
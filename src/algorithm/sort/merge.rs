@@ -0,0 +1,225 @@
+use std::cmp::Ordering;
+
+use super::Counter;
+
+/// Shorter slices are one run, sorted by binary insertion.
+const MIN_MERGE: usize = 64;
+
+pub(super) fn merge_sort<T, F>(v: &mut [T], counter: &mut Counter<F>)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if v.len() <= 1 {
+        return;
+    }
+
+    let mid = v.len() / 2;
+    merge_sort(&mut v[..mid], counter);
+    merge_sort(&mut v[mid..], counter);
+    merge(v, mid, counter);
+}
+
+pub(super) fn tim_sort<T, F>(v: &mut [T], counter: &mut Counter<F>)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let min_run = min_run(v.len());
+    // `(start, len)` of runs waiting to be merged, bottom of the stack first.
+    let mut runs = vec![];
+
+    let mut start = 0;
+    while start < v.len() {
+        let mut end = start + run_length(&mut v[start..], counter);
+        let forced = v.len().min(start + min_run);
+        if end < forced {
+            binary_insertion(&mut v[start..forced], end - start, counter);
+            end = forced;
+        }
+
+        runs.push((start, end - start));
+        collapse(v, &mut runs, counter);
+        start = end;
+    }
+
+    while let Some(at) = runs.len().checked_sub(2) {
+        merge_at(v, &mut runs, at, counter);
+    }
+}
+
+/// `len` itself when short, otherwise between `MIN_MERGE / 2` and `MIN_MERGE`, so that
+/// `len / min_run` is a power of two or just below one and the final merges come out balanced.
+fn min_run(mut len: usize) -> usize {
+    let mut odd = 0;
+    while len >= MIN_MERGE {
+        odd |= len & 1;
+        len >>= 1;
+    }
+    len + odd
+}
+
+/// Length of the run `v` starts with. A strictly descending one is reversed in place,
+/// which can't reorder equal elements.
+fn run_length<T, F>(v: &mut [T], counter: &mut Counter<F>) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if v.len() < 2 {
+        return v.len();
+    }
+
+    let descending = counter.less(&v[1], &v[0]);
+    let mut end = 2;
+    while end < v.len() && counter.less(&v[end], &v[end - 1]) == descending {
+        end += 1;
+    }
+
+    if descending {
+        for i in 0..end / 2 {
+            counter.swap(v, i, end - 1 - i);
+        }
+    }
+    end
+}
+
+/// Sorts `v` whose first `sorted` elements already are. Each next one goes after
+/// everything no larger than it, found by binary search.
+fn binary_insertion<T, F>(v: &mut [T], sorted: usize, counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in sorted.max(1)..v.len() {
+        let (mut low, mut high) = (0, i);
+        while low < high {
+            let mid = (low + high) / 2;
+            match counter.less(&v[i], &v[mid]) {
+                true => high = mid,
+                false => low = mid + 1,
+            }
+        }
+        counter.rotate_right(&mut v[low..=i]);
+    }
+}
+
+/// Merges the top runs until, from the top, each run is shorter than the one below it
+/// and than the two below it together. Run lengths then grow at least like Fibonacci
+/// numbers, so the stack stays O(log n) deep.
+fn collapse<T, F>(v: &mut [T], runs: &mut Vec<(usize, usize)>, counter: &mut Counter<F>)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while runs.len() > 1 {
+        let n = runs.len();
+        let len = |i: usize| runs[i].1;
+
+        let broken_three = n >= 3 && len(n - 3) <= len(n - 2) + len(n - 1);
+        let broken_four = n >= 4 && len(n - 4) <= len(n - 3) + len(n - 2);
+        if broken_three || broken_four {
+            // The shorter neighbor of the middle run.
+            let at = match len(n - 3) < len(n - 1) {
+                true => n - 3,
+                false => n - 2,
+            };
+            merge_at(v, runs, at, counter);
+        } else if len(n - 2) <= len(n - 1) {
+            merge_at(v, runs, n - 2, counter);
+        } else {
+            break;
+        }
+    }
+}
+
+/// Merges the runs at `at` and `at + 1` of the stack.
+fn merge_at<T, F>(v: &mut [T], runs: &mut Vec<(usize, usize)>, at: usize, counter: &mut Counter<F>)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (start, left) = runs[at];
+    let (_, right) = runs.remove(at + 1);
+    merge(&mut v[start..start + left + right], left, counter);
+    runs[at].1 = left + right;
+}
+
+/// Merges the sorted `v[..mid]` and `v[mid..]`, taking the left one on ties so it's stable.
+/// Only the left half is copied out: what's left of the right half is already in place.
+fn merge<T, F>(v: &mut [T], mid: usize, counter: &mut Counter<F>)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let left = v[..mid].to_vec();
+    let (mut i, mut j) = (0, mid);
+
+    while i < left.len() {
+        let k = i + j - mid;
+        match j < v.len() && counter.less(&v[j], &left[i]) {
+            true => {
+                let value = v[j].clone();
+                counter.write(&mut v[k], value);
+                j += 1;
+            }
+            false => {
+                counter.write(&mut v[k], left[i].clone());
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithm::sort::Sort, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn min_run_range() {
+        assert_eq!(min_run(10), 10);
+        for len in [64, 100, 1_000, 4_096, 65_537] {
+            assert!((MIN_MERGE / 2..=MIN_MERGE).contains(&min_run(len)), "{len}");
+        }
+        assert_eq!(min_run(4_096), 32);
+    }
+
+    #[test]
+    fn tim_sort_uses_runs() {
+        // Alternating ascending and descending stretches of 500.
+        let mut random = SeededRandom::new(503);
+        let mut v = vec![];
+        for i in 0..20 {
+            let mut run = random.get_vec::<u32>(500);
+            match i % 2 {
+                0 => run.sort_unstable(),
+                _ => run.sort_unstable_by(|a, b| b.cmp(a)),
+            }
+            v.extend(run);
+        }
+        let mut expected = v.clone();
+        expected.sort_unstable();
+
+        let mut by_tim = v.clone();
+        let tim = Sort::Tim.sort(&mut by_tim);
+        let merge = Sort::Merge.sort(&mut v);
+        assert_eq!(by_tim, expected);
+        assert_eq!(v, expected);
+        // Finding the runs takes n - 1, merging 20 of them about n log2(20).
+        assert!(tim.comparisons < 10_000 * 6);
+        assert!(tim.comparisons < merge.comparisons);
+    }
+
+    #[test]
+    fn random_input_within_n_log_n() {
+        let mut random = SeededRandom::new(504);
+        let mut v = random.get_vec::<u16>(20_000);
+        let mut expected = v.clone();
+        expected.sort();
+
+        let stats = Sort::Tim.sort(&mut v);
+        assert_eq!(v, expected);
+        // log2(20 000) is a bit over 14.
+        assert!(stats.comparisons < 20_000 * 16);
+    }
+}
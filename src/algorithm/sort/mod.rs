@@ -0,0 +1,389 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::random::Random;
+
+mod heap;
+mod integer;
+mod merge;
+mod quick;
+mod simple;
+pub use integer::*;
+
+/// What a sort did to get there.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortStats {
+    /// Calls to the comparator.
+    pub comparisons: usize,
+    /// Two elements trading places.
+    pub swaps: usize,
+    /// Single elements written into place, by sorts that go through a buffer.
+    pub writes: usize,
+}
+
+impl Display for SortStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} comparisons, {} swaps, {} writes",
+            self.comparisons, self.swaps, self.writes
+        )
+    }
+}
+
+/// Comparator that keeps [`SortStats`]. Sorts compare, swap and write through it,
+/// plain reads, like merge sort copying its left run, aren't counted.
+struct Counter<F> {
+    compare: F,
+    stats: SortStats,
+}
+
+impl<F> Counter<F> {
+    fn new(compare: F) -> Self {
+        Self {
+            compare,
+            stats: SortStats::default(),
+        }
+    }
+
+    fn compare<T>(&mut self, a: &T, b: &T) -> Ordering
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.stats.comparisons += 1;
+        (self.compare)(a, b)
+    }
+
+    fn less<T>(&mut self, a: &T, b: &T) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.compare(a, b) == Ordering::Less
+    }
+
+    /// Swapping an element with itself is free.
+    fn swap<T>(&mut self, v: &mut [T], i: usize, j: usize) {
+        if i != j {
+            self.stats.swaps += 1;
+            v.swap(i, j);
+        }
+    }
+
+    fn write<T>(&mut self, slot: &mut T, value: T) {
+        self.stats.writes += 1;
+        *slot = value;
+    }
+
+    /// Last element to the front, every other one a step back.
+    fn rotate_right<T>(&mut self, v: &mut [T]) {
+        if v.len() > 1 {
+            self.stats.writes += v.len();
+            v.rotate_right(1);
+        }
+    }
+}
+
+/// How quicksort picks its pivot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pivot {
+    /// Quadratic on sorted input.
+    First,
+    Last,
+    Middle,
+    /// Of the first, middle and last elements.
+    #[default]
+    MedianOfThree,
+    /// From a generator with a fixed seed, so runs are reproducible.
+    Random,
+}
+
+/// Comparison sorts. Every one sorts a slice in place and reports [`SortStats`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sort {
+    /// Swaps each element back until it fits. O(n²), O(n) on sorted input.
+    Insertion,
+    /// Swaps the smallest remaining element to the front. O(n²) comparisons, at most n swaps.
+    Selection,
+    /// Top-down, merging through a copy of the left half. O(n log n).
+    /// Like [`Sort::Tim`], sorts indices and then swaps the elements into place.
+    Merge,
+    /// Hoare partitioning, recursing into the smaller side. O(n log n) expected.
+    Quick(Pivot),
+    /// Max-heap built in place, then emptied to the back. O(n log n).
+    Heap,
+    /// Insertion sort over shrinking gaps 1, 4, 13, 40, …. About O(n^1.5).
+    Shell,
+    /// Natural runs, short ones extended by binary insertion, merged while keeping
+    /// the run lengths balanced. O(n) on sorted or reversed input, O(n log n) at worst.
+    Tim,
+}
+
+impl Sort {
+    /// Every sort, quicksort with each pivot.
+    pub const ALL: [Sort; 11] = [
+        Sort::Insertion,
+        Sort::Selection,
+        Sort::Merge,
+        Sort::Quick(Pivot::First),
+        Sort::Quick(Pivot::Last),
+        Sort::Quick(Pivot::Middle),
+        Sort::Quick(Pivot::MedianOfThree),
+        Sort::Quick(Pivot::Random),
+        Sort::Heap,
+        Sort::Shell,
+        Sort::Tim,
+    ];
+
+    /// Whether equal elements keep their order.
+    pub fn is_stable(self) -> bool {
+        matches!(self, Sort::Insertion | Sort::Merge | Sort::Tim)
+    }
+
+    pub fn sort_by<T, F>(self, v: &mut [T], compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut counter = Counter::new(compare);
+        match self {
+            Sort::Insertion => simple::insertion(v, &mut counter),
+            Sort::Selection => simple::selection(v, &mut counter),
+            Sort::Merge | Sort::Tim => return self.sort_indices_by(v, counter.compare),
+            Sort::Quick(pivot) => quick::quick_sort(v, pivot, &mut counter),
+            Sort::Heap => heap::heap_sort(v, &mut counter),
+            Sort::Shell => simple::shell(v, &mut counter),
+        }
+        counter.stats
+    }
+
+    pub fn sort<T: Ord>(self, v: &mut [T]) -> SortStats {
+        self.sort_by(v, T::cmp)
+    }
+
+    pub fn sort_by_key<T, K, F>(self, v: &mut [T], mut f: F) -> SortStats
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(v, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Merging needs a buffer, so the merge-based sorts order indices into `v` instead of
+    /// elements, which would have to be `Clone`. The order is then applied with swaps.
+    fn sort_indices_by<T, F>(self, v: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut order = (0..v.len()).collect::<Vec<_>>();
+        let mut counter = Counter::new(|&a: &usize, &b: &usize| compare(&v[a], &v[b]));
+        match self {
+            Sort::Merge => merge::merge_sort(&mut order, &mut counter),
+            Sort::Tim => merge::tim_sort(&mut order, &mut counter),
+            _ => unreachable!("Only merge-based sorts go through indices"),
+        }
+
+        let mut stats = counter.stats;
+        stats.swaps += permute(v, &mut order);
+        stats
+    }
+}
+
+/// Rearranges `v` so that `v[i]` is what was at `order[i]`, a cycle of the permutation
+/// at a time. @Returns the swaps that took, `order` is left as the identity.
+fn permute<T>(v: &mut [T], order: &mut [usize]) -> usize {
+    let mut swaps = 0;
+    for start in 0..v.len() {
+        let mut i = start;
+        while order[i] != start {
+            let from = std::mem::replace(&mut order[i], i);
+            v.swap(i, from);
+            swaps += 1;
+            i = from;
+        }
+        order[i] = i;
+    }
+    swaps
+}
+
+impl Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sort::Quick(pivot) => write!(f, "Quick({pivot:?})"),
+            sort => write!(f, "{sort:?}"),
+        }
+    }
+}
+
+impl super::Algorithm for Sort {
+    fn showcase() -> Result<(), Box<dyn super::Error>> {
+        const LEN: usize = 1_000;
+
+        // `u16`, so counting sort gets by with 65 536 counters.
+        let random = Random::<u16>::get_vec(LEN);
+        let mut sorted = random.clone();
+        sorted.sort_unstable();
+        let reversed = sorted.iter().rev().copied().collect::<Vec<_>>();
+        let few_unique = Random::<u16>::get_vec(LEN)
+            .into_iter()
+            .map(|x| x % 8)
+            .collect::<Vec<_>>();
+        let inputs = [
+            ("random", random),
+            ("sorted", sorted),
+            ("reversed", reversed),
+            ("8 unique", few_unique),
+        ];
+
+        println!("{LEN} elements, comparisons / swaps + writes:");
+        print!("{:<20}", "");
+        inputs.iter().for_each(|(name, _)| print!("{name:>17}  "));
+        println!();
+
+        let row = |name: String, run: &dyn Fn(&mut Vec<u16>) -> SortStats| {
+            print!("{name:<20}");
+            for (_, input) in &inputs {
+                let mut v = input.clone();
+                let stats = run(&mut v);
+                debug_assert!(v.is_sorted());
+                print!(
+                    "{:>8} {:>8}  ",
+                    stats.comparisons,
+                    stats.swaps + stats.writes
+                );
+            }
+            println!();
+        };
+        for sort in Sort::ALL {
+            row(sort.to_string(), &|v| sort.sort(v));
+        }
+        row("Counting".to_string(), &|v| {
+            counting_sort_by_key(v, |&x| x as usize)
+        });
+        row("Radix".to_string(), &|v| {
+            radix_sort_by_key(v, |&x| x as u64)
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        Sort::showcase().unwrap();
+    }
+
+    fn random_inputs(random: &mut SeededRandom) -> Vec<Vec<u32>> {
+        let mut inputs = vec![vec![], vec![7], vec![2, 1], vec![3; 10]];
+        for len in [5, 17, 64, 100, 333] {
+            let few_unique = (0..len).map(|_| random.get_in_range(0, 4)).collect();
+            let mut sorted = random.get_vec::<u32>(len);
+            sorted.sort_unstable();
+            let reversed = sorted.iter().rev().copied().collect();
+
+            inputs.extend([random.get_vec(len), few_unique, sorted, reversed]);
+        }
+        inputs
+    }
+
+    #[test]
+    fn against_std() {
+        let mut random = SeededRandom::new(50);
+        for input in random_inputs(&mut random) {
+            let mut expected = input.clone();
+            expected.sort_unstable();
+
+            for sort in Sort::ALL {
+                let mut v = input.clone();
+                sort.sort(&mut v);
+                assert_eq!(v, expected, "{sort}");
+
+                let mut v = input.clone();
+                sort.sort_by(&mut v, |a, b| b.cmp(a));
+                assert!(v.iter().eq(expected.iter().rev()), "{sort} descending");
+            }
+        }
+    }
+
+    #[test]
+    fn stable_sorts_keep_ties_in_order() {
+        let mut random = SeededRandom::new(501);
+        let input = (0..300)
+            .map(|i| (random.get_in_range(0, 10u8), i))
+            .collect::<Vec<_>>();
+        let mut expected = input.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        for sort in Sort::ALL.into_iter().filter(|sort| sort.is_stable()) {
+            let mut v = input.clone();
+            sort.sort_by_key(&mut v, |&(key, _)| key);
+            assert_eq!(v, expected, "{sort}");
+        }
+    }
+
+    #[test]
+    fn sorts_without_clone() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Boxed(Box<u32>);
+
+        let mut random = SeededRandom::new(505);
+        let input = random.get_vec::<u32>(200);
+        let mut expected = input.clone();
+        expected.sort_unstable();
+
+        for sort in Sort::ALL {
+            let mut v = input
+                .iter()
+                .map(|&x| Boxed(Box::new(x)))
+                .collect::<Vec<_>>();
+            sort.sort(&mut v);
+            assert!(
+                v.iter().map(|b| *b.0).eq(expected.iter().copied()),
+                "{sort}"
+            );
+        }
+    }
+
+    #[test]
+    fn permute_applies_order() {
+        let mut v = vec!['a', 'b', 'c', 'd', 'e'];
+        let mut order = vec![3, 0, 4, 1, 2];
+        // Cycles (0 3 1) and (2 4).
+        assert_eq!(permute(&mut v, &mut order), 3);
+        assert_eq!(v, ['d', 'a', 'e', 'b', 'c']);
+        assert_eq!(order, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn stats() {
+        let sorted = (0..100).collect::<Vec<_>>();
+        let reversed = sorted.iter().rev().copied().collect::<Vec<_>>();
+
+        let stats = Sort::Insertion.sort(&mut sorted.clone());
+        assert_eq!(
+            stats,
+            SortStats {
+                comparisons: 99,
+                ..Default::default()
+            }
+        );
+        let stats = Sort::Insertion.sort(&mut reversed.clone());
+        assert_eq!(stats.swaps, 100 * 99 / 2);
+
+        let stats = Sort::Selection.sort(&mut reversed.clone());
+        assert_eq!(stats.comparisons, 100 * 99 / 2);
+        assert!(stats.swaps <= 99);
+
+        // One run each, found with n - 1 comparisons.
+        assert_eq!(Sort::Tim.sort(&mut sorted.clone()).comparisons, 99);
+        assert_eq!(Sort::Tim.sort(&mut reversed.clone()).comparisons, 99);
+
+        let mut random = SeededRandom::new(502);
+        let mut v = random.get_vec::<u32>(1024);
+        // At most n log n for merge sort.
+        assert!(Sort::Merge.sort(&mut v).comparisons <= 1024 * 10);
+    }
+}
@@ -0,0 +1,132 @@
+use std::cmp::Ordering;
+
+use crate::random::SeededRandom;
+
+use super::{Counter, Pivot};
+
+/// Fixed, so [`Pivot::Random`] picks the same pivots every run.
+const SEED: u64 = 50;
+
+pub(super) fn quick_sort<T, F>(v: &mut [T], pivot: Pivot, counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut random = SeededRandom::new(SEED);
+    sort(v, pivot, &mut random, counter);
+}
+
+/// Recurses into the smaller side and loops on the larger, so the stack stays O(log n)
+/// even when the pivots are bad.
+fn sort<T, F>(mut v: &mut [T], pivot: Pivot, random: &mut SeededRandom, counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while v.len() > 1 {
+        let at = choose_pivot(v, pivot, random, counter);
+        let mid = partition(v, at, counter);
+
+        let (left, right) = v.split_at_mut(mid);
+        let right = &mut right[1..];
+        match left.len() < right.len() {
+            true => {
+                sort(left, pivot, random, counter);
+                v = right;
+            }
+            false => {
+                sort(right, pivot, random, counter);
+                v = left;
+            }
+        }
+    }
+}
+
+fn choose_pivot<T, F>(
+    v: &mut [T],
+    pivot: Pivot,
+    random: &mut SeededRandom,
+    counter: &mut Counter<F>,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (first, middle, last) = (0, v.len() / 2, v.len() - 1);
+    match pivot {
+        Pivot::First => first,
+        Pivot::Last => last,
+        Pivot::Middle => middle,
+        Pivot::Random => random.get_in_range(0, v.len()),
+        Pivot::MedianOfThree => {
+            // Sorting the three in place also moves the largest out of the way,
+            // reversed input would otherwise leave it in the middle of the left side.
+            for (a, b) in [(first, middle), (middle, last), (first, middle)] {
+                if counter.less(&v[b], &v[a]) {
+                    counter.swap(v, a, b);
+                }
+            }
+            middle
+        }
+    }
+}
+
+/// Hoare partitioning around `v[at]`: elements equal to the pivot stop both scans and get
+/// swapped, so many duplicates still split evenly.
+/// @Returns where the pivot ended up, nothing larger before it and nothing smaller after.
+fn partition<T, F>(v: &mut [T], at: usize, counter: &mut Counter<F>) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    counter.swap(v, 0, at);
+
+    // `v[1..i]` are no larger than the pivot, `v[j + 1..]` no smaller.
+    let (mut i, mut j) = (1, v.len() - 1);
+    loop {
+        while i <= j && counter.less(&v[i], &v[0]) {
+            i += 1;
+        }
+        while i <= j && counter.less(&v[0], &v[j]) {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+
+        counter.swap(v, i, j);
+        i += 1;
+        j -= 1;
+    }
+
+    counter.swap(v, 0, j);
+    j
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::sort::Sort;
+
+    use super::*;
+
+    #[test]
+    fn pivot_strategies_on_sorted_input() {
+        let len = 1_000;
+        let sorted = (0..len).collect::<Vec<_>>();
+        let reversed = sorted.iter().rev().copied().collect::<Vec<_>>();
+
+        for input in [sorted, reversed] {
+            let comparisons = |pivot| Sort::Quick(pivot).sort(&mut input.clone()).comparisons;
+
+            // Partitions peel off little more than the pivot.
+            assert!(comparisons(Pivot::First) >= len * (len - 1) / 4);
+            assert!(comparisons(Pivot::Last) >= len * (len - 1) / 4);
+            for pivot in [Pivot::Middle, Pivot::MedianOfThree, Pivot::Random] {
+                assert!(comparisons(pivot) < 3 * len * 10, "{pivot:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn all_equal_splits_evenly() {
+        let mut v = vec![5; 4_096];
+        let stats = Sort::Quick(Pivot::First).sort(&mut v);
+        assert!(stats.comparisons < 4_096 * 12 * 2);
+    }
+}
@@ -0,0 +1,55 @@
+use std::cmp::Ordering;
+
+use super::Counter;
+
+pub(super) fn insertion<T, F>(v: &mut [T], counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    gapped_insertion(v, 1, counter);
+}
+
+pub(super) fn selection<T, F>(v: &mut [T], counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in 0..v.len() {
+        let mut min = i;
+        for j in i + 1..v.len() {
+            if counter.less(&v[j], &v[min]) {
+                min = j;
+            }
+        }
+        counter.swap(v, i, min);
+    }
+}
+
+/// Knuth's gaps `3h + 1`, largest below a third of the length first.
+pub(super) fn shell<T, F>(v: &mut [T], counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut gap = 1;
+    while gap < v.len() / 3 {
+        gap = 3 * gap + 1;
+    }
+
+    while gap > 0 {
+        gapped_insertion(v, gap, counter);
+        gap /= 3;
+    }
+}
+
+/// Insertion sort of every `gap`-th element, for each starting offset at once.
+fn gapped_insertion<T, F>(v: &mut [T], gap: usize, counter: &mut Counter<F>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in gap..v.len() {
+        let mut j = i;
+        while j >= gap && counter.less(&v[j], &v[j - gap]) {
+            counter.swap(v, j, j - gap);
+            j -= gap;
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::{Debug, Display};
+
+/// Value that could not be pushed is handed back.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StackError<T> {
+    Overflow(T),
+}
+
+impl<T> StackError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Overflow(value) => value,
+        }
+    }
+}
+
+impl<T: Debug> super::super::Error for StackError<T> {}
+impl<T: Debug> Display for StackError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
+
+/// Stack that never grows past `capacity`, the buffer is allocated upfront.
+pub struct BoundedStack<T> {
+    inner: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> BoundedStack<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// @If stack is full -> `Err(Overflow(value))`.
+    pub fn push(&mut self, value: T) -> Result<(), StackError<T>> {
+        if self.is_full() {
+            return Err(StackError::Overflow(value));
+        }
+
+        self.inner.push(value);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    pub fn size(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.inner.len() == self.capacity
+    }
+}
+
+impl super::super::Algorithm for BoundedStack<u32> {
+    fn showcase() -> Result<(), Box<dyn std::error::Error>> {
+        let mut stack = BoundedStack::<u32>::new(3);
+
+        for v in 1..=3 {
+            stack.push(v)?;
+        }
+        println!("Full: {}, peek = {:?}", stack.is_full(), stack.peek());
+
+        if let Err(e) = stack.push(4) {
+            println!("Push on full stack: {e}");
+        }
+
+        stack.pop();
+        stack.push(52)?;
+        println!("Popped and pushed again, peek = {:?}", stack.peek());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::Algorithm;
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        BoundedStack::showcase().unwrap();
+    }
+
+    #[test]
+    fn overflow() {
+        let mut stack = BoundedStack::new(2);
+
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+        assert_eq!(stack.push(3), Err(StackError::Overflow(3)));
+        assert_eq!(stack.size(), 2);
+        assert_eq!(stack.peek(), Some(&2));
+
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.push(3), Ok(()));
+        assert_eq!(stack.capacity(), 2);
+        // `Vec` may allocate more than asked, but never has to grow.
+        assert!(stack.inner.capacity() >= stack.capacity());
+
+        let mut zero = BoundedStack::new(0);
+        assert!(zero.is_full() && zero.is_empty());
+        assert_eq!(zero.push(String::from("1")).unwrap_err().into_inner(), "1");
+    }
+
+    #[test]
+    fn error_through_algorithm_error() {
+        fn fill(stack: &mut BoundedStack<u32>) -> Result<(), Box<dyn crate::algorithm::Error>> {
+            (0..10).try_for_each(|v| stack.push(v))?;
+            Ok(())
+        }
+
+        let err = fill(&mut BoundedStack::new(5)).unwrap_err();
+        assert_eq!(err.to_string(), "Overflow(5)");
+    }
+}
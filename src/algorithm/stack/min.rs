@@ -0,0 +1,140 @@
+struct Entry<T> {
+    value: T,
+    // Indices of the min / max among this entry and everything below it.
+    min: usize,
+    max: usize,
+}
+
+/// Stack that knows its minimum and maximum in O(1).
+pub struct MinStack<T> {
+    inner: Vec<Entry<T>>,
+}
+
+impl<T: Ord> MinStack<T> {
+    pub fn new() -> Self {
+        Self { inner: vec![] }
+    }
+
+    pub fn push(&mut self, value: T) {
+        let i = self.inner.len();
+        let (min, max) = match self.inner.last() {
+            None => (i, i),
+            Some(top) => (
+                match value < self.inner[top.min].value {
+                    true => i,
+                    false => top.min,
+                },
+                match value > self.inner[top.max].value {
+                    true => i,
+                    false => top.max,
+                },
+            ),
+        };
+
+        self.inner.push(Entry { value, min, max });
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop().map(|entry| entry.value)
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.inner.last().map(|entry| &entry.value)
+    }
+
+    /// On ties, the lowest one in the stack.
+    pub fn min(&self) -> Option<&T> {
+        let top = self.inner.last()?;
+        Some(&self.inner[top.min].value)
+    }
+
+    /// On ties, the lowest one in the stack.
+    pub fn max(&self) -> Option<&T> {
+        let top = self.inner.last()?;
+        Some(&self.inner[top.max].value)
+    }
+
+    pub fn size(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: Ord> Default for MinStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::super::Algorithm for MinStack<u32> {
+    fn showcase() -> Result<(), Box<dyn std::error::Error>> {
+        let mut stack = MinStack::<u32>::new();
+
+        for v in [52, 30, 727, 10, 144] {
+            stack.push(v);
+            println!(
+                "Pushed {v:>3}: min = {:?}, max = {:?}",
+                stack.min(),
+                stack.max()
+            );
+        }
+
+        while let Some(v) = stack.pop() {
+            println!(
+                "Popped {v:>3}: min = {:?}, max = {:?}",
+                stack.min(),
+                stack.max()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        MinStack::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_vec() {
+        let mut random = SeededRandom::new(39);
+        let mut stack = MinStack::new();
+        let mut model = Vec::new();
+
+        for _ in 0..5_000 {
+            if random.get_bool(0.55) {
+                let v = random.get_in_range(0, 100);
+                stack.push(v);
+                model.push(v);
+            } else {
+                assert_eq!(stack.pop(), model.pop());
+            }
+
+            assert_eq!(stack.peek(), model.last());
+            assert_eq!(stack.min(), model.iter().min());
+            assert_eq!(stack.max(), model.iter().max());
+            assert_eq!(stack.size(), model.len());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut stack = MinStack::<i32>::new();
+        assert!(stack.min().is_none() && stack.max().is_none());
+
+        stack.push(1);
+        stack.pop();
+        assert!(stack.is_empty());
+        assert!(stack.min().is_none());
+    }
+}
@@ -1,5 +1,12 @@
 use std::fmt::{Debug, Display};
 
+mod bounded;
+mod min;
+mod persistent;
+pub use bounded::*;
+pub use min::*;
+pub use persistent::*;
+
 pub struct Stack<T> {
     inner: Vec<T>,
 }
@@ -19,8 +26,9 @@ impl<T> Stack<T> {
         self.inner.pop()
     }
 
+    /// Top of the stack, i.e. the value `pop` would return.
     pub fn peek(&self) -> Option<&T> {
-        self.inner.first()
+        self.inner.last()
     }
 
     pub fn size(&self) -> usize {
@@ -85,7 +93,7 @@ mod tests {
         stack.push(20);
 
         assert_eq!(stack.size(), 2);
-        assert_eq!(stack.peek(), Some(&10));
+        assert_eq!(stack.peek(), Some(&20));
     }
 
     #[test]
@@ -113,6 +121,12 @@ mod tests {
         stack.push(10);
 
         assert_eq!(stack.peek(), Some(&10));
+
+        stack.push(20);
+        assert_eq!(stack.peek(), Some(&20));
+
+        stack.pop();
+        assert_eq!(stack.peek(), Some(&10));
     }
 
     #[test]
@@ -0,0 +1,186 @@
+use std::rc::Rc;
+
+struct Node<T> {
+    value: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+/// Immutable stack: `push` and `pop` return a new version and leave `self`
+/// untouched. Versions share their common tail, so each operation is O(1).
+pub struct PersistentStack<T> {
+    head: Option<Rc<Node<T>>>,
+    size: usize,
+}
+
+impl<T> PersistentStack<T> {
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            size: 0,
+        }
+    }
+
+    pub fn push(&self, value: T) -> Self {
+        Self {
+            head: Some(Rc::new(Node {
+                value,
+                next: self.head.clone(),
+            })),
+            size: self.size + 1,
+        }
+    }
+
+    /// @Returns version without the top, `None` if empty.
+    pub fn pop(&self) -> Option<Self> {
+        let head = self.head.as_ref()?;
+
+        Some(Self {
+            head: head.next.clone(),
+            size: self.size - 1,
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// From the top to the bottom.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.head.as_deref(), |node| node.next.as_deref())
+            .map(|node| &node.value)
+    }
+
+    /// Both versions share the same top node.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+// Shallow: the new version shares every node.
+impl<T> Clone for PersistentStack<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for PersistentStack<T> {
+    // Frees uniquely owned nodes one by one and stops at the first shared one,
+    // instead of recursing through `next` on long histories.
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Default for PersistentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::super::Algorithm for PersistentStack<u32> {
+    fn showcase() -> Result<(), Box<dyn std::error::Error>> {
+        // Undo history: every edit is a new version, old ones stay valid.
+        let mut history = vec![PersistentStack::<u32>::new()];
+        for v in [1, 2, 3] {
+            let next = history[history.len() - 1].push(v);
+            history.push(next);
+        }
+
+        let current = history[history.len() - 1].clone();
+        println!("Current: {:?}", current.iter().collect::<Vec<_>>());
+
+        let undone = &history[history.len() - 2];
+        println!("After undo: {:?}", undone.iter().collect::<Vec<_>>());
+
+        let branch = undone.push(52);
+        println!("New branch: {:?}", branch.iter().collect::<Vec<_>>());
+        println!(
+            "Branch shares its tail: {}",
+            branch.pop().is_some_and(|tail| tail.ptr_eq(undone))
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::Algorithm;
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        PersistentStack::showcase().unwrap();
+    }
+
+    #[test]
+    fn versions() {
+        let empty = PersistentStack::new();
+        let a = empty.push(1);
+        let b = a.push(2);
+        let c = a.push(3);
+
+        assert!(empty.is_empty());
+        assert_eq!(a.iter().collect::<Vec<_>>(), [&1]);
+        assert_eq!(b.iter().collect::<Vec<_>>(), [&2, &1]);
+        assert_eq!(c.iter().collect::<Vec<_>>(), [&3, &1]);
+        assert_eq!(c.size(), 2);
+
+        let popped = b.pop().unwrap();
+        assert!(popped.ptr_eq(&a));
+        assert_eq!(b.peek(), Some(&2));
+        assert!(empty.pop().is_none());
+    }
+
+    #[test]
+    fn structural_sharing() {
+        let value = Rc::new(());
+        let base = PersistentStack::new().push(value.clone());
+
+        let versions = (0..10)
+            .map(|_| base.push(value.clone()))
+            .collect::<Vec<_>>();
+        // 1 for `base` + 1 per version: the shared bottom isn't copied.
+        assert_eq!(Rc::strong_count(&value), 1 + 1 + 10);
+
+        drop(base);
+        assert_eq!(Rc::strong_count(&value), 1 + 1 + 10);
+        drop(versions);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn long_history_drop() {
+        let mut stack = PersistentStack::new();
+        for v in 0..1_000_000 {
+            stack = stack.push(v);
+        }
+        let old = stack.pop().unwrap();
+
+        drop(stack);
+        assert_eq!(old.size(), 999_999);
+        drop(old);
+    }
+}
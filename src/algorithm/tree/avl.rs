@@ -0,0 +1,382 @@
+use std::{cmp::Ordering, mem};
+
+use super::*;
+
+type Link<K, V> = Option<Box<AvlNode<K, V>>>;
+
+pub struct AvlNode<K, V> {
+    key: K,
+    value: V,
+    left: Link<K, V>,
+    right: Link<K, V>,
+    height: usize,
+    size: usize,
+}
+
+impl<K, V> TreeNode<K, V> for AvlNode<K, V> {
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn left(&self) -> Option<&Self> {
+        self.left.as_deref()
+    }
+
+    fn right(&self) -> Option<&Self> {
+        self.right.as_deref()
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn tag(&self) -> String {
+        format!("[h={}]", self.height)
+    }
+}
+
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn update<K, V>(node: &mut AvlNode<K, V>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+fn rotate_right<K, V>(mut node: Box<AvlNode<K, V>>) -> Box<AvlNode<K, V>> {
+    let mut left = node.left.take().expect("Rotating right needs a left child");
+    node.left = left.right.take();
+    update(&mut node);
+
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+fn rotate_left<K, V>(mut node: Box<AvlNode<K, V>>) -> Box<AvlNode<K, V>> {
+    let mut right = node
+        .right
+        .take()
+        .expect("Rotating left needs a right child");
+    node.right = right.left.take();
+    update(&mut node);
+
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+/// Restores |height(left) - height(right)| <= 1 with at most two rotations.
+fn rebalance<K, V>(mut node: Box<AvlNode<K, V>>) -> Box<AvlNode<K, V>> {
+    update(&mut node);
+    let (left, right) = (height(&node.left), height(&node.right));
+
+    if left > right + 1 {
+        // Left-right case -> left-left case.
+        if let Some(child) = node.left.take() {
+            node.left = Some(match height(&child.left) < height(&child.right) {
+                true => rotate_left(child),
+                false => child,
+            });
+        }
+        return rotate_right(node);
+    }
+    if right > left + 1 {
+        if let Some(child) = node.right.take() {
+            node.right = Some(match height(&child.right) < height(&child.left) {
+                true => rotate_right(child),
+                false => child,
+            });
+        }
+        return rotate_left(node);
+    }
+
+    node
+}
+
+fn insert<K: Ord, V>(link: Link<K, V>, key: K, value: V) -> (Box<AvlNode<K, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        let leaf = AvlNode {
+            key,
+            value,
+            left: None,
+            right: None,
+            height: 1,
+            size: 1,
+        };
+        return (Box::new(leaf), None);
+    };
+
+    let old = match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), key, value);
+            node.left = Some(left);
+            old
+        }
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), key, value);
+            node.right = Some(right);
+            old
+        }
+        Ordering::Equal => {
+            let old = mem::replace(&mut node.value, value);
+            return (node, Some(old));
+        }
+    };
+
+    (rebalance(node), old)
+}
+
+/// @Returns the subtree without its minimum, and the detached minimum.
+fn remove_min<K, V>(mut node: Box<AvlNode<K, V>>) -> (Link<K, V>, Box<AvlNode<K, V>>) {
+    match node.left.take() {
+        None => (node.right.take(), node),
+        Some(left) => {
+            let (left, min) = remove_min(left);
+            node.left = left;
+            (Some(rebalance(node)), min)
+        }
+    }
+}
+
+fn remove<K: Ord, V>(link: Link<K, V>, key: &K) -> (Link<K, V>, Option<V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    let removed = match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), key);
+            node.left = left;
+            removed
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), key);
+            node.right = right;
+            removed
+        }
+        // The successor (minimum of the right subtree) takes the node's place.
+        Ordering::Equal => {
+            let AvlNode {
+                left, right, value, ..
+            } = *node;
+
+            return match (left, right) {
+                (None, child) | (child, None) => (child, Some(value)),
+                (Some(left), Some(right)) => {
+                    let (right, mut successor) = remove_min(right);
+                    successor.left = Some(left);
+                    successor.right = right;
+                    (Some(rebalance(successor)), Some(value))
+                }
+            };
+        }
+    };
+
+    (Some(rebalance(node)), removed)
+}
+
+/// Ordered map over an AVL tree: subtree heights differ by at most 1.
+pub struct AvlMap<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K: Ord, V> SearchTree<K, V> for AvlMap<K, V> {
+    type Node = AvlNode<K, V>;
+
+    fn root(&self) -> Option<&Self::Node> {
+        self.root.as_deref()
+    }
+}
+
+impl<K: Ord, V> AvlMap<K, V> {
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// @Returns previous value of `key`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (root, old) = insert(self.root.take(), key, value);
+        self.root = Some(root);
+        old
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (root, removed) = remove(self.root.take(), key);
+        self.root = root;
+        removed
+    }
+
+    /// BST order, sizes, stored heights and the AVL balance condition.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        fn check<K, V>(link: &Link<K, V>) -> Result<usize, String> {
+            let Some(node) = link else {
+                return Ok(0);
+            };
+
+            let (left, right) = (check(&node.left)?, check(&node.right)?);
+            if left.abs_diff(right) > 1 {
+                return Err(format!("Unbalanced node: heights {left} and {right}"));
+            }
+            if node.height != 1 + left.max(right) {
+                return Err(format!("Stored height {} is stale", node.height));
+            }
+
+            Ok(node.height)
+        }
+
+        self.check_order()?;
+        check(&self.root).map(|_| ())
+    }
+}
+
+impl<K: Ord, V> Default for AvlMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AvlMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        iter.into_iter().for_each(|(key, value)| {
+            map.insert(key, value);
+        });
+        map
+    }
+}
+
+impl super::super::Algorithm for AvlMap<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        // Ascending inserts: a plain BST would degrade into a list.
+        let mut map = (1..=10).map(|k| (k, k * k)).collect::<AvlMap<u32, u32>>();
+        println!("After inserting 1..=10:\n{}", map.shape());
+
+        map.remove(&4);
+        map.remove(&8);
+        println!("After removing 4 and 8:\n{}", map.shape());
+
+        println!("get(5) = {:?}", map.get(&5));
+        println!(
+            "floor(4) = {:?}, ceiling(8) = {:?}",
+            map.floor(&4),
+            map.ceiling(&8)
+        );
+        println!(
+            "rank(7) = {}, select(2) = {:?}",
+            map.rank(&7),
+            map.select(2)
+        );
+        println!("range(3..=7) = {:?}", map.range(3..=7).collect::<Vec<_>>());
+        println!(
+            "pre-order keys = {:?}",
+            map.pre_order().map(|(k, _)| k).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        AvlMap::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_btree_map() {
+        let mut random = SeededRandom::new(41);
+        let mut map = AvlMap::new();
+        let mut reference = BTreeMap::new();
+
+        for i in 0..3_000 {
+            let key = random.get_in_range(0, 300u32);
+            match random.get_bool(0.6) {
+                true => assert_eq!(map.insert(key, i), reference.insert(key, i)),
+                false => assert_eq!(map.remove(&key), reference.remove(&key)),
+            }
+
+            map.check_invariants().unwrap();
+            assert_eq!(map.len(), reference.len());
+            assert_eq!(map.get(&key), reference.get(&key));
+        }
+
+        assert!(map.iter().eq(reference.iter()));
+        // 1.44 * log2(n + 2) bound for AVL trees.
+        assert!(map.height() as f64 <= 1.45 * ((map.len() + 2) as f64).log2());
+    }
+
+    #[test]
+    fn order_statistics() {
+        let mut random = SeededRandom::new(14);
+        let map = (0..200)
+            .map(|_| (random.get_in_range(0, 1_000u32), ()))
+            .collect::<AvlMap<_, _>>();
+        let keys = map.iter().map(|(&k, _)| k).collect::<Vec<_>>();
+
+        for probe in 0..1_000 {
+            let rank = keys.partition_point(|&k| k < probe);
+            assert_eq!(map.rank(&probe), rank);
+
+            let floor = keys[..keys.partition_point(|&k| k <= probe)].last();
+            assert_eq!(map.floor(&probe).map(|(k, _)| k), floor);
+            assert_eq!(map.ceiling(&probe).map(|(k, _)| k), keys.get(rank));
+        }
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(map.select(i).map(|(k, _)| k), Some(key));
+        }
+        assert!(map.select(keys.len()).is_none());
+        assert_eq!(map.min().map(|(k, _)| k), keys.first());
+        assert_eq!(map.max().map(|(k, _)| k), keys.last());
+    }
+
+    #[test]
+    fn traversals() {
+        //     2
+        //   1   4
+        //      3 5
+        let map = [2, 1, 4, 3, 5]
+            .into_iter()
+            .map(|k| (k, ()))
+            .collect::<AvlMap<u32, ()>>();
+        let keys =
+            |iter: &mut dyn Iterator<Item = (&u32, &())>| iter.map(|(&k, _)| k).collect::<Vec<_>>();
+
+        assert_eq!(keys(&mut map.iter()), [1, 2, 3, 4, 5]);
+        assert_eq!(keys(&mut map.pre_order()), [2, 1, 4, 3, 5]);
+        assert_eq!(keys(&mut map.post_order()), [1, 3, 5, 4, 2]);
+        assert_eq!(
+            map.shape(),
+            "2 [h=3]\n├── L 1 [h=1]\n└── R 4 [h=2]\n    ├── L 3 [h=1]\n    └── R 5 [h=1]\n"
+        );
+    }
+
+    #[test]
+    fn range() {
+        let map = (0..20).map(|k| (k * 2, ())).collect::<AvlMap<u32, ()>>();
+        let reference = (0..20).map(|k| (k * 2, ())).collect::<BTreeMap<u32, ()>>();
+
+        for (start, end) in [(0, 40), (3, 9), (4, 10), (39, 50), (11, 11)] {
+            assert!(map.range(start..end).eq(reference.range(start..end)));
+            assert!(map.range(start..=end).eq(reference.range(start..=end)));
+        }
+        assert!(map.range(..7).eq(reference.range(..7)));
+        assert!(map.range(30..).eq(reference.range(30..)));
+    }
+}
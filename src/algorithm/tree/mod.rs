@@ -0,0 +1,398 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Write},
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+mod avl;
+mod red_black;
+pub use avl::*;
+pub use red_black::*;
+
+/// Read access to a node of a binary search tree.
+pub trait TreeNode<K, V> {
+    fn key(&self) -> &K;
+    fn value(&self) -> &V;
+    fn left(&self) -> Option<&Self>;
+    fn right(&self) -> Option<&Self>;
+    /// Number of nodes in the subtree rooted here.
+    fn size(&self) -> usize;
+    /// Balancing data printed next to the key by [`SearchTree::shape`].
+    fn tag(&self) -> String;
+}
+
+fn entry<K, V, N: TreeNode<K, V>>(node: &N) -> (&K, &V) {
+    (node.key(), node.value())
+}
+
+/// Everything that only reads the tree, shared by every balanced map.
+/// Each map only has to expose its `root`.
+///
+/// Methods handing out borrows spell out `Self::Node: 'a`: unlike `Self`,
+/// the associated type is not known to outlive `&self`.
+pub trait SearchTree<K: Ord, V> {
+    type Node: TreeNode<K, V>;
+
+    fn root(&self) -> Option<&Self::Node>;
+
+    fn len(&self) -> usize {
+        self.root().map_or(0, TreeNode::size)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root().is_none()
+    }
+
+    fn get<'a>(&'a self, key: &K) -> Option<&'a V>
+    where
+        Self::Node: 'a,
+    {
+        let mut node = self.root();
+        while let Some(current) = node {
+            node = match key.cmp(current.key()) {
+                Ordering::Less => current.left(),
+                Ordering::Greater => current.right(),
+                Ordering::Equal => return Some(current.value()),
+            };
+        }
+
+        None
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn min<'a>(&'a self) -> Option<(&'a K, &'a V)>
+    where
+        Self::Node: 'a,
+    {
+        let mut node = self.root()?;
+        while let Some(left) = node.left() {
+            node = left;
+        }
+
+        Some(entry(node))
+    }
+
+    fn max<'a>(&'a self) -> Option<(&'a K, &'a V)>
+    where
+        Self::Node: 'a,
+    {
+        let mut node = self.root()?;
+        while let Some(right) = node.right() {
+            node = right;
+        }
+
+        Some(entry(node))
+    }
+
+    /// Greatest key <= `key`.
+    fn floor<'a>(&'a self, key: &K) -> Option<(&'a K, &'a V)>
+    where
+        Self::Node: 'a,
+    {
+        let (mut node, mut floor) = (self.root(), None);
+        while let Some(current) = node {
+            node = match key.cmp(current.key()) {
+                Ordering::Less => current.left(),
+                Ordering::Equal => return Some(entry(current)),
+                Ordering::Greater => {
+                    floor = Some(current);
+                    current.right()
+                }
+            };
+        }
+
+        floor.map(entry)
+    }
+
+    /// Least key >= `key`.
+    fn ceiling<'a>(&'a self, key: &K) -> Option<(&'a K, &'a V)>
+    where
+        Self::Node: 'a,
+    {
+        let (mut node, mut ceiling) = (self.root(), None);
+        while let Some(current) = node {
+            node = match key.cmp(current.key()) {
+                Ordering::Greater => current.right(),
+                Ordering::Equal => return Some(entry(current)),
+                Ordering::Less => {
+                    ceiling = Some(current);
+                    current.left()
+                }
+            };
+        }
+
+        ceiling.map(entry)
+    }
+
+    /// Number of keys < `key`. O(height).
+    fn rank(&self, key: &K) -> usize {
+        let (mut node, mut rank) = (self.root(), 0);
+        while let Some(current) = node {
+            let left_size = current.left().map_or(0, TreeNode::size);
+            node = match key.cmp(current.key()) {
+                Ordering::Less => current.left(),
+                Ordering::Equal => return rank + left_size,
+                Ordering::Greater => {
+                    rank += left_size + 1;
+                    current.right()
+                }
+            };
+        }
+
+        rank
+    }
+
+    /// Entry with `rank` smaller keys, i.e. the `rank`-th from 0. O(height).
+    fn select<'a>(&'a self, mut rank: usize) -> Option<(&'a K, &'a V)>
+    where
+        Self::Node: 'a,
+    {
+        let mut node = self.root();
+        while let Some(current) = node {
+            let left_size = current.left().map_or(0, TreeNode::size);
+            node = match rank.cmp(&left_size) {
+                Ordering::Less => current.left(),
+                Ordering::Equal => return Some(entry(current)),
+                Ordering::Greater => {
+                    rank -= left_size + 1;
+                    current.right()
+                }
+            };
+        }
+
+        None
+    }
+
+    /// Number of nodes on the longest root-to-leaf path.
+    fn height(&self) -> usize {
+        fn height<K, V, N: TreeNode<K, V>>(node: Option<&N>) -> usize {
+            node.map_or(0, |node| 1 + height(node.left()).max(height(node.right())))
+        }
+
+        height(self.root())
+    }
+
+    /// Ascending by key.
+    fn iter(&self) -> InOrder<'_, K, V, Self::Node> {
+        let mut iter = InOrder {
+            stack: vec![],
+            _marker: PhantomData,
+        };
+        iter.push_left_spine(self.root());
+        iter
+    }
+
+    /// Node, then its left subtree, then its right subtree.
+    fn pre_order(&self) -> PreOrder<'_, K, V, Self::Node> {
+        PreOrder {
+            stack: self.root().into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Left subtree, right subtree, then the node itself.
+    fn post_order(&self) -> PostOrder<'_, K, V, Self::Node> {
+        PostOrder {
+            stack: self.root().map(|root| (root, false)).into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Ascending entries with keys in `range`. O(height + entries).
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V, Self::Node, R> {
+        let mut stack = vec![];
+        let mut node = self.root();
+        while let Some(current) = node {
+            node = match is_above_start(&range, current.key()) {
+                true => {
+                    stack.push(current);
+                    current.left()
+                }
+                false => current.right(),
+            };
+        }
+
+        Range {
+            inner: InOrder {
+                stack,
+                _marker: PhantomData,
+            },
+            range,
+        }
+    }
+
+    /// Tree drawn top-down, each node as `key [tag]`, `·` for an empty child.
+    fn shape(&self) -> String
+    where
+        K: Display,
+    {
+        fn write_node<K: Display, V, N: TreeNode<K, V>>(
+            out: &mut String,
+            node: &N,
+            prefix: &str,
+            connector: &str,
+        ) {
+            let _ = writeln!(out, "{prefix}{connector}{} {}", node.key(), node.tag());
+            if node.left().is_none() && node.right().is_none() {
+                return;
+            }
+
+            let prefix = match connector {
+                "├── L " => format!("{prefix}│   "),
+                "└── R " => format!("{prefix}    "),
+                _ => prefix.to_string(),
+            };
+            for (connector, child) in [("├── L ", node.left()), ("└── R ", node.right())]
+            {
+                match child {
+                    Some(child) => write_node(out, child, &prefix, connector),
+                    None => {
+                        let _ = writeln!(out, "{prefix}{connector}·");
+                    }
+                }
+            }
+        }
+
+        let mut out = String::new();
+        match self.root() {
+            Some(root) => write_node(&mut out, root, "", ""),
+            None => out.push_str("·\n"),
+        }
+        out
+    }
+
+    /// Keys are strictly ascending and every `size` is correct.
+    fn check_order(&self) -> Result<(), String> {
+        fn check<K: Ord, V, N: TreeNode<K, V>>(
+            node: Option<&N>,
+            low: Option<&K>,
+            high: Option<&K>,
+        ) -> Result<usize, String> {
+            let Some(node) = node else {
+                return Ok(0);
+            };
+
+            if low.is_some_and(|low| low >= node.key())
+                || high.is_some_and(|high| high <= node.key())
+            {
+                return Err("Keys are out of order".to_string());
+            }
+
+            let size = 1
+                + check(node.left(), low, Some(node.key()))?
+                + check(node.right(), Some(node.key()), high)?;
+            match size == node.size() {
+                true => Ok(size),
+                false => Err(format!("Size is {}, should be {size}", node.size())),
+            }
+        }
+
+        check(self.root(), None, None).map(|_| ())
+    }
+}
+
+fn is_above_start<K: Ord, R: RangeBounds<K>>(range: &R, key: &K) -> bool {
+    match range.start_bound() {
+        Bound::Included(start) => key >= start,
+        Bound::Excluded(start) => key > start,
+        Bound::Unbounded => true,
+    }
+}
+
+fn is_below_end<K: Ord, R: RangeBounds<K>>(range: &R, key: &K) -> bool {
+    match range.end_bound() {
+        Bound::Included(end) => key <= end,
+        Bound::Excluded(end) => key < end,
+        Bound::Unbounded => true,
+    }
+}
+
+pub struct InOrder<'a, K, V, N> {
+    stack: Vec<&'a N>,
+    _marker: PhantomData<(&'a K, &'a V)>,
+}
+
+impl<'a, K, V, N: TreeNode<K, V>> InOrder<'a, K, V, N> {
+    fn push_left_spine(&mut self, mut node: Option<&'a N>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left();
+        }
+    }
+}
+
+impl<'a, K: 'a, V: 'a, N: TreeNode<K, V>> Iterator for InOrder<'a, K, V, N> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right());
+
+        Some(entry(node))
+    }
+}
+
+pub struct PreOrder<'a, K, V, N> {
+    stack: Vec<&'a N>,
+    _marker: PhantomData<(&'a K, &'a V)>,
+}
+
+impl<'a, K: 'a, V: 'a, N: TreeNode<K, V>> Iterator for PreOrder<'a, K, V, N> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.right());
+        self.stack.extend(node.left());
+
+        Some(entry(node))
+    }
+}
+
+pub struct PostOrder<'a, K, V, N> {
+    // `true` once the node's children are already on the stack.
+    stack: Vec<(&'a N, bool)>,
+    _marker: PhantomData<(&'a K, &'a V)>,
+}
+
+impl<'a, K: 'a, V: 'a, N: TreeNode<K, V>> Iterator for PostOrder<'a, K, V, N> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some(entry(node));
+            }
+
+            self.stack.push((node, true));
+            self.stack.extend(node.right().map(|right| (right, false)));
+            self.stack.extend(node.left().map(|left| (left, false)));
+        }
+    }
+}
+
+pub struct Range<'a, K, V, N, R> {
+    inner: InOrder<'a, K, V, N>,
+    range: R,
+}
+
+impl<'a, K: Ord + 'a, V: 'a, N: TreeNode<K, V>, R: RangeBounds<K>> Iterator
+    for Range<'a, K, V, N, R>
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.inner.next()?;
+        if !is_below_end(&self.range, key) {
+            self.inner.stack.clear();
+            return None;
+        }
+
+        Some((key, value))
+    }
+}
@@ -0,0 +1,446 @@
+use std::{cmp::Ordering, mem};
+
+use super::*;
+
+type Link<K, V> = Option<Box<RedBlackNode<K, V>>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+impl Color {
+    fn flip(self) -> Self {
+        match self {
+            Self::Red => Self::Black,
+            Self::Black => Self::Red,
+        }
+    }
+}
+
+/// `color` is the color of the link from the parent.
+pub struct RedBlackNode<K, V> {
+    key: K,
+    value: V,
+    left: Link<K, V>,
+    right: Link<K, V>,
+    color: Color,
+    size: usize,
+}
+
+impl<K, V> TreeNode<K, V> for RedBlackNode<K, V> {
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn left(&self) -> Option<&Self> {
+        self.left.as_deref()
+    }
+
+    fn right(&self) -> Option<&Self> {
+        self.right.as_deref()
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn tag(&self) -> String {
+        match self.color {
+            Color::Red => "[R]",
+            Color::Black => "[B]",
+        }
+        .to_string()
+    }
+}
+
+fn is_red<K, V>(link: &Link<K, V>) -> bool {
+    link.as_ref().is_some_and(|node| node.color == Color::Red)
+}
+
+fn is_left_left_red<K, V>(link: &Link<K, V>) -> bool {
+    link.as_ref().is_some_and(|node| is_red(&node.left))
+}
+
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn update<K, V>(node: &mut RedBlackNode<K, V>) {
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+fn rotate_left<K, V>(mut node: Box<RedBlackNode<K, V>>) -> Box<RedBlackNode<K, V>> {
+    let mut right = node
+        .right
+        .take()
+        .expect("Rotating left needs a right child");
+    node.right = right.left.take();
+    right.color = node.color;
+    node.color = Color::Red;
+    update(&mut node);
+
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+fn rotate_right<K, V>(mut node: Box<RedBlackNode<K, V>>) -> Box<RedBlackNode<K, V>> {
+    let mut left = node.left.take().expect("Rotating right needs a left child");
+    node.left = left.right.take();
+    left.color = node.color;
+    node.color = Color::Red;
+    update(&mut node);
+
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+fn flip_colors<K, V>(node: &mut RedBlackNode<K, V>) {
+    node.color = node.color.flip();
+    for child in [&mut node.left, &mut node.right].into_iter().flatten() {
+        child.color = child.color.flip();
+    }
+}
+
+/// Fixes red right links and 4-nodes on the way up.
+fn balance<K, V>(mut node: Box<RedBlackNode<K, V>>) -> Box<RedBlackNode<K, V>> {
+    if is_red(&node.right) && !is_red(&node.left) {
+        node = rotate_left(node);
+    }
+    if is_red(&node.left) && is_left_left_red(&node.left) {
+        node = rotate_right(node);
+    }
+    if is_red(&node.left) && is_red(&node.right) {
+        flip_colors(&mut node);
+    }
+
+    update(&mut node);
+    node
+}
+
+/// Makes `node.left` or one of its children red, so deleting from the left keeps black balance.
+fn move_red_left<K, V>(mut node: Box<RedBlackNode<K, V>>) -> Box<RedBlackNode<K, V>> {
+    flip_colors(&mut node);
+    if is_left_left_red(&node.right) {
+        node.right = node.right.take().map(rotate_right);
+        node = rotate_left(node);
+        flip_colors(&mut node);
+    }
+    node
+}
+
+fn move_red_right<K, V>(mut node: Box<RedBlackNode<K, V>>) -> Box<RedBlackNode<K, V>> {
+    flip_colors(&mut node);
+    if is_left_left_red(&node.left) {
+        node = rotate_right(node);
+        flip_colors(&mut node);
+    }
+    node
+}
+
+fn insert<K: Ord, V>(link: Link<K, V>, key: K, value: V) -> (Box<RedBlackNode<K, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        let leaf = RedBlackNode {
+            key,
+            value,
+            left: None,
+            right: None,
+            color: Color::Red,
+            size: 1,
+        };
+        return (Box::new(leaf), None);
+    };
+
+    let old = match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), key, value);
+            node.left = Some(left);
+            old
+        }
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), key, value);
+            node.right = Some(right);
+            old
+        }
+        Ordering::Equal => {
+            let old = mem::replace(&mut node.value, value);
+            return (node, Some(old));
+        }
+    };
+
+    (balance(node), old)
+}
+
+/// @Returns the subtree without its minimum, and the detached minimum.
+fn remove_min<K, V>(mut node: Box<RedBlackNode<K, V>>) -> (Link<K, V>, Box<RedBlackNode<K, V>>) {
+    // Left-leaning: a node without a left child has no right child either.
+    if node.left.is_none() {
+        return (None, node);
+    }
+
+    if !is_red(&node.left) && !is_left_left_red(&node.left) {
+        node = move_red_left(node);
+    }
+    let (left, min) = remove_min(node.left.take().expect("Checked above"));
+    node.left = left;
+
+    (Some(balance(node)), min)
+}
+
+/// `key` must be present in the subtree.
+fn remove<K: Ord, V>(mut node: Box<RedBlackNode<K, V>>, key: &K) -> (Link<K, V>, V) {
+    let removed;
+
+    if key < &node.key {
+        if !is_red(&node.left) && !is_left_left_red(&node.left) {
+            node = move_red_left(node);
+        }
+        let left = node.left.take().expect("Key is in the left subtree");
+        let (left, value) = remove(left, key);
+        node.left = left;
+        removed = value;
+    } else {
+        if is_red(&node.left) {
+            node = rotate_right(node);
+        }
+        if key == &node.key && node.right.is_none() {
+            return (None, node.value);
+        }
+        if !is_red(&node.right) && !is_left_left_red(&node.right) {
+            node = move_red_right(node);
+        }
+
+        let right = node.right.take().expect("Key is in the right subtree");
+        match key == &node.key {
+            // The successor (minimum of the right subtree) takes the node's place.
+            true => {
+                let (right, mut successor) = remove_min(right);
+                mem::swap(&mut node.key, &mut successor.key);
+                mem::swap(&mut node.value, &mut successor.value);
+                node.right = right;
+                removed = successor.value;
+            }
+            false => {
+                let (right, value) = remove(right, key);
+                node.right = right;
+                removed = value;
+            }
+        }
+    }
+
+    (Some(balance(node)), removed)
+}
+
+/// Ordered map over a left-leaning red-black tree (Sedgewick):
+/// red links lean left, no two reds in a row, every path has the same number of blacks.
+pub struct RedBlackMap<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K: Ord, V> SearchTree<K, V> for RedBlackMap<K, V> {
+    type Node = RedBlackNode<K, V>;
+
+    fn root(&self) -> Option<&Self::Node> {
+        self.root.as_deref()
+    }
+}
+
+impl<K: Ord, V> RedBlackMap<K, V> {
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// @Returns previous value of `key`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (mut root, old) = insert(self.root.take(), key, value);
+        root.color = Color::Black;
+        self.root = Some(root);
+        old
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.contains_key(key) {
+            return None;
+        }
+
+        let mut root = self.root.take().expect("Map contains `key`");
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = Color::Red;
+        }
+
+        let (root, removed) = remove(root, key);
+        self.root = root;
+        if let Some(root) = &mut self.root {
+            root.color = Color::Black;
+        }
+
+        Some(removed)
+    }
+
+    /// BST order, sizes and the left-leaning red-black rules.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        // @Returns black height.
+        fn check<K, V>(link: &Link<K, V>) -> Result<usize, String> {
+            let Some(node) = link else {
+                return Ok(1);
+            };
+
+            if is_red(&node.right) {
+                return Err("Red right link".to_string());
+            }
+            if node.color == Color::Red && is_red(&node.left) {
+                return Err("Two red links in a row".to_string());
+            }
+
+            let (left, right) = (check(&node.left)?, check(&node.right)?);
+            if left != right {
+                return Err(format!("Black heights differ: {left} and {right}"));
+            }
+
+            Ok(left + usize::from(node.color == Color::Black))
+        }
+
+        if is_red(&self.root) {
+            return Err("Red root".to_string());
+        }
+
+        self.check_order()?;
+        check(&self.root).map(|_| ())
+    }
+}
+
+impl<K: Ord, V> Default for RedBlackMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for RedBlackMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        iter.into_iter().for_each(|(key, value)| {
+            map.insert(key, value);
+        });
+        map
+    }
+}
+
+impl super::super::Algorithm for RedBlackMap<u32, u32> {
+    fn showcase() -> Result<(), Box<dyn super::super::Error>> {
+        let mut map = (1..=10)
+            .map(|k| (k, k * k))
+            .collect::<RedBlackMap<u32, u32>>();
+        println!("After inserting 1..=10:\n{}", map.shape());
+
+        map.remove(&4);
+        map.remove(&8);
+        println!("After removing 4 and 8:\n{}", map.shape());
+
+        println!("get(5) = {:?}", map.get(&5));
+        println!(
+            "floor(4) = {:?}, ceiling(8) = {:?}",
+            map.floor(&4),
+            map.ceiling(&8)
+        );
+        println!(
+            "rank(7) = {}, select(2) = {:?}",
+            map.rank(&7),
+            map.select(2)
+        );
+        println!("range(3..=7) = {:?}", map.range(3..=7).collect::<Vec<_>>());
+        println!(
+            "post-order keys = {:?}",
+            map.post_order().map(|(k, _)| k).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{algorithm::Algorithm, random::SeededRandom};
+
+    use super::*;
+
+    #[test]
+    fn showcase() {
+        RedBlackMap::showcase().unwrap();
+    }
+
+    #[test]
+    fn against_btree_map() {
+        let mut random = SeededRandom::new(410);
+        let mut map = RedBlackMap::new();
+        let mut reference = BTreeMap::new();
+
+        for i in 0..3_000 {
+            let key = random.get_in_range(0, 300u32);
+            match random.get_bool(0.6) {
+                true => assert_eq!(map.insert(key, i), reference.insert(key, i)),
+                false => assert_eq!(map.remove(&key), reference.remove(&key)),
+            }
+
+            map.check_invariants().unwrap();
+            assert_eq!(map.len(), reference.len());
+            assert_eq!(map.get(&key), reference.get(&key));
+        }
+
+        assert!(map.iter().eq(reference.iter()));
+        // 2 * log2(n + 1) bound for red-black trees.
+        assert!(map.height() as f64 <= 2. * ((map.len() + 1) as f64).log2());
+    }
+
+    #[test]
+    fn drain_in_every_order() {
+        for keys in [
+            (0..64).collect::<Vec<u32>>(),
+            (0..64).rev().collect(),
+            (0..64).map(|k| (k * 37) % 64).collect(),
+        ] {
+            let mut map = (0..64).map(|k| (k, ())).collect::<RedBlackMap<u32, ()>>();
+            for key in keys {
+                assert_eq!(map.remove(&key), Some(()));
+                assert_eq!(map.remove(&key), None);
+                map.check_invariants().unwrap();
+            }
+            assert!(map.is_empty());
+        }
+    }
+
+    #[test]
+    fn order_statistics() {
+        let map = (0..50)
+            .map(|k| (k * 3, k))
+            .collect::<RedBlackMap<u32, u32>>();
+
+        assert_eq!(map.rank(&0), 0);
+        assert_eq!(map.rank(&10), 4);
+        assert_eq!(map.rank(&1_000), 50);
+        assert_eq!(map.select(4), Some((&12, &4)));
+        assert_eq!(map.floor(&10), Some((&9, &3)));
+        assert_eq!(map.ceiling(&10), Some((&12, &4)));
+        assert_eq!(map.floor(&200), Some((&147, &49)));
+        assert_eq!(map.ceiling(&148), None);
+        assert!(map.range(10..=20).map(|(&k, _)| k).eq([12, 15, 18]));
+    }
+
+    #[test]
+    fn traversals() {
+        let map = (1..=3).map(|k| (k, ())).collect::<RedBlackMap<u32, ()>>();
+
+        assert!(map.iter().map(|(&k, _)| k).eq([1, 2, 3]));
+        assert!(map.pre_order().map(|(&k, _)| k).eq([2, 1, 3]));
+        assert!(map.post_order().map(|(&k, _)| k).eq([1, 3, 2]));
+        assert_eq!(map.shape(), "2 [B]\n├── L 1 [B]\n└── R 3 [B]\n");
+    }
+}
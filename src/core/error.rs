@@ -1,7 +1,15 @@
-#[derive(Default, Debug, Clone, Copy)]
+use std::fmt::Display;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error {
     #[default]
     Any,
+    /// Operand dimensions don't fit the operation.
+    DimMismatch,
+    /// Row / column index is past the end.
+    OutOfBounds,
+    /// Value doesn't fit the target type.
+    OutOfRange,
 }
 
 impl Error {
@@ -9,3 +17,10 @@ impl Error {
         Self::Any
     }
 }
+
+impl std::error::Error for Error {}
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self))
+    }
+}
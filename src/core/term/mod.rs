@@ -10,6 +10,11 @@ pub fn size() -> TerminalSize {
     term_size::dimensions().expect("cannot get terminal size")
 }
 
+/// [`size`], but `None` when output isn't a terminal (pipes, tests).
+pub fn try_size() -> Option<TerminalSize> {
+    term_size::dimensions()
+}
+
 pub fn clear() {
     print_esc_ansi!("2J");
 }
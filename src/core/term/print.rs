@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 const PRECISION: usize = 4;
 
-#[derive(Default)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Align {
     #[default]
     Left,
@@ -10,7 +10,99 @@ pub enum Align {
     Right,
 }
 
-pub struct FormatConfig {}
+const X_GAP: usize = 4;
+const Y_GAP: usize = 3;
+const TITLE: &str = "Matrix";
+
+/// Frame drawn around [`Style::Plain`] output.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Border {
+    /// `____Title____` above and `‾‾‾‾` below.
+    #[default]
+    Banner,
+    None,
+    /// `+---+` and `|`.
+    Ascii,
+    /// `┌─┐`, `│`, `└─┘` around the whole matrix.
+    Unicode,
+    /// Box-drawing lines around every item.
+    Grid,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    #[default]
+    Plain,
+    /// `pmatrix` environment.
+    Latex,
+    /// Table with column indices as header.
+    Markdown,
+    /// Items shaded with ANSI background colors, from blue (min) to red (max).
+    Heatmap,
+}
+
+pub struct FormatConfig {
+    pub(crate) style: Style,
+    pub(crate) border: Border,
+    pub(crate) x_gap: usize,
+    /// Empty lines between rows.
+    pub(crate) y_gap: usize,
+    pub(crate) align: Align,
+    pub(crate) precision: usize,
+    pub(crate) title: Option<String>,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            border: Border::default(),
+            x_gap: X_GAP,
+            y_gap: Y_GAP,
+            align: Align::Right,
+            precision: PRECISION,
+            title: Some(TITLE.to_string()),
+        }
+    }
+}
+
+impl FormatConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn gaps(mut self, x_gap: usize, y_gap: usize) -> Self {
+        self.x_gap = x_gap;
+        self.y_gap = y_gap;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Shown by [`Border::Banner`].
+    pub fn title(mut self, title: Option<&str>) -> Self {
+        self.title = title.map(String::from);
+        self
+    }
+}
 
 pub struct PrintConfig {
     precision: usize,
@@ -49,7 +141,8 @@ where
     fn print(&self, cfg: &PrintConfig) {
         let s = format!("{self:.0$}", cfg.precision);
         let lines = s.lines().map(String::from).collect::<Vec<_>>();
-        let (tw, _) = super::size();
+        // Without a terminal there is nothing to align against.
+        let tw = super::try_size().map_or(0, |(tw, _)| tw);
 
         for line in lines {
             match cfg.align {
@@ -0,0 +1,88 @@
+use std::process::ExitCode;
+
+use playground::algorithm::registry::{self, Showcase, SHOWCASES};
+
+const USAGE: &str = "\
+Usage:
+    playground list                 List every showcase
+    playground run <name>...        Run showcases by name
+    playground all                  Run every showcase
+    playground complete [prefix]    Names starting with `prefix`, for shell completion";
+
+fn list() {
+    let width = SHOWCASES.iter().map(|s| s.name.len()).max().unwrap_or(0);
+    for showcase in SHOWCASES {
+        println!("{:<width$}    {}", showcase.name, showcase.description);
+    }
+}
+
+/// @Returns `false` if the showcase failed.
+fn run(showcase: &Showcase) -> bool {
+    println!("==> {}", showcase.name);
+
+    match (showcase.run)() {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("{} failed: {e}", showcase.name);
+            false
+        }
+    }
+}
+
+fn run_many<'a>(showcases: impl IntoIterator<Item = &'a Showcase>) -> ExitCode {
+    // Run everything, even after a failure.
+    let failed = showcases
+        .into_iter()
+        .filter(|showcase| !run(showcase))
+        .map(|showcase| showcase.name)
+        .collect::<Vec<_>>();
+
+    match failed.is_empty() {
+        true => ExitCode::SUCCESS,
+        false => {
+            eprintln!("\nFailed: {}", failed.join(", "));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["list"] => {
+            list();
+            ExitCode::SUCCESS
+        }
+        ["all"] => run_many(SHOWCASES),
+        ["complete", prefix @ ..] if prefix.len() <= 1 => {
+            for name in registry::complete(prefix.first().unwrap_or(&"")) {
+                println!("{name}");
+            }
+            ExitCode::SUCCESS
+        }
+        ["run", names @ ..] if !names.is_empty() => {
+            let mut showcases = vec![];
+            for name in names {
+                match registry::find(name) {
+                    Some(showcase) => showcases.push(showcase),
+                    None => {
+                        eprintln!("Unknown showcase `{name}`, see `playground list`");
+                        let suggestions = registry::suggest(name);
+                        if !suggestions.is_empty() {
+                            eprintln!("Did you mean: {}?", suggestions.join(", "));
+                        }
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            run_many(showcases)
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
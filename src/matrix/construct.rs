@@ -0,0 +1,132 @@
+use super::*;
+
+impl Matrix {
+    pub fn from_fn(dim: Dim, f: impl Fn(MatrixDim, MatrixDim) -> MatrixItem) -> Self {
+        let mut matrix = Self::new(dim);
+        matrix.fill_fn(f);
+        matrix
+    }
+
+    /// @Returns `Err` if rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<MatrixItem>>) -> Result<Self, pg::error::Error> {
+        let n = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != n) {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        Ok(Self::from_fn(Dim(rows.len(), n), |i, j| rows[i][j]))
+    }
+
+    /// @Returns `Err` if columns have different lengths.
+    pub fn from_cols(cols: Vec<Vec<MatrixItem>>) -> Result<Self, pg::error::Error> {
+        Ok(Self::from_rows(cols)?.transpose())
+    }
+
+    pub fn identity(size: MatrixDim) -> Self {
+        Self::from_fn(Dim(size, size), |i, j| if i == j { 1. } else { 0. })
+    }
+
+    pub fn diag(values: &[MatrixItem]) -> Self {
+        let size = values.len();
+        Self::from_fn(Dim(size, size), |i, j| if i == j { values[i] } else { 0. })
+    }
+
+    /// Row `i` is [1, xᵢ, xᵢ², ..., xᵢⁿ⁻¹].
+    pub fn vandermonde(points: &[MatrixItem], n: MatrixDim) -> Self {
+        Self::from_fn(Dim(points.len(), n), |i, j| points[i].powi(j as i32))
+    }
+
+    /// `H[i][j]` = 1 / (i + j + 1).
+    pub fn hilbert(size: MatrixDim) -> Self {
+        Self::from_fn(Dim(size, size), |i, j| 1. / (i + j + 1) as MatrixItem)
+    }
+
+    /// Constant along every diagonal: `col` is the first column, `row` is the
+    /// first row. `row[0]` is ignored in favour of `col[0]`.
+    pub fn toeplitz(col: &[MatrixItem], row: &[MatrixItem]) -> Self {
+        Self::from_fn(Dim(col.len(), row.len()), |i, j| match i >= j {
+            true => col[i - j],
+            false => row[j - i],
+        })
+    }
+
+    /// Places `blocks` along the main diagonal, zeros elsewhere.
+    pub fn block_diag(blocks: &[&dyn MatrixRepr]) -> Self {
+        let (m, n) = blocks.iter().fold((0, 0), |(m, n), block| {
+            (m + block.get_dim().get_m(), n + block.get_dim().get_n())
+        });
+
+        let mut matrix = Self::new(Dim(m, n));
+        let (mut row_offset, mut col_offset) = (0, 0);
+        for block in blocks {
+            for (i, row) in block.get_data().iter().enumerate() {
+                for (j, value) in row.iter().enumerate() {
+                    matrix.data[row_offset + i][col_offset + j] = *value;
+                }
+            }
+
+            row_offset += block.get_dim().get_m();
+            col_offset += block.get_dim().get_n();
+        }
+
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(matrix: &Matrix) -> Vec<Vec<MatrixItem>> {
+        matrix.get_data().iter().map(|row| row.to_vec()).collect()
+    }
+
+    #[test]
+    fn from_rows_and_cols() {
+        let matrix = Matrix::from_rows(vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(matrix.get_dim(), Dim::new(2, 3));
+        assert_eq!(matrix.get(1, 0), 4.);
+
+        let from_cols = Matrix::from_cols(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]).unwrap();
+        assert_eq!(from_cols, matrix);
+
+        assert_eq!(
+            Matrix::from_rows(vec![vec![1.], vec![2., 3.]]),
+            Err(pg::error::Error::DimMismatch)
+        );
+        assert_eq!(Matrix::from_rows(vec![]).unwrap().get_dim(), Dim::new(0, 0));
+    }
+
+    #[test]
+    fn identity_and_diag() {
+        assert_eq!(
+            rows(&Matrix::identity(3)),
+            [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+        );
+        assert_eq!(rows(&Matrix::diag(&[2., 3.])), [[2., 0.], [0., 3.]]);
+    }
+
+    #[test]
+    fn special() {
+        assert_eq!(
+            rows(&Matrix::vandermonde(&[1., 2., 3.], 3)),
+            [[1., 1., 1.], [1., 2., 4.], [1., 3., 9.]]
+        );
+        assert_eq!(rows(&Matrix::hilbert(2)), [[1., 0.5], [0.5, 1. / 3.]]);
+        assert_eq!(
+            rows(&Matrix::toeplitz(&[1., 2., 3.], &[0., 4., 5., 6.])),
+            [[1., 4., 5., 6.], [2., 1., 4., 5.], [3., 2., 1., 4.]]
+        );
+    }
+
+    #[test]
+    fn block_diag() {
+        let a = Matrix::from_rows(vec![vec![1., 2.]]).unwrap();
+        let b = SquareMatrix::try_from(Matrix::diag(&[3., 4.])).unwrap();
+
+        assert_eq!(
+            rows(&Matrix::block_diag(&[&a, &b])),
+            [[1., 2., 0., 0.], [0., 0., 3., 0.], [0., 0., 0., 4.]]
+        );
+    }
+}
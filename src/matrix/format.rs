@@ -0,0 +1,305 @@
+use std::fmt::{Formatter, Result, Write};
+
+use pg::term::print::{Align, Border, FormatConfig, Print, Style};
+
+use crate::esc_ansi;
+
+use super::*;
+
+/// [`Display`] of a matrix driven by [`FormatConfig`].
+///
+/// Precision from the format string (`{:.2}`) takes priority over the config.
+pub struct MatrixDisplay<'a> {
+    matrix: &'a dyn MatrixRepr,
+    cfg: &'a FormatConfig,
+}
+
+impl<'a> MatrixDisplay<'a> {
+    pub fn new(matrix: &'a dyn MatrixRepr, cfg: &'a FormatConfig) -> Self {
+        Self { matrix, cfg }
+    }
+
+    fn cells(&self, precision: usize) -> Vec<Vec<String>> {
+        self.matrix
+            .get_data()
+            .iter()
+            .map(|row| row.iter().map(|v| format!("{v:.precision$}")).collect())
+            .collect()
+    }
+
+    fn pad(&self, s: &str, width: usize) -> String {
+        match self.cfg.align {
+            Align::Left => format!("{s:<width$}"),
+            Align::Center => format!("{s:^width$}"),
+            Align::Right => format!("{s:>width$}"),
+        }
+    }
+
+    fn write_plain(&self, f: &mut Formatter<'_>, cells: &[Vec<String>], width: usize) -> Result {
+        let n = self.matrix.get_dim().get_n();
+        let gap = " ".repeat(self.cfg.x_gap);
+        let content_width = (n * (width + self.cfg.x_gap)).saturating_sub(self.cfg.x_gap);
+
+        let lines = cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| self.pad(cell, width))
+                    .collect::<Vec<_>>()
+                    .join(&gap)
+            })
+            .collect::<Vec<_>>();
+
+        let (top, side, bottom) = match self.cfg.border {
+            Border::Banner => {
+                let line_width = (n * (width + self.cfg.x_gap + 1)).div_ceil(2) * 2;
+                let title = self.cfg.title.as_deref().unwrap_or_default();
+
+                (
+                    format!("{title:_^line_width$}"),
+                    ("", ""),
+                    format!("{:‾^line_width$}", ""),
+                )
+            }
+            Border::None => (String::new(), ("", ""), String::new()),
+            Border::Ascii => (
+                format!("+{}+", "-".repeat(content_width + 2)),
+                ("| ", " |"),
+                format!("+{}+", "-".repeat(content_width + 2)),
+            ),
+            Border::Unicode => (
+                format!("┌{}┐", "─".repeat(content_width + 2)),
+                ("│ ", " │"),
+                format!("└{}┘", "─".repeat(content_width + 2)),
+            ),
+            Border::Grid => return self.write_grid(f, cells, width),
+        };
+
+        if !top.is_empty() {
+            writeln!(f, "{top}")?;
+        }
+
+        let blank = " ".repeat(content_width);
+        for (i, line) in lines.iter().enumerate() {
+            writeln!(f, "{}{line}{}", side.0, side.1)?;
+
+            if i != lines.len() - 1 {
+                for _ in 0..self.cfg.y_gap {
+                    writeln!(f, "{}{blank}{}", side.0, side.1)?;
+                }
+            }
+        }
+
+        if !bottom.is_empty() {
+            writeln!(f, "{bottom}")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_grid(&self, f: &mut Formatter<'_>, cells: &[Vec<String>], width: usize) -> Result {
+        let n = self.matrix.get_dim().get_n();
+        let segment = "─".repeat(width + 2);
+        let separator = |left: &str, middle: &str, right: &str| {
+            format!("{left}{}{right}", vec![segment.as_str(); n].join(middle))
+        };
+
+        writeln!(f, "{}", separator("┌", "┬", "┐"))?;
+        for (i, row) in cells.iter().enumerate() {
+            let line = row
+                .iter()
+                .map(|cell| format!(" {} ", self.pad(cell, width)))
+                .collect::<Vec<_>>()
+                .join("│");
+            writeln!(f, "│{line}│")?;
+
+            if i != cells.len() - 1 {
+                writeln!(f, "{}", separator("├", "┼", "┤"))?;
+            }
+        }
+        writeln!(f, "{}", separator("└", "┴", "┘"))
+    }
+
+    fn write_latex(&self, f: &mut Formatter<'_>, cells: &[Vec<String>], width: usize) -> Result {
+        writeln!(f, "\\begin{{pmatrix}}")?;
+        for (i, row) in cells.iter().enumerate() {
+            let line = row
+                .iter()
+                .map(|cell| self.pad(cell, width))
+                .collect::<Vec<_>>()
+                .join(" & ");
+            match i != cells.len() - 1 {
+                true => writeln!(f, "{line} \\\\")?,
+                false => writeln!(f, "{line}")?,
+            }
+        }
+        writeln!(f, "\\end{{pmatrix}}")
+    }
+
+    fn write_markdown(&self, f: &mut Formatter<'_>, cells: &[Vec<String>], width: usize) -> Result {
+        let n = self.matrix.get_dim().get_n();
+        let width = (0..n)
+            .map(|j| j.to_string().len())
+            .fold(width.max(3), usize::max);
+
+        let header = (0..n)
+            .map(|j| self.pad(&j.to_string(), width))
+            .collect::<Vec<_>>();
+        writeln!(f, "| {} |", header.join(" | "))?;
+
+        let rule = match self.cfg.align {
+            Align::Left => format!(":{}", "-".repeat(width - 1)),
+            Align::Center => format!(":{}:", "-".repeat(width - 2)),
+            Align::Right => format!("{}:", "-".repeat(width - 1)),
+        };
+        writeln!(f, "| {} |", vec![rule; n].join(" | "))?;
+
+        for row in cells {
+            let line = row
+                .iter()
+                .map(|cell| self.pad(cell, width))
+                .collect::<Vec<_>>();
+            writeln!(f, "| {} |", line.join(" | "))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_heatmap(&self, f: &mut Formatter<'_>, cells: &[Vec<String>], width: usize) -> Result {
+        let values = self.matrix.get_data();
+        let (min, max) = values
+            .iter()
+            .flatten()
+            .fold((MatrixItem::MAX, MatrixItem::MIN), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            });
+
+        for (row, values_row) in cells.iter().zip(values) {
+            let mut line = String::new();
+            for (cell, &value) in row.iter().zip(values_row) {
+                let t = match max > min {
+                    true => ((value - min) / (max - min)).clamp(0., 1.),
+                    false => 0.5,
+                };
+                let (r, b) = ((255. * t) as u8, (255. * (1. - t)) as u8);
+
+                write!(
+                    line,
+                    "{}{} {} {}",
+                    esc_ansi!(format!("48;2;{r};64;{b}m")),
+                    esc_ansi!("97m"),
+                    self.pad(cell, width),
+                    esc_ansi!("0m")
+                )?;
+            }
+
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for MatrixDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let precision = f.precision().unwrap_or(self.cfg.precision);
+        let cells = self.cells(precision);
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|s| s.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        match self.cfg.style {
+            Style::Plain => self.write_plain(f, &cells, width),
+            Style::Latex => self.write_latex(f, &cells, width),
+            Style::Markdown => self.write_markdown(f, &cells, width),
+            Style::Heatmap => self.write_heatmap(f, &cells, width),
+        }
+    }
+}
+
+impl Print for MatrixDisplay<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> Matrix {
+        Matrix::from_rows(vec![vec![1., -2.5], vec![30., 4.]]).unwrap()
+    }
+
+    fn render(cfg: FormatConfig) -> String {
+        matrix().display_with(&cfg).to_string()
+    }
+
+    #[test]
+    fn default_banner() {
+        let s = format!("{:.1}", matrix());
+        let lines = s.lines().collect::<Vec<_>>();
+
+        assert!(lines[0].contains("Matrix") && lines[0].starts_with('_'));
+        assert_eq!(lines[1], " 1.0    -2.5");
+        assert!(lines[2..5].iter().all(|line| line.trim().is_empty()));
+        assert_eq!(lines[5], "30.0     4.0");
+        assert!(lines[6].chars().all(|c| c == '‾'));
+    }
+
+    #[test]
+    fn borders() {
+        let cfg = FormatConfig::new().precision(1).gaps(1, 0);
+
+        assert_eq!(
+            render(cfg.border(Border::Unicode)),
+            "┌───────────┐\n│  1.0 -2.5 │\n│ 30.0  4.0 │\n└───────────┘\n"
+        );
+
+        let cfg = FormatConfig::new().precision(0).gaps(1, 1);
+        assert_eq!(
+            render(cfg.border(Border::Ascii).align(Align::Left)),
+            "+-------+\n| 1  -2 |\n|       |\n| 30 4  |\n+-------+\n"
+        );
+
+        let cfg = FormatConfig::new().precision(0).border(Border::Grid);
+        assert_eq!(
+            render(cfg),
+            "┌────┬────┐\n│  1 │ -2 │\n├────┼────┤\n│ 30 │  4 │\n└────┴────┘\n"
+        );
+
+        let cfg = FormatConfig::new().precision(0).gaps(1, 0);
+        assert_eq!(render(cfg.border(Border::None)), " 1 -2\n30  4\n");
+    }
+
+    #[test]
+    fn latex() {
+        let cfg = FormatConfig::new().style(Style::Latex).precision(1);
+
+        assert_eq!(
+            render(cfg),
+            "\\begin{pmatrix}\n 1.0 & -2.5 \\\\\n30.0 &  4.0\n\\end{pmatrix}\n"
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let cfg = FormatConfig::new().style(Style::Markdown).precision(0);
+
+        assert_eq!(
+            render(cfg.align(Align::Center)),
+            "|  0  |  1  |\n| :-: | :-: |\n|  1  | -2  |\n| 30  |  4  |\n"
+        );
+    }
+
+    #[test]
+    fn heatmap() {
+        let cfg = FormatConfig::new().style(Style::Heatmap).precision(0);
+        let s = render(cfg);
+
+        // min is blue, max is red.
+        assert!(s.contains("\x1b[48;2;0;64;255m"));
+        assert!(s.contains("\x1b[48;2;255;64;0m"));
+        assert_eq!(s.matches("\x1b[0m").count(), 4);
+        assert_eq!(s.lines().count(), 2);
+    }
+}
@@ -0,0 +1,341 @@
+use crate::random::SeededRandom;
+
+use super::*;
+
+type Rows = Vec<Vec<f64>>;
+
+fn gaussian_rows(rng: &mut SeededRandom, m: MatrixDim, n: MatrixDim) -> Rows {
+    (0..m)
+        .map(|_| (0..n).map(|_| rng.get_gaussian()).collect())
+        .collect()
+}
+
+fn uniform_rows(rng: &mut SeededRandom, m: MatrixDim, n: MatrixDim) -> Rows {
+    (0..m)
+        .map(|_| (0..n).map(|_| rng.get_in_range(-1., 1.)).collect())
+        .collect()
+}
+
+fn multiply_rows(lhs: &Rows, rhs: &Rows) -> Rows {
+    let inner = rhs.len();
+    let n = rhs.first().map_or(0, Vec::len);
+
+    lhs.iter()
+        .map(|row| {
+            (0..n)
+                .map(|j| (0..inner).map(|k| row[k] * rhs[k][j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+fn to_matrix_data(rows: &Rows) -> MatrixData {
+    rows.iter()
+        .map(|row| row.iter().map(|&v| v as MatrixItem).collect())
+        .collect()
+}
+
+/// `Q` factor of the Householder QR decomposition of `a` (N × N).
+///
+/// Columns are flipped so that `R` has a positive diagonal, which makes `Q`
+/// uniformly (Haar) distributed when `a` is Gaussian.
+fn householder_q(mut r: Rows) -> Rows {
+    let size = r.len();
+    let mut q = (0..size)
+        .map(|i| (0..size).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect::<Rows>();
+
+    for k in 0..size.saturating_sub(1) {
+        let mut v = (k..size).map(|i| r[i][k]).collect::<Vec<f64>>();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0. {
+            continue;
+        }
+
+        v[0] += v[0].signum() * norm;
+        let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|x| *x /= v_norm);
+
+        // R = H · R
+        let dots = (0..size)
+            .map(|j| v.iter().zip(&r[k..]).map(|(x, row)| x * row[j]).sum())
+            .collect::<Vec<f64>>();
+        for (x, row) in v.iter().zip(r[k..].iter_mut()) {
+            row.iter_mut()
+                .zip(&dots)
+                .for_each(|(value, dot)| *value -= 2. * x * dot);
+        }
+
+        // Q = Q · H
+        for row in q.iter_mut() {
+            let dot = (0..v.len()).map(|l| row[k + l] * v[l]).sum::<f64>();
+            (0..v.len()).for_each(|l| row[k + l] -= 2. * dot * v[l]);
+        }
+    }
+
+    for (j, r_row) in r.iter().enumerate() {
+        if r_row[j] < 0. {
+            q.iter_mut().for_each(|row| row[j] = -row[j]);
+        }
+    }
+
+    q
+}
+
+impl SquareMatrix {
+    fn from_rows_f64(rows: &Rows) -> Self {
+        Self {
+            data: to_matrix_data(rows),
+            size: rows.len(),
+        }
+    }
+
+    /// Entries are uniform in `[-1, 1)`, mirrored over the main diagonal.
+    pub fn random_symmetric(size: MatrixDim, seed: u64) -> Self {
+        let mut rows = uniform_rows(&mut SeededRandom::new(seed), size, size);
+        for i in 1..size {
+            let (upper, lower) = rows.split_at_mut(i);
+            for (j, row) in upper.iter().enumerate() {
+                lower[0][j] = row[i];
+            }
+        }
+
+        Self::from_rows_f64(&rows)
+    }
+
+    /// Symmetric positive definite: `B · Bᵀ + N · I` for a Gaussian `B`.
+    pub fn random_spd(size: MatrixDim, seed: u64) -> Self {
+        let b = gaussian_rows(&mut SeededRandom::new(seed), size, size);
+        let b_t = (0..size)
+            .map(|j| (0..size).map(|i| b[i][j]).collect())
+            .collect::<Rows>();
+
+        let mut rows = multiply_rows(&b, &b_t);
+        for (i, row) in rows.iter_mut().enumerate() {
+            row[i] += size as f64;
+        }
+
+        Self::from_rows_f64(&rows)
+    }
+
+    /// `Q` factor of the QR decomposition of a Gaussian matrix.
+    pub fn random_orthogonal(size: MatrixDim, seed: u64) -> Self {
+        let a = gaussian_rows(&mut SeededRandom::new(seed), size, size);
+
+        Self::from_rows_f64(&householder_q(a))
+    }
+
+    /// Off-diagonal entries are uniform in `[-1, 1)`; every diagonal entry
+    /// exceeds the absolute sum of the rest of its row by `[1, 2)`.
+    pub fn random_diagonally_dominant(size: MatrixDim, seed: u64) -> Self {
+        let mut rng = SeededRandom::new(seed);
+        let mut rows = uniform_rows(&mut rng, size, size);
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            let off_diagonal = row
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, v)| v.abs())
+                .sum::<f64>();
+
+            row[i] = off_diagonal + rng.get_in_range(1., 2.);
+        }
+
+        Self::from_rows_f64(&rows)
+    }
+}
+
+impl Matrix {
+    fn from_rows_f64(dim: Dim, rows: &Rows) -> Self {
+        Self {
+            data: to_matrix_data(rows),
+            dim,
+        }
+    }
+
+    /// Every entry is non-zero with probability `density`, uniform in `[-1, 1)`.
+    pub fn random_sparse(dim: Dim, density: f64, seed: u64) -> Self {
+        let mut rng = SeededRandom::new(seed);
+        let rows = (0..dim.get_m())
+            .map(|_| {
+                (0..dim.get_n())
+                    .map(|_| match rng.get_bool(density) {
+                        true => rng.get_in_range(-1., 1.),
+                        false => 0.,
+                    })
+                    .collect()
+            })
+            .collect::<Rows>();
+
+        Self::from_rows_f64(dim, &rows)
+    }
+
+    /// Product of Gaussian `M × rank` and `rank × N` matrices.
+    ///
+    /// @Returns `Err` if `rank` > min(`M`, `N`).
+    pub fn random_with_rank(
+        dim: Dim,
+        rank: MatrixDim,
+        seed: u64,
+    ) -> Result<Self, pg::error::Error> {
+        if rank > dim.get_m().min(dim.get_n()) {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        let mut rng = SeededRandom::new(seed);
+        let lhs = gaussian_rows(&mut rng, dim.get_m(), rank);
+        let rhs = gaussian_rows(&mut rng, rank, dim.get_n());
+
+        // `rank` = 0 leaves the product without columns.
+        let rows = match rank {
+            0 => vec![vec![0.; dim.get_n()]; dim.get_m()],
+            _ => multiply_rows(&lhs, &rhs),
+        };
+
+        Ok(Self::from_rows_f64(dim, &rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-4;
+
+    fn to_rows(matrix: &dyn MatrixRepr) -> Rows {
+        matrix
+            .get_data()
+            .iter()
+            .map(|row| row.iter().map(|&v| v as f64).collect())
+            .collect()
+    }
+
+    // Gaussian elimination with partial pivoting.
+    fn rank(mut rows: Rows) -> usize {
+        let (m, n) = (rows.len(), rows.first().map_or(0, Vec::len));
+        let mut rank = 0;
+
+        for col in 0..n {
+            let Some(pivot) = (rank..m)
+                .max_by(|&a, &b| rows[a][col].abs().total_cmp(&rows[b][col].abs()))
+                .filter(|&p| rows[p][col].abs() > 1e-6)
+            else {
+                continue;
+            };
+
+            rows.swap(rank, pivot);
+            let (upper, lower) = rows.split_at_mut(rank + 1);
+            let pivot_row = &upper[rank];
+            for row in lower {
+                let factor = row[col] / pivot_row[col];
+                for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *value -= factor * pivot_value;
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank
+    }
+
+    #[test]
+    fn reproducible() {
+        assert_eq!(
+            SquareMatrix::random_spd(5, 52),
+            SquareMatrix::random_spd(5, 52)
+        );
+        assert_eq!(
+            Matrix::random_sparse(Dim::new(4, 7), 0.3, 1),
+            Matrix::random_sparse(Dim::new(4, 7), 0.3, 1)
+        );
+        assert_ne!(
+            SquareMatrix::random_symmetric(5, 1),
+            SquareMatrix::random_symmetric(5, 2)
+        );
+    }
+
+    #[test]
+    fn symmetric() {
+        let matrix = SquareMatrix::random_symmetric(6, 7);
+        for i in 0..6 {
+            for j in 0..6 {
+                assert_eq!(matrix.get(i, j), matrix.get(j, i));
+            }
+        }
+    }
+
+    #[test]
+    fn spd() {
+        let rows = to_rows(&SquareMatrix::random_spd(6, 7));
+
+        // Cholesky succeeds iff the matrix is positive definite.
+        let mut l = vec![vec![0.; 6]; 6];
+        for i in 0..6 {
+            for j in 0..=i {
+                assert_eq!(rows[i][j], rows[j][i]);
+
+                let sum = (0..j).map(|k| l[i][k] * l[j][k]).sum::<f64>();
+                match i == j {
+                    true => {
+                        assert!(rows[i][i] - sum > 0.);
+                        l[i][j] = (rows[i][i] - sum).sqrt();
+                    }
+                    false => l[i][j] = (rows[i][j] - sum) / l[j][j],
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn orthogonal() {
+        let q = SquareMatrix::random_orthogonal(8, 7);
+        for i in 0..8 {
+            for j in 0..8 {
+                let dot = (0..8).map(|k| q.get(k, i) * q.get(k, j)).sum::<f32>();
+                let expected = if i == j { 1. } else { 0. };
+
+                assert!((dot - expected).abs() < EPS, "QᵀQ[{i}][{j}] = {dot}");
+            }
+        }
+    }
+
+    #[test]
+    fn diagonally_dominant() {
+        let matrix = SquareMatrix::random_diagonally_dominant(6, 7);
+        for i in 0..6 {
+            let off_diagonal = (0..6)
+                .filter(|&j| j != i)
+                .map(|j| matrix.get(i, j).abs())
+                .sum::<f32>();
+
+            assert!(matrix.get(i, i) > off_diagonal);
+        }
+    }
+
+    #[test]
+    fn sparse() {
+        let matrix = Matrix::random_sparse(Dim::new(100, 100), 0.2, 7);
+        let non_zero = matrix.iter().filter(|&&v| v != 0.).count();
+
+        assert!((1_500..2_500).contains(&non_zero), "{non_zero} non-zero");
+        assert_eq!(
+            Matrix::random_sparse(Dim::new(3, 3), 0., 7)
+                .iter()
+                .filter(|&&v| v != 0.)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn fixed_rank() {
+        for r in 0..=4 {
+            let matrix = Matrix::random_with_rank(Dim::new(4, 6), r, 7).unwrap();
+            assert_eq!(rank(to_rows(&matrix)), r);
+        }
+
+        assert!(Matrix::random_with_rank(Dim::new(4, 6), 5, 7).is_err());
+    }
+}
@@ -1,19 +1,29 @@
-use pg::{math::get_i32_len, term::print::Print};
-use print::ITEM_X_GAP;
+use std::ops::{Index, IndexMut};
+
+use pg::term::print::{FormatConfig, Print};
 
 use super::*;
 
 fn new_empty_matrix_data(dim: &Dim) -> MatrixData {
-    let mut matrix_data = Vec::with_capacity(dim.get_m());
+    vec![vec![MatrixItem::default(); dim.get_n()]; dim.get_m()]
+}
 
-    for i in 0..dim.get_m() {
-        matrix_data.push(Vec::with_capacity(dim.get_n()));
-        for _ in 0..dim.get_n() {
-            matrix_data[i].push(ItemCell::default());
-        }
+fn collect_col(repr: &dyn MatrixRepr, col_i: MatrixDim) -> MatrixColumn {
+    MatrixColumn(repr.get_data().iter().map(|row| row[col_i]).collect())
+}
+
+fn check_row(repr: &dyn MatrixRepr, row_i: MatrixDim) -> Result<(), pg::error::Error> {
+    match row_i < repr.get_dim().get_m() {
+        true => Ok(()),
+        false => Err(pg::error::Error::OutOfBounds),
     }
+}
 
-    matrix_data
+fn check_col(repr: &dyn MatrixRepr, col_i: MatrixDim) -> Result<(), pg::error::Error> {
+    match col_i < repr.get_dim().get_n() {
+        true => Ok(()),
+        false => Err(pg::error::Error::OutOfBounds),
+    }
 }
 
 pub trait MatrixRepr {
@@ -21,62 +31,127 @@ pub trait MatrixRepr {
     fn get_dim(&self) -> Dim;
 }
 
-pub trait MatrixOperations: MatrixRepr
+mod sealed {
+    use super::MatrixData;
+
+    /// Raw rows could be resized, so only this crate gets to see them. Outside it,
+    /// [`MatrixOperations`](super::MatrixOperations) only offers mutators that keep the shape.
+    pub trait MatrixDataMut {
+        /// Rows may be mutated in place, but the shape must stay the same.
+        fn get_data_mut(&mut self) -> &'_ mut MatrixData;
+    }
+}
+use sealed::MatrixDataMut;
+
+pub trait MatrixOperations: MatrixRepr + MatrixDataMut
 where
     Self: Sized + Clone + Display,
 {
-    fn iter(&self) -> impl Iterator<Item = &ItemCell> {
+    fn iter(&self) -> impl Iterator<Item = &MatrixItem> {
         self.get_data().iter().flatten()
     }
 
-    fn row(&self, row_i: MatrixDim) -> MatrixRow {
-        MatrixRow(self.get_data().get(row_i).unwrap())
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut MatrixItem> {
+        self.get_data_mut().iter_mut().flatten()
     }
 
-    fn col(&self, col_i: MatrixDim) -> MatrixColumn {
-        MatrixColumn(
-            self.get_data()
-                .iter()
-                .map(|row| row[col_i].clone())
-                .collect(),
-        )
+    /// Row-major, with `(i, j)` of every element.
+    fn enumerate(&self) -> impl Iterator<Item = ((MatrixDim, MatrixDim), &MatrixItem)> {
+        self.get_data()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, v)| ((i, j), v)))
     }
 
-    fn get(&self, i: MatrixDim, j: MatrixDim) -> &ItemCell {
-        &self.get_data()[i][j]
+    fn rows(&self) -> impl Iterator<Item = MatrixRow<'_>> {
+        self.get_data().iter().map(|row| MatrixRow(row))
+    }
+
+    fn cols(&self) -> impl Iterator<Item = MatrixColumn> {
+        (0..self.get_dim().get_n()).map(|j| collect_col(self, j))
+    }
+
+    fn row(&self, row_i: MatrixDim) -> Result<MatrixRow<'_>, pg::error::Error> {
+        self.get_data()
+            .get(row_i)
+            .map(|row| MatrixRow(row))
+            .ok_or(pg::error::Error::OutOfBounds)
+    }
+
+    fn col(&self, col_i: MatrixDim) -> Result<MatrixColumn, pg::error::Error> {
+        check_col(self, col_i)?;
+        Ok(collect_col(self, col_i))
+    }
+
+    fn get(&self, i: MatrixDim, j: MatrixDim) -> MatrixItem {
+        self.get_data()[i][j]
+    }
+
+    fn set(&mut self, i: MatrixDim, j: MatrixDim, value: MatrixItem) {
+        self.get_data_mut()[i][j] = value;
     }
 
     /// @Mutate matrix row | [fold_to_row_idx]
     ///
     /// @Leaves unchanged elements in | [fold_from_row_idx]
-    fn fold_row(&self, to_idx: MatrixDim, from_idx: MatrixDim, k: f32) {
-        self.row(to_idx).fold(self.row(from_idx), k);
+    fn fold_row(&mut self, to_idx: MatrixDim, from_idx: MatrixDim, k: f32) {
+        let from = self.get_data()[from_idx].clone();
+        for (v1, v2) in self.get_data_mut()[to_idx].iter_mut().zip(from) {
+            *v1 += k * v2;
+        }
     }
 
-    fn spaw_rows(&mut self, lhs: MatrixDim, rhs: MatrixDim) {
-        for i in 0..self.get_dim().get_n() {
-            self.get_data()[lhs][i].swap(&self.get_data()[rhs][i]);
-        }
+    fn swap_rows(&mut self, lhs: MatrixDim, rhs: MatrixDim) -> Result<(), pg::error::Error> {
+        check_row(self, lhs)?;
+        check_row(self, rhs)?;
+
+        self.get_data_mut().swap(lhs, rhs);
+        Ok(())
+    }
+
+    fn swap_cols(&mut self, lhs: MatrixDim, rhs: MatrixDim) -> Result<(), pg::error::Error> {
+        check_col(self, lhs)?;
+        check_col(self, rhs)?;
+
+        self.get_data_mut()
+            .iter_mut()
+            .for_each(|row| row.swap(lhs, rhs));
+        Ok(())
+    }
+
+    fn scale_row(&mut self, row_i: MatrixDim, k: MatrixItem) -> Result<(), pg::error::Error> {
+        check_row(self, row_i)?;
+
+        self.get_data_mut()[row_i].iter_mut().for_each(|v| *v *= k);
+        Ok(())
+    }
+
+    fn scale_col(&mut self, col_i: MatrixDim, k: MatrixItem) -> Result<(), pg::error::Error> {
+        check_col(self, col_i)?;
+
+        self.get_data_mut()
+            .iter_mut()
+            .for_each(|row| row[col_i] *= k);
+        Ok(())
     }
 
     fn fill_random(&mut self) {
-        self.iter().for_each(|v| v.set(Random::get()));
+        self.iter_mut().for_each(|v| *v = Random::get());
     }
 
     fn fill_random_in_range(&mut self, min: MatrixItem, max: MatrixItem) {
-        // ? cast?? tf
-        self.iter()
-            .for_each(|v| v.set(Random::get_in_range(min as u8, max as u8) as f32));
+        self.iter_mut()
+            .for_each(|v| *v = Random::get_in_range(min, max));
     }
 
     fn fill(&mut self, value: MatrixItem) {
-        self.iter().for_each(|v| v.set(value));
+        self.iter_mut().for_each(|v| *v = value);
     }
 
     fn fill_fn(&mut self, f: impl Fn(MatrixDim, MatrixDim) -> MatrixItem) {
-        for i in 0..self.get_dim().get_m() {
-            for j in 0..self.get_dim().get_n() {
-                self.get_data()[i][j].set(f(i, j));
+        for (i, row) in self.get_data_mut().iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = f(i, j);
             }
         }
     }
@@ -90,7 +165,7 @@ where
                 let mut idx = 0;
 
                 for j in start_pivot_idx..self.get_dim().get_m() {
-                    el = self.get(j, i).get();
+                    el = self.get(j, i);
                     if el != 0. {
                         idx = j;
                         break;
@@ -105,11 +180,12 @@ where
             }
 
             if pivot_idx != start_pivot_idx {
-                self.spaw_rows(pivot_idx, i);
+                self.swap_rows(pivot_idx, start_pivot_idx)
+                    .expect("Pivot rows should be in range");
             }
 
             for j in (start_pivot_idx + 1)..self.get_dim().get_m() {
-                let factor = self.get(j, i).get() / pivot_el;
+                let factor = self.get(j, i) / pivot_el;
 
                 self.fold_row(j, start_pivot_idx, -factor);
             }
@@ -130,29 +206,118 @@ where
 
         let dim = Dim(m1, n2);
 
-        let data = new_empty_matrix_data(&dim);
-        for n in 0..n2 {
-            for m in 0..m1 {
-                data[m][n].set((0..n1).zip(0..m2).fold(0., |acc, (i, j)| {
-                    acc + self.get(m, i).get() * rhs.get(j, n).get()
-                }));
+        let mut data = new_empty_matrix_data(&dim);
+        for (m, row) in data.iter_mut().enumerate() {
+            for (n, value) in row.iter_mut().enumerate() {
+                *value = (0..n1)
+                    .zip(0..m2)
+                    .fold(0., |acc, (i, j)| acc + self.get(m, i) * rhs.get(j, n));
             }
         }
 
         Some(Matrix { data, dim })
     }
+
+    fn display_with<'a>(&'a self, cfg: &'a FormatConfig) -> MatrixDisplay<'a> {
+        MatrixDisplay::new(self, cfg)
+    }
+
+    fn transpose(&self) -> Matrix {
+        let dim = self.get_dim();
+        Matrix::from_fn(Dim(dim.get_n(), dim.get_m()), |i, j| self.get(j, i))
+    }
+
+    /// [`self` | `rhs`]
+    fn hstack(&self, rhs: &dyn MatrixRepr) -> Result<Matrix, pg::error::Error> {
+        let (lhs_dim, rhs_dim) = (self.get_dim(), rhs.get_dim());
+        if lhs_dim.get_m() != rhs_dim.get_m() {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        let split = lhs_dim.get_n();
+        let dim = Dim(lhs_dim.get_m(), split + rhs_dim.get_n());
+        Ok(Matrix::from_fn(dim, |i, j| match j < split {
+            true => self.get(i, j),
+            false => rhs.get_data()[i][j - split],
+        }))
+    }
+
+    /// [`self` / `rhs`]
+    fn vstack(&self, rhs: &dyn MatrixRepr) -> Result<Matrix, pg::error::Error> {
+        let (lhs_dim, rhs_dim) = (self.get_dim(), rhs.get_dim());
+        if lhs_dim.get_n() != rhs_dim.get_n() {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        let split = lhs_dim.get_m();
+        let dim = Dim(split + rhs_dim.get_m(), lhs_dim.get_n());
+        Ok(Matrix::from_fn(dim, |i, j| match i < split {
+            true => self.get(i, j),
+            false => rhs.get_data()[i - split][j],
+        }))
+    }
+
+    /// Keeps row-major order of elements.
+    ///
+    /// @Returns `Err` if element count differs.
+    fn reshape(&self, dim: Dim) -> Result<Matrix, pg::error::Error> {
+        let n = self.get_dim().get_n();
+        if dim.get_m() * dim.get_n() != self.get_dim().get_m() * n {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        Ok(Matrix::from_fn(dim, |i, j| {
+            let idx = i * dim.get_n() + j;
+            self.get(idx / n, idx % n)
+        }))
+    }
+
+    /// Kronecker product: block (i, j) is `self[i][j]` · `rhs`.
+    fn kronecker(&self, rhs: &dyn MatrixRepr) -> Matrix {
+        let (lhs_dim, rhs_dim) = (self.get_dim(), rhs.get_dim());
+        let (p, q) = (rhs_dim.get_m(), rhs_dim.get_n());
+
+        let dim = Dim(lhs_dim.get_m() * p, lhs_dim.get_n() * q);
+        Matrix::from_fn(dim, |i, j| {
+            self.get(i / p, j / q) * rhs.get_data()[i % p][j % q]
+        })
+    }
+
+    /// Element-wise product.
+    fn hadamard(&self, rhs: &dyn MatrixRepr) -> Result<Matrix, pg::error::Error> {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+
+    fn map(&self, f: impl Fn(MatrixItem) -> MatrixItem) -> Matrix {
+        Matrix::from_fn(self.get_dim(), |i, j| f(self.get(i, j)))
+    }
+
+    /// @Returns `Err` if dimensions differ.
+    fn zip_with(
+        &self,
+        rhs: &dyn MatrixRepr,
+        f: impl Fn(MatrixItem, MatrixItem) -> MatrixItem,
+    ) -> Result<Matrix, pg::error::Error> {
+        if self.get_dim() != rhs.get_dim() {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        Ok(Matrix::from_fn(self.get_dim(), |i, j| {
+            f(self.get(i, j), rhs.get_data()[i][j])
+        }))
+    }
 }
 
 impl Dim {
     /// m = Rows
     #[inline]
-    fn get_m(&self) -> MatrixDim {
+    pub fn get_m(&self) -> MatrixDim {
         self.0
     }
 
     /// n = Cols
     #[inline]
-    fn get_n(&self) -> MatrixDim {
+    pub fn get_n(&self) -> MatrixDim {
         self.1
     }
 
@@ -169,17 +334,44 @@ impl Dim {
     }
 }
 impl MatrixRow<'_> {
-    fn iter(&self) -> impl Iterator<Item = &ItemCell> {
+    pub fn iter(&self) -> impl Iterator<Item = &MatrixItem> {
         self.0.iter()
     }
 
-    /// @Mutate `self`: `cell[self]` += `k` * `cell[row]`
-    ///
-    /// @Leaves unchanged `row`
-    pub fn fold(&self, row: MatrixRow, k: f32) {
-        for (v1, v2) in self.iter().zip(row.iter()) {
-            v1.set(v1.get() + k * v2.get());
-        }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Index<MatrixDim> for MatrixRow<'_> {
+    type Output = MatrixItem;
+    fn index(&self, index: MatrixDim) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl MatrixColumn {
+    pub fn iter(&self) -> impl Iterator<Item = &MatrixItem> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Index<MatrixDim> for MatrixColumn {
+    type Output = MatrixItem;
+    fn index(&self, index: MatrixDim) -> &Self::Output {
+        &self.0[index]
     }
 }
 
@@ -190,6 +382,60 @@ impl Matrix {
             dim,
         }
     }
+
+    /// Inserts `values` as row `at`, shifting the following rows down.
+    pub fn insert_row(
+        &mut self,
+        at: MatrixDim,
+        values: Vec<MatrixItem>,
+    ) -> Result<(), pg::error::Error> {
+        if at > self.dim.get_m() {
+            return Err(pg::error::Error::OutOfBounds);
+        }
+        if values.len() != self.dim.get_n() {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        self.data.insert(at, values);
+        self.dim.0 += 1;
+        Ok(())
+    }
+
+    /// Inserts `values` as column `at`, shifting the following columns right.
+    pub fn insert_col(
+        &mut self,
+        at: MatrixDim,
+        values: Vec<MatrixItem>,
+    ) -> Result<(), pg::error::Error> {
+        if at > self.dim.get_n() {
+            return Err(pg::error::Error::OutOfBounds);
+        }
+        if values.len() != self.dim.get_m() {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        for (row, value) in self.data.iter_mut().zip(values) {
+            row.insert(at, value);
+        }
+        self.dim.1 += 1;
+        Ok(())
+    }
+
+    /// @Returns removed row.
+    pub fn remove_row(&mut self, at: MatrixDim) -> Result<Vec<MatrixItem>, pg::error::Error> {
+        check_row(self, at)?;
+
+        self.dim.0 -= 1;
+        Ok(self.data.remove(at))
+    }
+
+    /// @Returns removed column.
+    pub fn remove_col(&mut self, at: MatrixDim) -> Result<Vec<MatrixItem>, pg::error::Error> {
+        check_col(self, at)?;
+
+        self.dim.1 -= 1;
+        Ok(self.data.iter_mut().map(|row| row.remove(at)).collect())
+    }
 }
 
 impl SquareMatrix {
@@ -205,7 +451,7 @@ impl SquareMatrix {
     }
 
     pub fn get_main_diagonal(&self) -> Vec<MatrixItem> {
-        (0..self.get_size()).map(|i| self.get(i, i).get()).collect()
+        (0..self.get_size()).map(|i| self.get(i, i)).collect()
     }
 
     /// @Returns `determinant` of the matrix.
@@ -236,6 +482,39 @@ impl MatrixRepr for SquareMatrix {
         Dim(self.size, self.size)
     }
 }
+impl MatrixDataMut for Matrix {
+    fn get_data_mut(&mut self) -> &mut MatrixData {
+        &mut self.data
+    }
+}
+impl MatrixDataMut for SquareMatrix {
+    fn get_data_mut(&mut self) -> &mut MatrixData {
+        &mut self.data
+    }
+}
+
+impl Index<(MatrixDim, MatrixDim)> for Matrix {
+    type Output = MatrixItem;
+    fn index(&self, (i, j): (MatrixDim, MatrixDim)) -> &Self::Output {
+        &self.data[i][j]
+    }
+}
+impl IndexMut<(MatrixDim, MatrixDim)> for Matrix {
+    fn index_mut(&mut self, (i, j): (MatrixDim, MatrixDim)) -> &mut Self::Output {
+        &mut self.data[i][j]
+    }
+}
+impl Index<(MatrixDim, MatrixDim)> for SquareMatrix {
+    type Output = MatrixItem;
+    fn index(&self, (i, j): (MatrixDim, MatrixDim)) -> &Self::Output {
+        &self.data[i][j]
+    }
+}
+impl IndexMut<(MatrixDim, MatrixDim)> for SquareMatrix {
+    fn index_mut(&mut self, (i, j): (MatrixDim, MatrixDim)) -> &mut Self::Output {
+        &mut self.data[i][j]
+    }
+}
 
 impl MatrixOperations for Matrix {}
 impl MatrixOperations for SquareMatrix {}
@@ -255,71 +534,219 @@ impl TryFrom<Matrix> for SquareMatrix {
                 size: value.dim.get_m(),
                 data: value.data,
             }),
-            false => Err(Self::Error::default()),
+            false => Err(Self::Error::DimMismatch),
         }
     }
 }
 
 impl Display for dyn MatrixRepr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let precision = f.precision().unwrap_or(print::PRECISION);
+        MatrixDisplay::new(self, &FormatConfig::default()).fmt(f)
+    }
+}
 
-        let (max, min) = {
-            let mut max = f32::MIN;
-            let mut min = f32::MAX;
-            self.get_data().iter().flatten().for_each(|v| {
-                let val = v.get();
-                (val > max).then(|| max = val);
-                (val < min).then(|| min = val);
-            });
+impl Print for Matrix {}
+impl Print for SquareMatrix {}
+impl Display for SquareMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <dyn MatrixRepr>::fmt(self, f)
+    }
+}
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <dyn MatrixRepr>::fmt(self, f)
+    }
+}
 
-            (max as i32, min as i32)
-        };
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let (m, n) = (self.get_dim().get_m(), self.get_dim().get_n());
+    fn matrix(rows: &[&[MatrixItem]]) -> Matrix {
+        Matrix::from_rows(rows.iter().map(|row| row.to_vec()).collect()).unwrap()
+    }
 
-        // Element width
-        let width = get_i32_len(max).max(get_i32_len(min)) + precision;
+    #[test]
+    fn transpose() {
+        let a = matrix(&[&[1., 2., 3.], &[4., 5., 6.]]);
 
-        // Overall line width
-        let line_width = (n * (width + ITEM_X_GAP + 1)).div_ceil(2) * 2;
+        assert_eq!(a.transpose(), matrix(&[&[1., 4.], &[2., 5.], &[3., 6.]]));
+        assert_eq!(a.transpose().transpose(), a);
+    }
 
-        writeln!(f, "{:_^line_width$}", "Matrix")?;
-        for i in 0..m {
-            for j in 0..n {
-                write!(f, "{:>width$.precision$}", self.get_data()[i][j].get())?;
+    #[test]
+    fn stack() {
+        let a = matrix(&[&[1., 2.], &[3., 4.]]);
+        let b = matrix(&[&[5.], &[6.]]);
 
-                if j != self.get_dim().get_n() - 1 {
-                    write!(f, "{:>1$}", " ", ITEM_X_GAP)?;
-                }
-            }
+        assert_eq!(a.hstack(&b), Ok(matrix(&[&[1., 2., 5.], &[3., 4., 6.]])));
+        assert_eq!(
+            a.vstack(&b.transpose()),
+            Ok(matrix(&[&[1., 2.], &[3., 4.], &[5., 6.]]))
+        );
+        assert_eq!(a.vstack(&b), Err(pg::error::Error::DimMismatch));
+        assert_eq!(a.hstack(&b.transpose()), Err(pg::error::Error::DimMismatch));
+    }
 
-            // At least one newline.
-            writeln!(f)?;
+    #[test]
+    fn reshape() {
+        let a = matrix(&[&[1., 2., 3.], &[4., 5., 6.]]);
 
-            // If not last row -> newline n-1 times.
-            if i != m - 1 {
-                for _ in 1..print::ITEM_Y_GAP {
-                    writeln!(f)?;
-                }
-            }
-        }
+        assert_eq!(
+            a.reshape(Dim::new(3, 2)),
+            Ok(matrix(&[&[1., 2.], &[3., 4.], &[5., 6.]]))
+        );
+        assert!(a.reshape(Dim::new(4, 2)).is_err());
+    }
 
-        writeln!(f, "{:‾^line_width$}", "")?;
+    #[test]
+    fn kronecker() {
+        let a = matrix(&[&[1., 2.], &[3., 4.]]);
+        let b = matrix(&[&[0., 5.], &[6., 7.]]);
 
-        Ok(())
+        assert_eq!(
+            a.kronecker(&b),
+            matrix(&[
+                &[0., 5., 0., 10.],
+                &[6., 7., 12., 14.],
+                &[0., 15., 0., 20.],
+                &[18., 21., 24., 28.],
+            ])
+        );
+        assert_eq!(Matrix::identity(1).kronecker(&b), b);
     }
-}
 
-impl Print for Matrix {}
-impl Print for SquareMatrix {}
-impl Display for SquareMatrix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <dyn MatrixRepr>::fmt(self, f)
+    #[test]
+    fn element_wise() {
+        let a = matrix(&[&[1., 2.], &[3., 4.]]);
+        let b = matrix(&[&[5., 6.], &[7., 8.]]);
+
+        assert_eq!(a.hadamard(&b), Ok(matrix(&[&[5., 12.], &[21., 32.]])));
+        assert_eq!(a.map(|v| v * 10.), matrix(&[&[10., 20.], &[30., 40.]]));
+        assert_eq!(
+            a.zip_with(&b, |x, y| y - x),
+            Ok(matrix(&[&[4., 4.], &[4., 4.]]))
+        );
+        assert!(a.hadamard(&Matrix::identity(3)).is_err());
     }
-}
-impl Display for Matrix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <dyn MatrixRepr>::fmt(self, f)
+
+    #[test]
+    fn multiply_identity() {
+        let a = matrix(&[&[1., 2., 3.], &[4., 5., 6.]]);
+
+        assert_eq!(a.multiply(&Matrix::identity(3)), Some(a.clone()));
+        assert_eq!(Matrix::identity(2).multiply(&a), Some(a));
+    }
+
+    #[test]
+    fn index() {
+        let mut a = Matrix::new(Dim::new(2, 3));
+        a[(1, 2)] = 5.;
+        a[(0, 0)] += 1.;
+
+        assert_eq!(a[(1, 2)], 5.);
+        assert_eq!(a.get(0, 0), 1.);
+
+        let mut square = SquareMatrix::new(2);
+        square[(1, 0)] = 3.;
+        assert_eq!(square.get_data()[1][0], 3.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _ = Matrix::new(Dim::new(2, 2))[(2, 0)];
+    }
+
+    #[test]
+    fn iterators() {
+        let mut a = matrix(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
+
+        a.iter_mut().for_each(|v| *v *= 2.);
+        assert_eq!(
+            a.iter().copied().collect::<Vec<_>>(),
+            [2., 4., 6., 8., 10., 12.]
+        );
+
+        let rows = a.rows().map(|row| row.iter().sum::<MatrixItem>());
+        assert_eq!(rows.collect::<Vec<_>>(), [6., 14., 22.]);
+
+        let cols = a.cols().map(|col| col.iter().sum::<MatrixItem>());
+        assert_eq!(cols.collect::<Vec<_>>(), [18., 24.]);
+
+        let (position, _) = a.enumerate().find(|&(_, &v)| v == 8.).unwrap();
+        assert_eq!(position, (1, 1));
+    }
+
+    #[test]
+    fn row_and_col() {
+        let a = matrix(&[&[1., 2.], &[3., 4.]]);
+
+        assert_eq!(a.row(1).unwrap()[0], 3.);
+        assert_eq!(a.col(1).unwrap()[0], 2.);
+        assert!(a.row(2).is_err());
+        assert_eq!(a.col(2).err(), Some(pg::error::Error::OutOfBounds));
+    }
+
+    #[test]
+    fn swap_and_scale() {
+        let mut a = matrix(&[&[1., 2.], &[3., 4.]]);
+
+        a.swap_rows(0, 1).unwrap();
+        assert_eq!(a, matrix(&[&[3., 4.], &[1., 2.]]));
+
+        a.swap_cols(0, 1).unwrap();
+        assert_eq!(a, matrix(&[&[4., 3.], &[2., 1.]]));
+
+        a.scale_row(0, 2.).unwrap();
+        a.scale_col(1, -1.).unwrap();
+        assert_eq!(a, matrix(&[&[8., -6.], &[2., -1.]]));
+
+        assert_eq!(a.swap_rows(0, 2), Err(pg::error::Error::OutOfBounds));
+        assert_eq!(a.swap_cols(5, 0), Err(pg::error::Error::OutOfBounds));
+        assert_eq!(a.scale_row(2, 1.), Err(pg::error::Error::OutOfBounds));
+        assert_eq!(a.scale_col(2, 1.), Err(pg::error::Error::OutOfBounds));
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut a = matrix(&[&[1., 2.], &[3., 4.]]);
+
+        a.insert_row(1, vec![5., 6.]).unwrap();
+        assert_eq!(a, matrix(&[&[1., 2.], &[5., 6.], &[3., 4.]]));
+
+        a.insert_col(2, vec![7., 8., 9.]).unwrap();
+        assert_eq!(a, matrix(&[&[1., 2., 7.], &[5., 6., 8.], &[3., 4., 9.]]));
+
+        assert_eq!(a.remove_row(0), Ok(vec![1., 2., 7.]));
+        assert_eq!(a.remove_col(1), Ok(vec![6., 4.]));
+        assert_eq!(a, matrix(&[&[5., 8.], &[3., 9.]]));
+        assert_eq!(a.get_dim(), Dim::new(2, 2));
+
+        assert_eq!(
+            a.insert_row(3, vec![0., 0.]),
+            Err(pg::error::Error::OutOfBounds)
+        );
+        assert_eq!(
+            a.insert_row(0, vec![0.]),
+            Err(pg::error::Error::DimMismatch)
+        );
+        assert_eq!(
+            a.insert_col(0, vec![0.]),
+            Err(pg::error::Error::DimMismatch)
+        );
+        assert_eq!(a.remove_row(2), Err(pg::error::Error::OutOfBounds));
+        assert_eq!(a.remove_col(2), Err(pg::error::Error::OutOfBounds));
+    }
+
+    #[test]
+    fn echelon_form_with_row_swap() {
+        let a = matrix(&[&[0., 1.], &[2., 3.], &[4., 5.]]);
+        let echelon = a.to_echelon_form();
+
+        assert_eq!(echelon.row(0).unwrap()[0], 2.);
+        assert_eq!(echelon.get(1, 0), 0.);
+        assert_eq!(echelon.get(2, 0), 0.);
+        assert_eq!(echelon.get(2, 1), 0.);
     }
 }
@@ -0,0 +1,524 @@
+use super::*;
+
+/// `u` · `a` = `h`, where `u` is unimodular and `h` is in (row-style) Hermite normal form.
+#[derive(Clone, Debug)]
+pub struct HermiteForm {
+    pub h: IntMatrix,
+    pub u: IntMatrix,
+}
+
+/// `u` · `a` · `v` = `d`, where `u`, `v` are unimodular and `d` is in Smith normal form.
+#[derive(Clone, Debug)]
+pub struct SmithForm {
+    pub d: IntMatrix,
+    pub u: IntMatrix,
+    pub v: IntMatrix,
+}
+
+impl IntMatrix {
+    pub fn new(dim: Dim) -> Self {
+        Self {
+            data: vec![vec![0; dim.get_n()]; dim.get_m()],
+            dim,
+        }
+    }
+
+    pub fn identity(size: MatrixDim) -> Self {
+        let mut matrix = Self::new(Dim(size, size));
+        for i in 0..size {
+            matrix.data[i][i] = 1;
+        }
+
+        matrix
+    }
+
+    /// @Returns `Err` if rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<IntItem>>) -> Result<Self, pg::error::Error> {
+        let n = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != n) {
+            return Err(pg::error::Error::DimMismatch);
+        }
+
+        Ok(Self {
+            dim: Dim(rows.len(), n),
+            data: rows,
+        })
+    }
+
+    pub fn get_dim(&self) -> Dim {
+        self.dim
+    }
+
+    pub fn get(&self, i: MatrixDim, j: MatrixDim) -> IntItem {
+        self.data[i][j]
+    }
+
+    pub fn set(&mut self, i: MatrixDim, j: MatrixDim, value: IntItem) {
+        self.data[i][j] = value;
+    }
+
+    /// @If dimensions don't match or an entry overflows `IntItem`, returns `None`.
+    pub fn multiply(&self, rhs: &Self) -> Option<Self> {
+        let (m1, n1) = (self.dim.get_m(), self.dim.get_n());
+        let (m2, n2) = (rhs.dim.get_m(), rhs.dim.get_n());
+
+        if n1 != m2 {
+            return None;
+        }
+
+        let mut result = Self::new(Dim(m1, n2));
+        for (m, row) in result.data.iter_mut().enumerate() {
+            for (n, value) in row.iter_mut().enumerate() {
+                *value = (0..n1).try_fold(0, |acc: IntItem, k| {
+                    acc.checked_add(self.data[m][k].checked_mul(rhs.data[k][n])?)
+                })?;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Fraction-free (Bareiss) elimination: every intermediate value is a minor
+    /// of the matrix, so divisions are exact.
+    ///
+    /// @If matrix is not square, or a minor overflows `i128`, returns `None`.
+    pub fn det_bareiss(&self) -> Option<i128> {
+        if !self.dim.is_square() {
+            return None;
+        }
+
+        let size = self.dim.get_m();
+        if size == 0 {
+            return Some(1);
+        }
+
+        let mut a = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|&v| v as i128).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut sign = 1;
+        let mut previous_pivot = 1;
+        for k in 0..size - 1 {
+            if a[k][k] == 0 {
+                let Some(swap_with) = (k + 1..size).find(|&i| a[i][k] != 0) else {
+                    return Some(0);
+                };
+
+                a.swap(k, swap_with);
+                sign = -sign;
+            }
+
+            for i in k + 1..size {
+                for j in k + 1..size {
+                    let minor = a[i][j]
+                        .checked_mul(a[k][k])?
+                        .checked_sub(a[i][k].checked_mul(a[k][j])?)?;
+                    a[i][j] = minor / previous_pivot;
+                }
+            }
+
+            previous_pivot = a[k][k];
+        }
+
+        Some(sign * a[size - 1][size - 1])
+    }
+
+    /// Pivots are positive and every entry above a pivot is reduced into `[0, pivot)`.
+    ///
+    /// @If an entry of `h` or `u` overflows `IntItem` on the way, returns `None`.
+    pub fn hermite_normal_form(&self) -> Option<HermiteForm> {
+        let (m, n) = (self.dim.get_m(), self.dim.get_n());
+        let mut h = self.clone();
+        let mut u = Self::identity(m);
+
+        let mut pivot_row = 0;
+        for col in 0..n {
+            if pivot_row == m {
+                break;
+            }
+
+            // Euclid over the rows: move the smallest entry up and reduce the rest by it.
+            while let Some(min_row) = (pivot_row..m)
+                .filter(|&i| h.data[i][col] != 0)
+                .min_by_key(|&i| h.data[i][col].unsigned_abs())
+            {
+                h.swap_rows(pivot_row, min_row);
+                u.swap_rows(pivot_row, min_row);
+
+                let pivot = h.data[pivot_row][col];
+                let mut reduced = true;
+                for i in pivot_row + 1..m {
+                    let q = h.data[i][col].checked_div(pivot)?.checked_neg()?;
+                    if q != 0 {
+                        h.add_row(i, pivot_row, q)?;
+                        u.add_row(i, pivot_row, q)?;
+                    }
+
+                    reduced &= h.data[i][col] == 0;
+                }
+
+                if reduced {
+                    break;
+                }
+            }
+
+            if h.data[pivot_row][col] == 0 {
+                continue;
+            }
+
+            if h.data[pivot_row][col] < 0 {
+                h.negate_row(pivot_row)?;
+                u.negate_row(pivot_row)?;
+            }
+
+            let pivot = h.data[pivot_row][col];
+            for i in 0..pivot_row {
+                let q = h.data[i][col].checked_div_euclid(pivot)?.checked_neg()?;
+                if q != 0 {
+                    h.add_row(i, pivot_row, q)?;
+                    u.add_row(i, pivot_row, q)?;
+                }
+            }
+
+            pivot_row += 1;
+        }
+
+        Some(HermiteForm { h, u })
+    }
+
+    /// Diagonal entries are non-negative and each one divides the next.
+    ///
+    /// @If an entry of `d`, `u` or `v` overflows `IntItem` on the way, returns `None`.
+    pub fn smith_normal_form(&self) -> Option<SmithForm> {
+        let (m, n) = (self.dim.get_m(), self.dim.get_n());
+        let mut d = self.clone();
+        let mut u = Self::identity(m);
+        let mut v = Self::identity(n);
+
+        for t in 0..m.min(n) {
+            // Smallest non-zero entry of the remaining block becomes the pivot.
+            while let Some((pi, pj)) = (t..m)
+                .flat_map(|i| (t..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| d.data[i][j] != 0)
+                .min_by_key(|&(i, j)| d.data[i][j].unsigned_abs())
+            {
+                d.swap_rows(t, pi);
+                u.swap_rows(t, pi);
+                d.swap_cols(t, pj);
+                v.swap_cols(t, pj);
+
+                let pivot = d.data[t][t];
+                let mut reduced = true;
+                for i in t + 1..m {
+                    let q = d.data[i][t].checked_div(pivot)?.checked_neg()?;
+                    if q != 0 {
+                        d.add_row(i, t, q)?;
+                        u.add_row(i, t, q)?;
+                    }
+
+                    reduced &= d.data[i][t] == 0;
+                }
+                for j in t + 1..n {
+                    let q = d.data[t][j].checked_div(pivot)?.checked_neg()?;
+                    if q != 0 {
+                        d.add_col(j, t, q)?;
+                        v.add_col(j, t, q)?;
+                    }
+
+                    reduced &= d.data[t][j] == 0;
+                }
+
+                if !reduced {
+                    continue;
+                }
+
+                // Pivot has to divide the rest of the block, otherwise pull the
+                // offending row in and go again with a smaller remainder.
+                let mut offending = None;
+                'rows: for i in t + 1..m {
+                    for j in t + 1..n {
+                        if d.data[i][j].checked_rem(pivot)? != 0 {
+                            offending = Some(i);
+                            break 'rows;
+                        }
+                    }
+                }
+                match offending {
+                    Some(i) => {
+                        d.add_row(t, i, 1)?;
+                        u.add_row(t, i, 1)?;
+                    }
+                    None => break,
+                }
+            }
+
+            if d.data[t][t] < 0 {
+                d.negate_row(t)?;
+                u.negate_row(t)?;
+            }
+        }
+
+        Some(SmithForm { d, u, v })
+    }
+
+    fn swap_rows(&mut self, lhs: MatrixDim, rhs: MatrixDim) {
+        self.data.swap(lhs, rhs);
+    }
+
+    fn swap_cols(&mut self, lhs: MatrixDim, rhs: MatrixDim) {
+        self.data.iter_mut().for_each(|row| row.swap(lhs, rhs));
+    }
+
+    /// @Mutate `row[to]` += `k` * `row[from]`
+    ///
+    /// @If an entry overflows, returns `None` and leaves the row partly updated.
+    fn add_row(&mut self, to: MatrixDim, from: MatrixDim, k: IntItem) -> Option<()> {
+        for j in 0..self.dim.get_n() {
+            self.data[to][j] = self.data[to][j].checked_add(k.checked_mul(self.data[from][j])?)?;
+        }
+        Some(())
+    }
+
+    /// @Mutate `col[to]` += `k` * `col[from]`
+    ///
+    /// @If an entry overflows, returns `None` and leaves the column partly updated.
+    fn add_col(&mut self, to: MatrixDim, from: MatrixDim, k: IntItem) -> Option<()> {
+        for row in self.data.iter_mut() {
+            row[to] = row[to].checked_add(k.checked_mul(row[from])?)?;
+        }
+        Some(())
+    }
+
+    /// @If an entry is `IntItem::MIN`, returns `None` and leaves the row partly negated.
+    fn negate_row(&mut self, i: MatrixDim) -> Option<()> {
+        for v in self.data[i].iter_mut() {
+            *v = v.checked_neg()?;
+        }
+        Some(())
+    }
+
+    /// @If an entry isn't a finite integer -> `Err(Any)`, if it's out of `IntItem` range
+    /// -> `Err(OutOfRange)`.
+    fn from_repr(repr: &dyn MatrixRepr) -> Result<Self, pg::error::Error> {
+        // -2^63 and 2^63, both exact as floats.
+        let range = IntItem::MIN as MatrixItem..-(IntItem::MIN as MatrixItem);
+        let data = repr
+            .get_data()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&value| match value {
+                        v if v.fract() != 0. => Err(pg::error::Error::default()),
+                        v if !range.contains(&v) => Err(pg::error::Error::OutOfRange),
+                        v => Ok(v as IntItem),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            data,
+            dim: repr.get_dim(),
+        })
+    }
+}
+
+impl SquareMatrix {
+    /// Exact determinant through [`IntMatrix::det_bareiss`].
+    ///
+    /// @If matrix has non-integer elements, returns `None`.
+    pub fn det_exact(&self) -> Option<i128> {
+        IntMatrix::try_from(self).ok()?.det_bareiss()
+    }
+}
+
+impl TryFrom<&Matrix> for IntMatrix {
+    type Error = pg::error::Error;
+    fn try_from(value: &Matrix) -> Result<Self, Self::Error> {
+        Self::from_repr(value)
+    }
+}
+impl TryFrom<&SquareMatrix> for IntMatrix {
+    type Error = pg::error::Error;
+    fn try_from(value: &SquareMatrix) -> Result<Self, Self::Error> {
+        Self::from_repr(value)
+    }
+}
+
+impl From<&IntMatrix> for Matrix {
+    fn from(value: &IntMatrix) -> Self {
+        let mut matrix = Matrix::new(value.dim);
+        matrix.fill_fn(|i, j| value.data[i][j] as MatrixItem);
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_matrix(rows: &[&[IntItem]]) -> IntMatrix {
+        IntMatrix::from_rows(rows.iter().map(|row| row.to_vec()).collect()).unwrap()
+    }
+
+    // Laplace expansion, slow but obviously correct.
+    fn det_cofactor(a: &[Vec<i128>]) -> i128 {
+        if a.is_empty() {
+            return 1;
+        }
+
+        (0..a.len())
+            .map(|j| {
+                let minor = a[1..]
+                    .iter()
+                    .map(|row| [&row[..j], &row[j + 1..]].concat())
+                    .collect::<Vec<_>>();
+                let sign = if j % 2 == 0 { 1 } else { -1 };
+
+                sign * a[0][j] * det_cofactor(&minor)
+            })
+            .sum()
+    }
+
+    fn is_unimodular(matrix: &IntMatrix) -> bool {
+        matrix.det_bareiss().is_some_and(|det| det.abs() == 1)
+    }
+
+    #[test]
+    fn bareiss() {
+        let a = int_matrix(&[&[2, -3, 1], &[2, 0, -1], &[1, 4, 5]]);
+        assert_eq!(a.det_bareiss(), Some(49));
+
+        assert_eq!(int_matrix(&[&[0, 1], &[1, 0]]).det_bareiss(), Some(-1));
+        assert_eq!(int_matrix(&[&[1, 2], &[2, 4]]).det_bareiss(), Some(0));
+        assert_eq!(int_matrix(&[&[0, 0], &[0, 3]]).det_bareiss(), Some(0));
+        assert_eq!(IntMatrix::new(Dim(0, 0)).det_bareiss(), Some(1));
+        assert_eq!(IntMatrix::new(Dim(2, 3)).det_bareiss(), None);
+    }
+
+    #[test]
+    fn bareiss_large_entries() {
+        let size = 6;
+        let mut a = IntMatrix::new(Dim(size, size));
+        for i in 0..size {
+            for j in 0..size {
+                a.set(
+                    i,
+                    j,
+                    ((i * 7 + j * 13) % 97) as IntItem * 1_000 + (i + j) as IntItem,
+                );
+            }
+        }
+
+        let expected = det_cofactor(
+            &a.data
+                .iter()
+                .map(|row| row.iter().map(|&v| v as i128).collect())
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(a.det_bareiss(), Some(expected));
+    }
+
+    #[test]
+    fn det_exact() {
+        let mut matrix = SquareMatrix::new(3);
+        matrix.fill_fn(|i, j| [[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]][i][j]);
+        assert_eq!(matrix.det_exact(), Some(49));
+
+        matrix[(0, 0)] = 0.5;
+        assert_eq!(matrix.det_exact(), None);
+    }
+
+    #[test]
+    fn from_repr_range() {
+        let mut matrix = SquareMatrix::new(1);
+        for (value, expected) in [
+            (-9.223_372e18, Ok(IntItem::MIN)),
+            (9.223_372e18, Err(pg::error::Error::OutOfRange)),
+            (1e30, Err(pg::error::Error::OutOfRange)),
+            (MatrixItem::INFINITY, Err(pg::error::Error::Any)),
+            (MatrixItem::NAN, Err(pg::error::Error::Any)),
+        ] {
+            matrix[(0, 0)] = value;
+            assert_eq!(IntMatrix::try_from(&matrix).map(|m| m.get(0, 0)), expected);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let big = IntItem::MAX / 2 + 1;
+        let a = int_matrix(&[&[big, 1], &[1, 0]]);
+        assert_eq!(a.multiply(&a), None);
+
+        // Reducing the second row by the first one overflows.
+        let a = int_matrix(&[&[2, big], &[-IntItem::MAX, 0]]);
+        assert!(a.hermite_normal_form().is_none());
+        assert!(a.smith_normal_form().is_none());
+
+        let a = int_matrix(&[&[IntItem::MIN]]);
+        assert!(a.hermite_normal_form().is_none());
+
+        // `IntItem::MIN % -1` overflows too.
+        let a = int_matrix(&[&[-1, 0], &[0, IntItem::MIN]]);
+        assert!(a.smith_normal_form().is_none());
+    }
+
+    #[test]
+    fn hermite() {
+        let a = int_matrix(&[&[2, 3, 6, 2], &[5, 6, 1, 6], &[8, 3, 1, 1]]);
+        let HermiteForm { h, u } = a.hermite_normal_form().unwrap();
+
+        assert_eq!(u.multiply(&a).unwrap(), h);
+        assert!(is_unimodular(&u));
+
+        let mut last_pivot_col = None;
+        for i in 0..h.dim.get_m() {
+            let Some(col) = (0..h.dim.get_n()).find(|&j| h.get(i, j) != 0) else {
+                continue;
+            };
+
+            assert!(last_pivot_col.is_none_or(|last| col > last));
+            assert!(h.get(i, col) > 0);
+            for above in 0..i {
+                assert!((0..h.get(i, col)).contains(&h.get(above, col)));
+            }
+
+            last_pivot_col = Some(col);
+        }
+    }
+
+    #[test]
+    fn hermite_rank_deficient() {
+        let a = int_matrix(&[&[2, 4], &[3, 6], &[1, 2]]);
+        let HermiteForm { h, u } = a.hermite_normal_form().unwrap();
+
+        assert_eq!(u.multiply(&a).unwrap(), h);
+        assert!(is_unimodular(&u));
+        assert_eq!(h, int_matrix(&[&[1, 2], &[0, 0], &[0, 0]]));
+    }
+
+    #[test]
+    fn smith() {
+        let a = int_matrix(&[&[2, 4, 4], &[-6, 6, 12], &[10, -4, -16]]);
+        let SmithForm { d, u, v } = a.smith_normal_form().unwrap();
+
+        assert_eq!(u.multiply(&a).unwrap().multiply(&v).unwrap(), d);
+        assert!(is_unimodular(&u));
+        assert!(is_unimodular(&v));
+        assert_eq!(d, int_matrix(&[&[2, 0, 0], &[0, 6, 0], &[0, 0, 12]]));
+    }
+
+    #[test]
+    fn smith_rectangular() {
+        let a = int_matrix(&[&[6, 4, 0], &[4, 0, 8]]);
+        let SmithForm { d, u, v } = a.smith_normal_form().unwrap();
+
+        assert_eq!(u.multiply(&a).unwrap().multiply(&v).unwrap(), d);
+        assert!(is_unimodular(&u));
+        assert!(is_unimodular(&v));
+        assert_eq!(d, int_matrix(&[&[2, 0, 0], &[0, 8, 0]]));
+    }
+}
@@ -1,5 +1,6 @@
 use rand::distributions::uniform::SampleUniform;
 use rand::prelude::*;
+use rand::rngs::StdRng;
 use rand::thread_rng;
 
 pub struct Random<T> {
@@ -30,3 +31,51 @@ where
         thread_rng().gen_range(min..max)
     }
 }
+
+/// Same interface as [`Random`], but driven by a seeded generator,
+/// so every sequence can be reproduced.
+pub struct SeededRandom {
+    rng: StdRng,
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn get_vec<T>(&mut self, size: usize) -> Vec<T>
+    where
+        rand::distributions::Standard: Distribution<T>,
+    {
+        (0..size).map(|_| self.rng.gen()).collect()
+    }
+
+    pub fn get<T>(&mut self) -> T
+    where
+        rand::distributions::Standard: Distribution<T>,
+    {
+        self.rng.gen()
+    }
+
+    pub fn get_in_range<T>(&mut self, min: T, max: T) -> T
+    where
+        T: PartialOrd + SampleUniform,
+    {
+        self.rng.gen_range(min..max)
+    }
+
+    /// `true` with probability `p`.
+    pub fn get_bool(&mut self, p: f64) -> bool {
+        self.rng.gen_bool(p.clamp(0., 1.))
+    }
+
+    /// Standard normal sample (Box–Muller).
+    pub fn get_gaussian(&mut self) -> f64 {
+        let u1 = 1. - self.rng.gen::<f64>();
+        let u2 = self.rng.gen::<f64>();
+
+        (-2. * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}